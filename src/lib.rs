@@ -7,9 +7,15 @@
 //! Las operaciones se realizan sobre "tablas" (archivos csv).
 //!
 //!
-//! Consultas Permitidas: [SELECT, INSERT, UPDATE, DELETE]
+//! Consultas Permitidas: [SELECT, WITH ... SELECT, INSERT, INSERT ... SELECT, UPDATE, DELETE, CREATE TABLE, DROP TABLE, ALTER TABLE]
 //!
-//! Operadores Disponibles: [AND, OR, NOT y comparadores simples (>, <, =, etc..)]
+//! Operadores Disponibles: [AND, OR, NOT, IS NULL, IS NOT NULL, LIKE, NOT LIKE, ILIKE, IN, NOT IN, BETWEEN, EXISTS, CASE WHEN, UNION, UNION ALL, INTERSECT, EXCEPT, sub-consultas, comparadores simples (>, <, =, etc..) y aritméticos (+, -, *, /, %)]
+//!
+//! Funciones Disponibles: [UPPER, LOWER, LENGTH, SUBSTR, TRIM, CONCAT, REPLACE, NOW, DATE_ADD, DATE_SUB, EXTRACT, DATE_TRUNC]
+//!
+//! Funciones de Ventana: [ROW_NUMBER, RANK, LAG, LEAD, SUM, con OVER (PARTITION BY ... ORDER BY ...)]
+//!
+//! Tipos de Datos: [INT, FLOAT, TEXT, DATE, TIMESTAMP]
 //!
 //!
 //! Estructura del Proyecto:
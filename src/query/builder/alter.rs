@@ -0,0 +1,232 @@
+use crate::errored;
+use crate::query::builder::{unexpected_token_in_stage, validate_keywords, Builder};
+use crate::query::structs::alteration::Alteration;
+use crate::query::structs::operation::Operation::Alter;
+use crate::query::structs::query::Query;
+use crate::query::structs::schema::ColumnType;
+use crate::query::structs::token::TokenKind::{Identifier, Keyword, Number};
+use crate::query::structs::token::{Token, TokenKind};
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
+use std::collections::VecDeque;
+
+const ALLOWED_KEYWORDS: &[&str] = &[
+    "ADD COLUMN",
+    "DROP COLUMN",
+    "RENAME COLUMN",
+    "TO",
+    "DEFAULT",
+    "NULL",
+];
+
+/// Constructor para consultas de modificación de tablas (`ALTER TABLE`).
+///
+/// Admite las siguientes modificaciones:
+///
+/// - `ALTER TABLE tabla ADD COLUMN columna [TIPO] [DEFAULT valor|NULL]`
+/// - `ALTER TABLE tabla DROP COLUMN columna`
+/// - `ALTER TABLE tabla RENAME COLUMN columna TO nuevo_nombre`
+pub struct AlterBuilder {
+    tokens: VecDeque<Token>,
+}
+
+impl AlterBuilder {
+    /// Crea una nueva instancia de `AlterBuilder`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens (`VecDeque<Token>`) que se utilizarán para construir la consulta.
+    pub fn new(tokens: VecDeque<Token>) -> Self {
+        Self { tokens }
+    }
+
+    /// Analiza la modificación a realizar sobre la tabla, que sigue a su nombre.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la modificación no es ninguna de las admitidas o si está incompleta.
+    fn parse_alteration(&mut self) -> Result<Alteration, Errored> {
+        let action = self
+            .tokens
+            .pop_front()
+            .ok_or_else(|| Syntax("expected ADD, DROP or RENAME COLUMN.".to_string()))?;
+        if action.kind != Keyword {
+            unexpected_token_in_stage("ALTER", &action)?;
+        }
+        match action.value.as_str() {
+            "ADD COLUMN" => {
+                let column = self.parse_column()?;
+                let kind = match self.tokens.front() {
+                    Some(t) if t.kind == Identifier => Some(self.parse_type(&column)?),
+                    _ => None,
+                };
+                let mut default = None;
+                if self.peek_expecting("DEFAULT", Keyword).is_ok() {
+                    self.tokens.pop_front();
+                    default = self.parse_default()?;
+                }
+                Ok(Alteration::AddColumn {
+                    column,
+                    kind,
+                    default,
+                })
+            }
+            "DROP COLUMN" => Ok(Alteration::DropColumn(self.parse_column()?)),
+            "RENAME COLUMN" => {
+                let from = self.parse_column()?;
+                self.pop_expecting("TO", Keyword)?;
+                let to = self.parse_column()?;
+                Ok(Alteration::RenameColumn { from, to })
+            }
+            _ => errored!(Syntax, "invalid ALTER TABLE action: {}", action.value),
+        }
+    }
+
+    /// Extrae el nombre de una columna.
+    fn parse_column(&mut self) -> Result<String, Errored> {
+        match self.tokens.pop_front() {
+            Some(t) if t.kind == Identifier && !t.value.contains('.') => Ok(t.value),
+            Some(t) => unexpected_token_in_stage("COLUMN", &t).map(|_| t.value),
+            None => errored!(Syntax, "expected column name but was end of query."),
+        }
+    }
+
+    /// Extrae el tipo de la columna a agregar.
+    fn parse_type(&mut self, column: &str) -> Result<ColumnType, Errored> {
+        let t = self.tokens.pop_front().unwrap_or_default();
+        match ColumnType::from_name(&t.value) {
+            Some(kind) => Ok(kind),
+            None => errored!(Syntax, "unknown type for column {}: {}", column, t.value),
+        }
+    }
+
+    /// Extrae el valor por defecto de la columna a agregar, que debe ser un string, un número
+    /// o `NULL` (en cuyo caso retorna `None`).
+    fn parse_default(&mut self) -> Result<Option<String>, Errored> {
+        match self.tokens.pop_front() {
+            Some(t) if t.kind == TokenKind::String || t.kind == Number => Ok(Some(t.value)),
+            Some(t) if t.kind == Keyword && t.value == "NULL" => Ok(None),
+            Some(t) => unexpected_token_in_stage("DEFAULT", &t).map(|_| None),
+            None => errored!(Syntax, "expected value after DEFAULT but was end of query."),
+        }
+    }
+}
+
+impl Builder for AlterBuilder {
+    /// Construye una consulta `ALTER TABLE` a partir de los tokens proporcionados.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un objeto `Query` con la tabla y la modificación a realizar.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error `Errored` si la consulta contiene errores de sintaxis o palabras clave no permitidas.
+    fn build(&mut self) -> Result<Query, Errored> {
+        let mut query = Query::default();
+        self.validate_keywords()?;
+        query.operation = Alter;
+        query.table = self.parse_table(Alter)?;
+        query.alteration = Some(self.parse_alteration()?);
+        self.expect_none()?;
+        Ok(query)
+    }
+
+    /// Retorna una referencia mutable a los tokens que se están procesando.
+    fn tokens(&mut self) -> &mut VecDeque<Token> {
+        &mut self.tokens
+    }
+
+    /// Valida las palabras clave permitidas en una consulta `ALTER TABLE`.
+    ///
+    /// Las palabras clave permitidas son: `ADD COLUMN`, `DROP COLUMN`, `RENAME COLUMN`, `TO`, `DEFAULT`, `NULL`.
+    fn validate_keywords(&self) -> Result<(), Errored> {
+        validate_keywords(ALLOWED_KEYWORDS, &self.tokens, Alter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::structs::alteration::Alteration;
+    use crate::query::structs::operation::Operation::Alter;
+    use crate::query::structs::query::Query;
+    use crate::query::structs::schema::ColumnType::Int;
+    use crate::query::structs::token::Token;
+    use crate::query::tokenizer::Tokenizer;
+
+    fn tokenize(sql: &str) -> Vec<Token> {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.tokenize(sql).unwrap()
+    }
+
+    #[test]
+    fn test_alter_add_column() {
+        let sql = "ALTER TABLE ordenes ADD COLUMN precio INT DEFAULT 0";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Alter);
+        assert_eq!(query.table, "ordenes");
+        assert_eq!(
+            query.alteration,
+            Some(Alteration::AddColumn {
+                column: "precio".to_string(),
+                kind: Some(Int),
+                default: Some("0".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_alter_add_column_without_type() {
+        let sql = "ALTER TABLE ordenes ADD COLUMN estado DEFAULT 'pendiente'";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(
+            query.alteration,
+            Some(Alteration::AddColumn {
+                column: "estado".to_string(),
+                kind: None,
+                default: Some("pendiente".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_alter_drop_and_rename_column() {
+        let tokens = tokenize("ALTER TABLE ordenes DROP COLUMN cantidad");
+        let query = Query::from(tokens).unwrap();
+        assert_eq!(
+            query.alteration,
+            Some(Alteration::DropColumn("cantidad".to_string()))
+        );
+
+        let tokens = tokenize("ALTER TABLE ordenes RENAME COLUMN producto TO articulo");
+        let query = Query::from(tokens).unwrap();
+        assert_eq!(
+            query.alteration,
+            Some(Alteration::RenameColumn {
+                from: "producto".to_string(),
+                to: "articulo".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_alter_invalid() {
+        let invalid = [
+            "ALTER TABLE ordenes",
+            "ALTER TABLE ordenes ADD COLUMN",
+            "ALTER TABLE ordenes ADD COLUMN precio FLOATY",
+            "ALTER TABLE ordenes ADD COLUMN precio INT DEFAULT",
+            "ALTER TABLE ordenes RENAME COLUMN producto articulo",
+            "ALTER TABLE ordenes DROP COLUMN cantidad id",
+            "ALTER TABLE ordenes WHERE id = 1",
+        ];
+        for sql in invalid {
+            let tokens = tokenize(sql);
+            assert!(Query::from(tokens).is_err(), "{}", sql);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::errored;
+use crate::query::builder::{unexpected_token_in_stage, validate_keywords, Builder};
+use crate::query::structs::operation::Operation::Create;
+use crate::query::structs::query::Query;
+use crate::query::structs::schema::{ColumnType, Schema};
+use crate::query::structs::token::Token;
+use crate::query::structs::token::TokenKind::{
+    Identifier, Keyword, ParenthesisClose, ParenthesisOpen,
+};
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
+use std::collections::VecDeque;
+
+const ALLOWED_KEYWORDS: &[&str] = &["IF NOT EXISTS"];
+
+/// Constructor para consultas de creación de tablas (`CREATE TABLE`).
+///
+/// `CreateBuilder` se encarga de construir una consulta de creación a partir de los tokens
+/// de una consulta como `CREATE TABLE [IF NOT EXISTS] tabla (columna TIPO, ...)`.
+pub struct CreateBuilder {
+    tokens: VecDeque<Token>,
+}
+
+impl CreateBuilder {
+    /// Crea una nueva instancia de `CreateBuilder`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens (`VecDeque<Token>`) que se utilizarán para construir la consulta.
+    pub fn new(tokens: VecDeque<Token>) -> Self {
+        Self { tokens }
+    }
+
+    /// Analiza la definición de las columnas de la tabla, encerrada entre paréntesis.
+    ///
+    /// Cada columna se define con su nombre seguido de su tipo (`INT`, `TEXT`, etc).
+    ///
+    /// # Retorno
+    ///
+    /// Retorna el esquema con las columnas definidas, en el orden en que fueron declaradas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falta alguno de los paréntesis, si una columna no tiene un tipo
+    /// válido, si una columna se repite o si no se define ninguna columna.
+    fn parse_definitions(&mut self) -> Result<Schema, Errored> {
+        self.pop_expecting("(", ParenthesisOpen)?;
+        let mut schema = Schema::default();
+        loop {
+            let name = match self.tokens.pop_front() {
+                Some(t) if t.kind == ParenthesisClose => break,
+                Some(t) if t.kind == Identifier => t.value,
+                Some(t) => return unexpected_token_in_stage("COLUMN", &t).map(|_| schema),
+                None => errored!(Syntax, "expected ) closing column definitions."),
+            };
+            let kind = match self.tokens.pop_front() {
+                Some(t) if t.kind == Identifier => {
+                    ColumnType::from_name(&t.value).ok_or_else(|| {
+                        Syntax(format!("unknown type for column {}: {}", name, t.value))
+                    })?
+                }
+                Some(t) => return unexpected_token_in_stage("TYPE", &t).map(|_| schema),
+                None => errored!(Syntax, "expected type for column {}.", name),
+            };
+            schema.add_column(name, kind)?;
+        }
+        if schema.column_names().is_empty() {
+            errored!(Syntax, "CREATE TABLE needs at least one column.")
+        }
+        Ok(schema)
+    }
+}
+
+impl Builder for CreateBuilder {
+    /// Construye una consulta `CREATE TABLE` a partir de los tokens proporcionados.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un objeto `Query` con el nombre de la tabla y el esquema de sus columnas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error `Errored` si la consulta contiene errores de sintaxis o palabras clave no permitidas.
+    fn build(&mut self) -> Result<Query, Errored> {
+        let mut query = Query::default();
+        self.validate_keywords()?;
+        query.operation = Create;
+        if self.peek_expecting("IF NOT EXISTS", Keyword).is_ok() {
+            self.tokens.pop_front();
+            query.if_exists = true;
+        }
+        query.table = self.parse_table(Create)?;
+        if query.table.contains('.') {
+            errored!(Syntax, "invalid table name: {}", query.table)
+        }
+        query.schema = self.parse_definitions()?;
+        self.expect_none()?;
+        Ok(query)
+    }
+
+    /// Retorna una referencia mutable a los tokens que se están procesando.
+    fn tokens(&mut self) -> &mut VecDeque<Token> {
+        &mut self.tokens
+    }
+
+    /// Valida las palabras clave permitidas en una consulta `CREATE TABLE`.
+    ///
+    /// La única palabra clave permitida es `IF NOT EXISTS`.
+    fn validate_keywords(&self) -> Result<(), Errored> {
+        validate_keywords(ALLOWED_KEYWORDS, &self.tokens, Create)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::structs::operation::Operation::Create;
+    use crate::query::structs::query::Query;
+    use crate::query::structs::schema::ColumnType::{Int, Text};
+    use crate::query::structs::token::Token;
+    use crate::query::tokenizer::Tokenizer;
+
+    fn tokenize(sql: &str) -> Vec<Token> {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.tokenize(sql).unwrap()
+    }
+
+    #[test]
+    fn test_create_table() {
+        let sql = "CREATE TABLE tiendas (id INT, ciudad TEXT, zip varchar)";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Create);
+        assert_eq!(query.table, "tiendas");
+        assert!(!query.if_exists);
+        assert_eq!(query.schema.column_names(), vec!["id", "ciudad", "zip"]);
+        assert_eq!(query.schema.column_type("id"), Some(&Int));
+        assert_eq!(query.schema.column_type("zip"), Some(&Text));
+    }
+
+    #[test]
+    fn test_create_table_if_not_exists() {
+        let sql = "create table if not exists tiendas (id INT)";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Create);
+        assert!(query.if_exists);
+    }
+
+    #[test]
+    fn test_create_table_invalid_definitions() {
+        let invalid = [
+            "CREATE TABLE tiendas",
+            "CREATE TABLE tiendas ()",
+            "CREATE TABLE tiendas (id)",
+            "CREATE TABLE tiendas (id FLOATY)",
+            "CREATE TABLE tiendas (id INT, id TEXT)",
+            "CREATE TABLE tiendas (id INT",
+            "CREATE TABLE tiendas (id INT) WHERE",
+        ];
+        for sql in invalid {
+            let mut tokenizer = Tokenizer::new();
+            if let Ok(tokens) = tokenizer.tokenize(sql) {
+                assert!(Query::from(tokens).is_err(), "{}", sql);
+            }
+        }
+    }
+}
@@ -6,7 +6,30 @@ use crate::query::structs::token::TokenKind::Keyword;
 use crate::utils::errors::Errored;
 use std::collections::VecDeque;
 
-const ALLOWED_KEYWORDS: &[&str] = &["FROM", "WHERE", "AND", "OR"];
+const ALLOWED_KEYWORDS: &[&str] = &[
+    "FROM",
+    "WHERE",
+    "AND",
+    "OR",
+    "IS NULL",
+    "IS NOT NULL",
+    "NULL",
+    "LIKE",
+    "NOT LIKE",
+    "ILIKE",
+    "NOT ILIKE",
+    "ESCAPE",
+    "IN",
+    "NOT IN",
+    "BETWEEN",
+    "NOT BETWEEN",
+    "EXISTS",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+];
 
 /// Constructor para consultas de eliminación (`DELETE`).
 ///
@@ -0,0 +1,116 @@
+use crate::errored;
+use crate::query::builder::{validate_keywords, Builder};
+use crate::query::structs::operation::Operation::Drop;
+use crate::query::structs::query::Query;
+use crate::query::structs::token::Token;
+use crate::query::structs::token::TokenKind::Keyword;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
+use std::collections::VecDeque;
+
+const ALLOWED_KEYWORDS: &[&str] = &["IF EXISTS"];
+
+/// Constructor para consultas de eliminación de tablas (`DROP TABLE`).
+///
+/// `DropBuilder` se encarga de construir una consulta a partir de los tokens de una
+/// consulta como `DROP TABLE [IF EXISTS] tabla`.
+pub struct DropBuilder {
+    tokens: VecDeque<Token>,
+}
+
+impl DropBuilder {
+    /// Crea una nueva instancia de `DropBuilder`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens (`VecDeque<Token>`) que se utilizarán para construir la consulta.
+    pub fn new(tokens: VecDeque<Token>) -> Self {
+        Self { tokens }
+    }
+}
+
+impl Builder for DropBuilder {
+    /// Construye una consulta `DROP TABLE` a partir de los tokens proporcionados.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un objeto `Query` con el nombre de la tabla a eliminar.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error `Errored` si la consulta contiene errores de sintaxis o palabras clave no permitidas.
+    fn build(&mut self) -> Result<Query, Errored> {
+        let mut query = Query::default();
+        self.validate_keywords()?;
+        query.operation = Drop;
+        if self.peek_expecting("IF EXISTS", Keyword).is_ok() {
+            self.tokens.pop_front();
+            query.if_exists = true;
+        }
+        query.table = self.parse_table(Drop)?;
+        if query.table.contains('.') {
+            errored!(Syntax, "invalid table name: {}", query.table)
+        }
+        self.expect_none()?;
+        Ok(query)
+    }
+
+    /// Retorna una referencia mutable a los tokens que se están procesando.
+    fn tokens(&mut self) -> &mut VecDeque<Token> {
+        &mut self.tokens
+    }
+
+    /// Valida las palabras clave permitidas en una consulta `DROP TABLE`.
+    ///
+    /// La única palabra clave permitida es `IF EXISTS`.
+    fn validate_keywords(&self) -> Result<(), Errored> {
+        validate_keywords(ALLOWED_KEYWORDS, &self.tokens, Drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::structs::operation::Operation::Drop;
+    use crate::query::structs::query::Query;
+    use crate::query::structs::token::Token;
+    use crate::query::tokenizer::Tokenizer;
+
+    fn tokenize(sql: &str) -> Vec<Token> {
+        let mut tokenizer = Tokenizer::new();
+        tokenizer.tokenize(sql).unwrap()
+    }
+
+    #[test]
+    fn test_drop_table() {
+        let sql = "DROP TABLE ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Drop);
+        assert_eq!(query.table, "ordenes");
+        assert!(!query.if_exists);
+    }
+
+    #[test]
+    fn test_drop_table_if_exists() {
+        let sql = "DROP TABLE IF EXISTS ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert!(query.if_exists);
+    }
+
+    #[test]
+    fn test_drop_table_extra_tokens() {
+        let sql = "DROP TABLE ordenes clientes";
+        let tokens = tokenize(sql);
+        assert!(Query::from(tokens).is_err());
+    }
+
+    #[test]
+    fn test_drop_table_dotted_name() {
+        let sql = "DROP TABLE ordenes.backup";
+        let tokens = tokenize(sql);
+        assert!(Query::from(tokens).is_err());
+    }
+}
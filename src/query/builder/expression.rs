@@ -1,9 +1,16 @@
 use crate::errored;
+use crate::query::structs::aggregate::{Aggregate, AggregateKind};
+use crate::query::structs::date::{parse_date, parse_timestamp, DatePart};
 use crate::query::structs::expression::ExpressionNode::{Empty, Leaf};
 use crate::query::structs::expression::ExpressionOperator::*;
-use crate::query::structs::expression::{ExpressionNode, ExpressionOperator};
+use crate::query::structs::expression::ExpressionResult::{Date, Str, Timestamp};
+use crate::query::structs::expression::{ExpressionNode, ExpressionOperator, ExpressionResult};
+use crate::query::structs::function::{Function, FunctionKind};
+use crate::query::structs::ordering::{OrderKind, Ordering};
+use crate::query::structs::query::Query;
 use crate::query::structs::token::TokenKind::Keyword;
 use crate::query::structs::token::{Token, TokenKind};
+use crate::query::structs::window::{Window, WindowKind};
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Syntax;
 use std::collections::VecDeque;
@@ -11,8 +18,8 @@ use std::collections::VecDeque;
 /// Estructura para analizar y construir expresiones lógicas en una consulta.
 ///
 /// `ExpressionBuilder` proporciona métodos recursivos para analizar expresiones condicionales
-/// como `AND`, `OR`, `NOT`, y comparaciones simples. Estas expresiones son comúnmente
-/// utilizadas en las cláusulas `WHERE` de las consultas SQL.
+/// como `AND`, `OR`, `NOT`, comparaciones simples y operaciones aritméticas. Estas expresiones
+/// son comúnmente utilizadas en las cláusulas `WHERE` de las consultas SQL.
 pub struct ExpressionBuilder;
 
 impl ExpressionBuilder {
@@ -25,7 +32,7 @@ impl ExpressionBuilder {
     /// es la que tiene menor precedencia, de esta manera la expresión sera parseada en el siguiente orden de
     /// predecencia:
     ///
-    /// - OR -> AND -> NOT -> (Parantesis)
+    /// - OR -> AND -> NOT -> Comparaciones -> (+, -) -> (*, /, %) -> (Parantesis)
     ///
     /// Teniendo prioridad los operadores que estan más la derecha.
     ///
@@ -169,12 +176,59 @@ impl ExpressionBuilder {
     ///
     /// Retorna un error si los tokens no forman una comparación válida.
     fn parse_comparisons(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
-        let left = ExpressionBuilder::parse_leaf(tokens)?;
+        let left = ExpressionBuilder::parse_arithmetic(tokens)?;
+        if let Some(operator) = ExpressionBuilder::parse_null_predicate(tokens) {
+            return Ok(ExpressionNode::Statement {
+                operator,
+                left: Box::new(left),
+                right: Box::new(Empty),
+            });
+        }
+        if let Some(keyword) = ExpressionBuilder::pop_pattern_keyword(tokens) {
+            let right = ExpressionBuilder::parse_arithmetic(tokens)?;
+            let escape = ExpressionBuilder::parse_escape(tokens)?;
+            let operator = match keyword.as_str() {
+                "LIKE" => Like(escape),
+                "NOT LIKE" => NotLike(escape),
+                "ILIKE" => ILike(escape),
+                _ => NotILike(escape),
+            };
+            return Ok(ExpressionNode::like(left, operator, right));
+        }
+        match tokens.front() {
+            Some(t) if t.kind == Keyword && (t.value == "IN" || t.value == "NOT IN") => {
+                let negated = tokens.pop_front().is_some_and(|t| t.value == "NOT IN");
+                let list = if ExpressionBuilder::is_subquery(tokens) {
+                    vec![ExpressionNode::Subquery(Box::new(
+                        ExpressionBuilder::parse_subquery(tokens)?,
+                    ))]
+                } else {
+                    ExpressionBuilder::parse_list(tokens)?
+                };
+                return Ok(ExpressionNode::in_list(left, list, negated));
+            }
+            Some(t) if t.kind == Keyword && (t.value == "BETWEEN" || t.value == "NOT BETWEEN") => {
+                let negated = tokens.pop_front().is_some_and(|t| t.value == "NOT BETWEEN");
+                let low = ExpressionBuilder::parse_arithmetic(tokens)?;
+                match tokens.pop_front() {
+                    Some(t) if t.kind == Keyword && t.value == "AND" => {}
+                    t => errored!(Syntax, "expected AND in BETWEEN, got: {:?}", t),
+                }
+                let high = ExpressionBuilder::parse_arithmetic(tokens)?;
+                return Ok(ExpressionNode::Between {
+                    expression: Box::new(left),
+                    low: Box::new(low),
+                    high: Box::new(high),
+                    negated,
+                });
+            }
+            _ => {}
+        }
         let operator = ExpressionBuilder::parse_simple_operator(tokens);
         if operator.is_err() {
             return Ok(left);
         }
-        let right = ExpressionBuilder::parse_leaf(tokens)?;
+        let right = ExpressionBuilder::parse_arithmetic(tokens)?;
         Ok(ExpressionNode::Statement {
             operator: operator?,
             left: Box::new(left),
@@ -182,7 +236,200 @@ impl ExpressionBuilder {
         })
     }
 
-    /// Analiza las hojas de una expresión, como identificadores, números o cadenas.
+    /// Analiza los predicados de nulidad `IS NULL` e `IS NOT NULL`, que siguen a un valor.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna el operador del predicado si el siguiente token es uno de ellos, consumiéndolo.
+    fn parse_null_predicate(tokens: &mut VecDeque<Token>) -> Option<ExpressionOperator> {
+        let operator = match tokens.front() {
+            Some(t) if t.kind == Keyword && t.value == "IS NULL" => IsNull,
+            Some(t) if t.kind == Keyword && t.value == "IS NOT NULL" => IsNotNull,
+            _ => return Option::None,
+        };
+        tokens.pop_front();
+        Some(operator)
+    }
+
+    /// Analiza la lista de valores entre paréntesis de un predicado `IN`, como `(1, 2, 3)`.
+    ///
+    /// Cada elemento de la lista es una expresión aritmética.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna los nodos de expresión de cada elemento de la lista.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la lista no está entre paréntesis o si está vacía.
+    fn parse_list(tokens: &mut VecDeque<Token>) -> Result<Vec<ExpressionNode>, Errored> {
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisOpen)?;
+        let mut list = vec![];
+        while let Some(t) = tokens.front() {
+            if t.kind == TokenKind::ParenthesisClose {
+                break;
+            }
+            list.push(ExpressionBuilder::parse_arithmetic(tokens)?);
+        }
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisClose)?;
+        if list.is_empty() {
+            errored!(Syntax, "IN list must have at least one value.")
+        }
+        Ok(list)
+    }
+
+    /// Extrae el siguiente token si es un operador de patrones (`LIKE`, `NOT LIKE`, `ILIKE`
+    /// o `NOT ILIKE`).
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna la palabra clave del operador, o `None` si el siguiente token no es uno de ellos.
+    fn pop_pattern_keyword(tokens: &mut VecDeque<Token>) -> Option<String> {
+        match tokens.front() {
+            Some(t)
+                if t.kind == Keyword
+                    && ["LIKE", "NOT LIKE", "ILIKE", "NOT ILIKE"].contains(&t.value.as_str()) =>
+            {
+                tokens.pop_front().map(|t| t.value)
+            }
+            _ => Option::None,
+        }
+    }
+
+    /// Analiza la cláusula opcional `ESCAPE` que sigue al patrón de un `LIKE`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna el caracter de escape, o `None` si el patrón no tiene cláusula `ESCAPE`.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si `ESCAPE` no está seguido de un string de exactamente un caracter.
+    fn parse_escape(tokens: &mut VecDeque<Token>) -> Result<Option<char>, Errored> {
+        match tokens.front() {
+            Some(t) if t.kind == Keyword && t.value == "ESCAPE" => tokens.pop_front(),
+            _ => return Ok(Option::None),
+        };
+        let escape = ExpressionBuilder::pop_kind(tokens, TokenKind::String)?;
+        let mut chars = escape.value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), Option::None) => Ok(Some(c)),
+            _ => errored!(
+                Syntax,
+                "ESCAPE must be a single character, got: '{}'",
+                escape.value
+            ),
+        }
+    }
+
+    /// Analiza las expresiones aritméticas con los operadores `+` y `-`.
+    ///
+    /// Son los operadores aritméticos de menor precedencia, por lo que delega en
+    /// `parse_multiplicative` el valor de cada término. Los operadores se asocian por
+    /// izquierda, de manera que `a - b - c` se evalúa como `(a - b) - c`.
+    ///
+    /// Este método también es el punto de entrada para analizar una expresión que produce
+    /// un valor, como las columnas calculadas de una proyección (`precio * cantidad`).
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un nodo de expresión con operadores de suma y resta.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si hay un problema con los tokens.
+    pub fn parse_arithmetic(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
+        let mut left = ExpressionBuilder::parse_multiplicative(tokens)?;
+        while let Some(operator) = ExpressionBuilder::peek_arithmetic_operator(tokens, &["+", "-"])
+        {
+            tokens.pop_front();
+            let right = ExpressionBuilder::parse_multiplicative(tokens)?;
+            left = ExpressionNode::Statement {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    /// Analiza las expresiones aritméticas con los operadores `*`, `/` y `%`.
+    ///
+    /// Similar a `parse_arithmetic`, pero para los operadores de mayor precedencia.
+    /// Cada factor es una hoja o una expresión entre paréntesis.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un nodo de expresión con operadores de multiplicación, división y módulo.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si hay un problema con los tokens.
+    fn parse_multiplicative(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
+        let mut left = ExpressionBuilder::parse_leaf(tokens)?;
+        while let Some(operator) =
+            ExpressionBuilder::peek_arithmetic_operator(tokens, &["*", "/", "%"])
+        {
+            tokens.pop_front();
+            let right = ExpressionBuilder::parse_leaf(tokens)?;
+            left = ExpressionNode::Statement {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    /// Obtiene el operador aritmético del siguiente token, sin consumirlo, si es uno de los esperados.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    /// - `symbols`: Los símbolos de los operadores aceptados en el nivel de precedencia actual.
+    fn peek_arithmetic_operator(
+        tokens: &VecDeque<Token>,
+        symbols: &[&str],
+    ) -> Option<ExpressionOperator> {
+        let t = tokens.front()?;
+        if t.kind != TokenKind::Operator || !symbols.contains(&t.value.as_str()) {
+            return Option::None;
+        }
+        match t.value.as_str() {
+            "+" => Some(Add),
+            "-" => Some(Subtract),
+            "*" => Some(Multiply),
+            "/" => Some(Divide),
+            "%" => Some(Modulo),
+            _ => Option::None,
+        }
+    }
+
+    /// Analiza las hojas de una expresión, como identificadores, números, cadenas o `NULL`.
     ///
     /// Este método maneja elementos básicos que no son operadores lógicos, como los valores literales.
     ///
@@ -204,7 +451,46 @@ impl ExpressionBuilder {
         let mut leaf = Empty;
         while let Some(t) = tokens.front() {
             match t.kind {
-                TokenKind::Identifier | TokenKind::Number | TokenKind::String => {
+                TokenKind::ParenthesisOpen if ExpressionBuilder::is_subquery(tokens) => {
+                    let query = ExpressionBuilder::parse_subquery(tokens)?;
+                    leaf = ExpressionNode::Subquery(Box::new(query));
+                    break;
+                }
+                Keyword if t.value == "CASE" => {
+                    leaf = ExpressionBuilder::parse_case(tokens)?;
+                    break;
+                }
+                Keyword if t.value == "EXISTS" => {
+                    tokens.pop_front();
+                    if !ExpressionBuilder::is_subquery(tokens) {
+                        errored!(Syntax, "EXISTS must be followed by a subquery.")
+                    }
+                    let query = ExpressionBuilder::parse_subquery(tokens)?;
+                    leaf = ExpressionNode::Exists(Box::new(query));
+                    break;
+                }
+                TokenKind::Identifier if ExpressionBuilder::is_window_call(tokens) => {
+                    let window = ExpressionBuilder::parse_window(tokens)?;
+                    leaf = ExpressionNode::Window(window);
+                    break;
+                }
+                TokenKind::Identifier if ExpressionBuilder::is_aggregate_call(tokens) => {
+                    let aggregate = ExpressionBuilder::parse_aggregate(tokens)?;
+                    leaf = ExpressionNode::Aggregate(aggregate);
+                    break;
+                }
+                TokenKind::Identifier if ExpressionBuilder::is_typed_literal(tokens) => {
+                    leaf = ExpressionBuilder::parse_typed_literal(tokens)?;
+                    break;
+                }
+                TokenKind::Identifier if ExpressionBuilder::is_function_call(tokens) => {
+                    let function = ExpressionBuilder::parse_function(tokens)?;
+                    leaf = ExpressionNode::Function(function);
+                    break;
+                }
+                TokenKind::Identifier | TokenKind::Number | TokenKind::String | Keyword
+                    if t.kind != Keyword || t.value == "NULL" =>
+                {
                     if let Some(t) = tokens.pop_front() {
                         leaf = Leaf(t);
                         break;
@@ -224,6 +510,555 @@ impl ExpressionBuilder {
         Ok(leaf)
     }
 
+    /// Analiza una expresión condicional `CASE`, que puede tener dos formas:
+    ///
+    /// - `CASE WHEN condición THEN valor ... [ELSE valor] END`, donde se elige el valor de la
+    ///   primera condición verdadera.
+    /// - `CASE expresión WHEN valor THEN valor ... [ELSE valor] END`, donde se elige el valor de
+    ///   la primera rama cuyo valor sea igual al de la expresión.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un nodo `Case` con el operando, las ramas y el valor por defecto de la expresión.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la expresión no tiene ninguna rama `WHEN`, si alguna rama no tiene
+    /// `THEN` o si falta el `END` final.
+    fn parse_case(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
+        ExpressionBuilder::pop_keyword(tokens, "CASE")?;
+        let operand = match tokens.front() {
+            Some(t) if t.kind == Keyword && t.value == "WHEN" => Option::None,
+            _ => Some(Box::new(ExpressionBuilder::parse_expressions(tokens)?)),
+        };
+        let mut branches = vec![];
+        while tokens
+            .front()
+            .is_some_and(|t| t.kind == Keyword && t.value == "WHEN")
+        {
+            tokens.pop_front();
+            let condition = ExpressionBuilder::parse_expressions(tokens)?;
+            ExpressionBuilder::pop_keyword(tokens, "THEN")?;
+            branches.push((condition, ExpressionBuilder::parse_expressions(tokens)?));
+        }
+        if branches.is_empty() {
+            errored!(
+                Syntax,
+                "CASE expression must have at least one WHEN branch."
+            )
+        }
+        let default = match tokens.front() {
+            Some(t) if t.kind == Keyword && t.value == "ELSE" => {
+                tokens.pop_front();
+                Some(Box::new(ExpressionBuilder::parse_expressions(tokens)?))
+            }
+            _ => Option::None,
+        };
+        ExpressionBuilder::pop_keyword(tokens, "END")?;
+        Ok(ExpressionNode::Case {
+            operand,
+            branches,
+            default,
+        })
+    }
+
+    /// Determina si los próximos tokens corresponden a una sub-consulta, es decir, un
+    /// paréntesis de apertura seguido de `SELECT`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    pub fn is_subquery(tokens: &VecDeque<Token>) -> bool {
+        match (tokens.front(), tokens.get(1)) {
+            (Some(open), Some(select)) => {
+                open.kind == TokenKind::ParenthesisOpen
+                    && select.kind == Keyword
+                    && select.value == "SELECT"
+            }
+            _ => false,
+        }
+    }
+
+    /// Analiza una sub-consulta entre paréntesis, como `(SELECT id FROM ordenes)`.
+    ///
+    /// Se extraen los tokens hasta el paréntesis que cierra la sub-consulta y se construye
+    /// con ellos una nueva consulta mediante `Query::from`, por lo que la sub-consulta se
+    /// valida igual que una consulta `SELECT` independiente.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna la consulta (`Query`) de la sub-consulta.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si los paréntesis no están balanceados o si la sub-consulta no es válida.
+    pub fn parse_subquery(tokens: &mut VecDeque<Token>) -> Result<Query, Errored> {
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisOpen)?;
+        let mut inner = vec![];
+        let mut depth = 0;
+        loop {
+            let t = match tokens.pop_front() {
+                Some(t) => t,
+                Option::None => errored!(Syntax, "subquery is missing a closing parenthesis."),
+            };
+            match t.kind {
+                TokenKind::ParenthesisOpen => depth += 1,
+                TokenKind::ParenthesisClose if depth == 0 => break,
+                TokenKind::ParenthesisClose => depth -= 1,
+                _ => {}
+            }
+            inner.push(t);
+        }
+        Query::from(inner)
+    }
+
+    /// Determina si los próximos tokens corresponden a la llamada de una función de agregación.
+    ///
+    /// Una llamada de agregación es un identificador con el nombre de una función conocida
+    /// (`COUNT`, `SUM`, `AVG`, `MIN`, `MAX`) seguido de un paréntesis de apertura.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    pub fn is_aggregate_call(tokens: &VecDeque<Token>) -> bool {
+        match (tokens.front(), tokens.get(1)) {
+            (Some(name), Some(next)) => {
+                name.kind == TokenKind::Identifier
+                    && next.kind == TokenKind::ParenthesisOpen
+                    && AggregateKind::from_name(&name.value).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Analiza la llamada a una función de agregación, como `COUNT(*)` o `SUM(cantidad)`.
+    ///
+    /// El argumento de la función debe ser una columna, salvo en `COUNT` donde también
+    /// se admite el operador `*`. La columna puede estar precedida por `DISTINCT`, en cuyo
+    /// caso la función solo considera los valores distintos, como en `COUNT(DISTINCT id)`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna la agregación (`Aggregate`) representada por los tokens.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la función no existe o si su argumento no es válido.
+    pub fn parse_aggregate(tokens: &mut VecDeque<Token>) -> Result<Aggregate, Errored> {
+        let name = tokens.pop_front().ok_or_else(|| {
+            Syntax("expected aggregate function but was end of query.".to_string())
+        })?;
+        let kind = AggregateKind::from_name(&name.value)
+            .ok_or_else(|| Syntax(format!("unknown aggregate function: {}", name.value)))?;
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisOpen)?;
+        let distinct = match tokens.front() {
+            Some(t) if t.kind == TokenKind::Keyword && t.value == "DISTINCT" => {
+                tokens.pop_front();
+                true
+            }
+            _ => false,
+        };
+        let field = ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier)
+            .or_else(|_| ExpressionBuilder::pop_kind(tokens, TokenKind::Operator))?;
+        if field.kind == TokenKind::Operator
+            && (field.value != "*" || kind != AggregateKind::Count || distinct)
+        {
+            errored!(Syntax, "invalid argument for {:?}: {}", kind, field.value)
+        }
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisClose)?;
+        Ok(Aggregate {
+            kind,
+            field,
+            distinct,
+        })
+    }
+
+    /// Determina si los próximos tokens corresponden a la llamada de una función de ventana.
+    ///
+    /// Una llamada de ventana es un identificador con el nombre de una función de ventana
+    /// (`ROW_NUMBER`, `RANK`, `LAG`, `LEAD`) seguido de un paréntesis de apertura, o una
+    /// llamada a `SUM` seguida de la palabra clave `OVER`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    pub fn is_window_call(tokens: &VecDeque<Token>) -> bool {
+        let kind = match (tokens.front(), tokens.get(1)) {
+            (Some(name), Some(next))
+                if name.kind == TokenKind::Identifier
+                    && next.kind == TokenKind::ParenthesisOpen =>
+            {
+                WindowKind::from_name(&name.value)
+            }
+            _ => return false,
+        };
+        match kind {
+            Some(WindowKind::Sum) => ExpressionBuilder::is_followed_by_over(tokens),
+            Some(_) => true,
+            Option::None => false,
+        }
+    }
+
+    /// Determina si la llamada que comienza en los próximos tokens está seguida de `OVER`.
+    fn is_followed_by_over(tokens: &VecDeque<Token>) -> bool {
+        let mut depth = 0;
+        for (i, t) in tokens.iter().enumerate().skip(1) {
+            match t.kind {
+                TokenKind::ParenthesisOpen => depth += 1,
+                TokenKind::ParenthesisClose if depth == 1 => {
+                    return tokens
+                        .get(i + 1)
+                        .is_some_and(|t| t.kind == Keyword && t.value == "OVER");
+                }
+                TokenKind::ParenthesisClose => depth -= 1,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Analiza la llamada a una función de ventana, como
+    /// `RANK() OVER (PARTITION BY ciudad ORDER BY edad DESC)` o `SUM(precio) OVER (ORDER BY id)`.
+    ///
+    /// `ROW_NUMBER` y `RANK` no reciben argumentos, `SUM` recibe una columna y `LAG`/`LEAD`
+    /// reciben una columna, opcionalmente seguida de la cantidad de filas de distancia y del
+    /// literal a devolver cuando esa fila no existe (`LAG(precio, 1, 0)`).
+    ///
+    /// La cláusula `OVER` puede tener una partición (`PARTITION BY` seguido de columnas) y un
+    /// ordenamiento (`ORDER BY` seguido de columnas con `ASC` o `DESC` opcional), en ese orden.
+    /// Ambos admiten también agregaciones, que se calculan por grupo antes que la función (ver
+    /// `parse_window_column`).
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna la función de ventana (`Window`) representada por los tokens.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la función no existe, si sus argumentos no son válidos o si la
+    /// cláusula `OVER` falta, está mal formada o contiene una expresión que no es una columna
+    /// ni una agregación.
+    pub fn parse_window(tokens: &mut VecDeque<Token>) -> Result<Window, Errored> {
+        let name = ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier)?;
+        let mut kind = WindowKind::from_name(&name.value)
+            .ok_or_else(|| Syntax(format!("unknown window function: {}", name.value)))?;
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisOpen)?;
+        let field = match kind {
+            WindowKind::RowNumber | WindowKind::Rank => Option::None,
+            _ => Some(ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier)?),
+        };
+        if let WindowKind::Lag { offset, default } | WindowKind::Lead { offset, default } =
+            &mut kind
+        {
+            if tokens.front().is_some_and(|t| t.kind == TokenKind::Number) {
+                let t = ExpressionBuilder::pop_kind(tokens, TokenKind::Number)?;
+                *offset = t.value.parse::<usize>()?;
+                if tokens
+                    .front()
+                    .is_some_and(|t| t.kind != TokenKind::ParenthesisClose)
+                {
+                    *default = ExpressionBuilder::parse_window_default(tokens)?;
+                }
+            }
+        }
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisClose)?;
+        ExpressionBuilder::pop_keyword(tokens, "OVER")?;
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisOpen)?;
+        let mut aggregates = vec![];
+        let mut partition_by = vec![];
+        if tokens
+            .front()
+            .is_some_and(|t| t.kind == Keyword && t.value == "PARTITION BY")
+        {
+            tokens.pop_front();
+            while tokens.front().is_some_and(|t| {
+                t.kind != TokenKind::ParenthesisClose
+                    && !(t.kind == Keyword && t.value == "ORDER BY")
+            }) {
+                partition_by.push(ExpressionBuilder::parse_window_column(
+                    tokens,
+                    &mut aggregates,
+                )?);
+            }
+            if partition_by.is_empty() {
+                errored!(Syntax, "PARTITION BY clause must have at least one column.")
+            }
+        }
+        let mut ordering = vec![];
+        if tokens
+            .front()
+            .is_some_and(|t| t.kind == Keyword && t.value == "ORDER BY")
+        {
+            tokens.pop_front();
+            while tokens
+                .front()
+                .is_some_and(|t| t.kind != TokenKind::ParenthesisClose)
+            {
+                let field = ExpressionBuilder::parse_window_column(tokens, &mut aggregates)?;
+                let kind = match tokens.front() {
+                    Some(t) if t.kind == Keyword && (t.value == "ASC" || t.value == "DESC") => {
+                        let desc = t.value == "DESC";
+                        tokens.pop_front();
+                        if desc {
+                            OrderKind::Desc
+                        } else {
+                            OrderKind::Asc
+                        }
+                    }
+                    _ => OrderKind::Asc,
+                };
+                ordering.push(Ordering { field, kind });
+            }
+            if ordering.is_empty() {
+                errored!(
+                    Syntax,
+                    "ORDER BY clause of {} must have at least one column.",
+                    name.value
+                )
+            }
+        }
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisClose)?;
+        Ok(Window {
+            kind,
+            field,
+            partition_by,
+            ordering,
+            aggregates,
+        })
+    }
+
+    /// Analiza una columna de la partición o del ordenamiento de una función de ventana, que
+    /// puede ser también una agregación (`RANK() OVER (ORDER BY SUM(precio) DESC)`).
+    ///
+    /// Las agregaciones se registran en `aggregates`, evitando duplicados, y se nombran con su
+    /// representación SQL, que es la columna en la que se guarda su valor en cada grupo.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si los próximos tokens no son una columna ni una agregación.
+    fn parse_window_column(
+        tokens: &mut VecDeque<Token>,
+        aggregates: &mut Vec<Aggregate>,
+    ) -> Result<Token, Errored> {
+        if ExpressionBuilder::is_aggregate_call(tokens) {
+            let aggregate = ExpressionBuilder::parse_aggregate(tokens)?;
+            let column = Token {
+                value: aggregate.to_string(),
+                kind: TokenKind::Identifier,
+            };
+            if !aggregates.contains(&aggregate) {
+                aggregates.push(aggregate);
+            }
+            return Ok(column);
+        }
+        match tokens.pop_front() {
+            Some(t) if t.kind == TokenKind::Identifier => Ok(t),
+            Some(t) => errored!(
+                Syntax,
+                "window clauses only accept columns or aggregate functions, got: {}",
+                t.value
+            ),
+            Option::None => errored!(Syntax, "expected window column but was end of query."),
+        }
+    }
+
+    /// Analiza el valor por defecto de `LAG` y `LEAD`, que debe ser un número, un texto o `NULL`.
+    fn parse_window_default(tokens: &mut VecDeque<Token>) -> Result<ExpressionResult, Errored> {
+        match tokens.pop_front() {
+            Some(t) if t.kind == TokenKind::Number => ExpressionResult::parse_number(&t.value),
+            Some(t) if t.kind == TokenKind::String => Ok(Str(t.value)),
+            Some(t) if t.kind == Keyword && t.value == "NULL" => Ok(ExpressionResult::Null),
+            Some(t) => errored!(Syntax, "invalid default value for window function: {:?}", t),
+            Option::None => errored!(Syntax, "expected default value but was end of query."),
+        }
+    }
+
+    /// Determina si los próximos tokens corresponden a la llamada de una función escalar.
+    ///
+    /// Una llamada es un identificador con el nombre de una función registrada en
+    /// `FunctionKind::from_name` (`UPPER`, `SUBSTR`, etc.) seguido de un paréntesis de apertura.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    pub fn is_function_call(tokens: &VecDeque<Token>) -> bool {
+        match (tokens.front(), tokens.get(1)) {
+            (Some(name), Some(next)) => {
+                name.kind == TokenKind::Identifier
+                    && next.kind == TokenKind::ParenthesisOpen
+                    && FunctionKind::from_name(&name.value).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Analiza la llamada a una función escalar, como `UPPER(nombre)` o `SUBSTR(email, 1, 5)`.
+    ///
+    /// Cada argumento es una expresión completa, por lo que puede contener operaciones,
+    /// columnas, literales u otras llamadas a funciones.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna la función (`Function`) representada por los tokens.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falta el paréntesis de cierre, si algún argumento no es válido o
+    /// si la función no recibe la cantidad de argumentos que espera.
+    pub fn parse_function(tokens: &mut VecDeque<Token>) -> Result<Function, Errored> {
+        let name = ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier)?;
+        let kind = FunctionKind::from_name(&name.value)
+            .ok_or_else(|| Syntax(format!("unknown function: {}", name.value)))?;
+        ExpressionBuilder::pop_kind(tokens, TokenKind::ParenthesisOpen)?;
+        let mut arguments = match kind {
+            FunctionKind::Extract => {
+                let part = ExpressionBuilder::parse_date_part(tokens)?;
+                ExpressionBuilder::pop_keyword(tokens, "FROM")?;
+                vec![part, ExpressionBuilder::parse_expressions(tokens)?]
+            }
+            FunctionKind::DateAdd | FunctionKind::DateSub => {
+                let value = ExpressionBuilder::parse_expressions(tokens)?;
+                match ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier) {
+                    Ok(t) if t.value.eq_ignore_ascii_case("INTERVAL") => {}
+                    _ => errored!(Syntax, "{} expects an INTERVAL.", name.value),
+                }
+                let amount = ExpressionBuilder::parse_arithmetic(tokens)?;
+                vec![value, amount, ExpressionBuilder::parse_date_part(tokens)?]
+            }
+            _ => vec![],
+        };
+        loop {
+            match tokens.front() {
+                Some(t) if t.kind == TokenKind::ParenthesisClose => {
+                    tokens.pop_front();
+                    break;
+                }
+                Some(_) => arguments.push(ExpressionBuilder::parse_expressions(tokens)?),
+                Option::None => errored!(
+                    Syntax,
+                    "call to {} is missing a closing parenthesis.",
+                    name.value
+                ),
+            }
+        }
+        Function::new(kind, arguments)
+    }
+
+    /// Analiza la parte de una fecha escrita como identificador, como `YEAR` en
+    /// `EXTRACT(YEAR FROM fecha)`.
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un nodo constante con el nombre de la parte, en mayúsculas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el siguiente token no es una parte de fecha válida.
+    fn parse_date_part(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
+        let t = ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier)?;
+        match DatePart::from_name(&t.value) {
+            Some(part) => Ok(ExpressionNode::Constant(Str(part.to_string()))),
+            Option::None => errored!(Syntax, "unknown date part: {}", t.value),
+        }
+    }
+
+    /// Determina si los próximos tokens corresponden a un literal de fecha, es decir, `DATE`
+    /// o `TIMESTAMP` seguido de un string, como en `DATE '2024-01-31'`.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tokens`: Cola de tokens a analizar.
+    pub fn is_typed_literal(tokens: &VecDeque<Token>) -> bool {
+        match (tokens.front(), tokens.get(1)) {
+            (Some(kind), Some(value)) => {
+                kind.kind == TokenKind::Identifier
+                    && (kind.value.eq_ignore_ascii_case("DATE")
+                        || kind.value.eq_ignore_ascii_case("TIMESTAMP"))
+                    && value.kind == TokenKind::String
+            }
+            _ => false,
+        }
+    }
+
+    /// Analiza un literal de fecha (`DATE '2024-01-31'`) o de fecha y hora
+    /// (`TIMESTAMP '2024-01-31 18:30:00'`).
+    ///
+    /// # Retorno
+    ///
+    /// Retorna un nodo constante con el valor del literal.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el string no tiene el formato del tipo indicado.
+    pub fn parse_typed_literal(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
+        let kind = ExpressionBuilder::pop_kind(tokens, TokenKind::Identifier)?;
+        let raw = ExpressionBuilder::pop_kind(tokens, TokenKind::String)?;
+        let value = if kind.value.eq_ignore_ascii_case("DATE") {
+            parse_date(&raw.value).map(Date)
+        } else {
+            parse_timestamp(&raw.value).map(Timestamp)
+        };
+        match value {
+            Some(value) => Ok(ExpressionNode::Constant(value)),
+            Option::None => errored!(
+                Syntax,
+                "invalid {} literal: '{}'",
+                kind.value.to_uppercase(),
+                raw.value
+            ),
+        }
+    }
+
+    /// Extrae el siguiente token si es la palabra clave esperada.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el siguiente token no es la palabra clave esperada.
+    fn pop_keyword(tokens: &mut VecDeque<Token>, keyword: &str) -> Result<Token, Errored> {
+        match tokens.front() {
+            Some(t) if t.kind == Keyword && t.value == keyword => {
+                ExpressionBuilder::pop_kind(tokens, Keyword)
+            }
+            Some(t) => errored!(Syntax, "expected {} but got: {:?}", keyword, t),
+            _ => errored!(Syntax, "expected {} but was end of query.", keyword),
+        }
+    }
+
+    /// Extrae el siguiente token si es del tipo esperado.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si no quedan tokens o si el siguiente token es de otro tipo.
+    fn pop_kind(tokens: &mut VecDeque<Token>, kind: TokenKind) -> Result<Token, Errored> {
+        match tokens.front() {
+            Some(t) if t.kind == kind => tokens
+                .pop_front()
+                .ok_or_else(|| Syntax(format!("expected {:?} token.", kind))),
+            Some(t) => errored!(Syntax, "expected {:?} token, got: {:?}", kind, t),
+            _ => errored!(Syntax, "expected {:?} token but was end of query.", kind),
+        }
+    }
+
     /// Analiza los operadores simples en las comparaciones.
     ///
     /// Este método reconoce operadores como `=`, `!=`, `>`, `<`, etc.
@@ -273,7 +1108,7 @@ mod tests {
 
     fn operator_should_be(node: &ExpressionNode, op: ExpressionOperator) {
         match node {
-            ExpressionNode::Statement { operator, .. } => {
+            ExpressionNode::Statement { operator, .. } | ExpressionNode::Like { operator, .. } => {
                 assert_eq!(*operator, op);
             }
             Empty => {
@@ -385,6 +1220,36 @@ mod tests {
         leaves_should_have_op(result, Equals, Or);
     }
 
+    #[test]
+    fn test_parse_aggregate_comparison() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "count"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Operator, "*"),
+            create_token(ParenthesisClose, ")"),
+            create_token(Operator, ">"),
+            create_token(Number, "5"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        operator_should_be(&result, GreaterThan);
+        assert_eq!(result.aggregates().len(), 1);
+        assert_eq!(result.aggregates()[0].to_string(), "COUNT(*)");
+    }
+
+    #[test]
+    fn test_parse_aggregate_invalid_argument() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "SUM"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Operator, "*"),
+            create_token(ParenthesisClose, ")"),
+        ]);
+
+        let result = ExpressionBuilder::parse_aggregate(&mut tokens);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_parse_invalid_token() {
         let mut tokens = VecDeque::from(vec![
@@ -397,4 +1262,306 @@ mod tests {
         let result = ExpressionBuilder::parse_expressions(&mut tokens);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_arithmetic_precedence() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "cantidad"),
+            create_token(Operator, "+"),
+            create_token(Identifier, "precio"),
+            create_token(Operator, "*"),
+            create_token(Number, "2"),
+            create_token(Operator, ">"),
+            create_token(Number, "10"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        operator_should_be(&result, GreaterThan);
+        assert_eq!(result.to_string(), "(cantidad + (precio * 2)) > 10");
+    }
+
+    #[test]
+    fn test_parse_arithmetic_left_associative() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Number, "10"),
+            create_token(Operator, "-"),
+            create_token(Number, "4"),
+            create_token(Operator, "-"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Number, "3"),
+            create_token(Operator, "%"),
+            create_token(Number, "2"),
+            create_token(ParenthesisClose, ")"),
+        ]);
+
+        let result = ExpressionBuilder::parse_arithmetic(&mut tokens).unwrap();
+        operator_should_be(&result, Subtract);
+        leaves_should_have_op(result, Subtract, Modulo);
+    }
+
+    #[test]
+    fn test_parse_null_predicates() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "x"),
+            create_token(Keyword, "IS NULL"),
+            create_token(Keyword, "OR"),
+            create_token(Identifier, "y"),
+            create_token(Keyword, "IS NOT NULL"),
+            create_token(Keyword, "AND"),
+            create_token(Identifier, "z"),
+            create_token(Operator, "="),
+            create_token(Keyword, "NULL"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        operator_should_be(&result, Or);
+        assert_eq!(
+            result.to_string(),
+            "(x IS NULL) OR ((y IS NOT NULL) AND (z = NULL))"
+        );
+    }
+
+    #[test]
+    fn test_parse_like_expressions() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "email"),
+            create_token(Keyword, "NOT ILIKE"),
+            create_token(String, "%!_%"),
+            create_token(Keyword, "ESCAPE"),
+            create_token(String, "!"),
+            create_token(Keyword, "AND"),
+            create_token(Identifier, "name"),
+            create_token(Keyword, "LIKE"),
+            create_token(String, "J%"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        leaves_should_have_op(result, NotILike(Some('!')), Like(Option::None));
+    }
+
+    #[test]
+    fn test_parse_invalid_escape() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "email"),
+            create_token(Keyword, "LIKE"),
+            create_token(String, "%"),
+            create_token(Keyword, "ESCAPE"),
+            create_token(String, "!!"),
+        ]);
+
+        assert!(ExpressionBuilder::parse_expressions(&mut tokens).is_err());
+    }
+
+    #[test]
+    fn test_parse_in_and_between() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "id"),
+            create_token(Keyword, "NOT IN"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Number, "1"),
+            create_token(Number, "2"),
+            create_token(ParenthesisClose, ")"),
+            create_token(Keyword, "AND"),
+            create_token(Identifier, "edad"),
+            create_token(Keyword, "BETWEEN"),
+            create_token(Number, "18"),
+            create_token(Keyword, "AND"),
+            create_token(Number, "30"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "(id NOT IN (1, 2)) AND (edad BETWEEN 18 AND 30)"
+        );
+        assert!(tokens.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_in_and_between() {
+        let mut empty_list = VecDeque::from(vec![
+            create_token(Identifier, "id"),
+            create_token(Keyword, "IN"),
+            create_token(ParenthesisOpen, "("),
+            create_token(ParenthesisClose, ")"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut empty_list).is_err());
+
+        let mut missing_and = VecDeque::from(vec![
+            create_token(Identifier, "edad"),
+            create_token(Keyword, "BETWEEN"),
+            create_token(Number, "18"),
+            create_token(Keyword, "OR"),
+            create_token(Number, "30"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut missing_and).is_err());
+    }
+
+    #[test]
+    fn test_parse_function_calls() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "upper"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "CONCAT"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "nombre"),
+            create_token(String, " "),
+            create_token(Identifier, "apellido"),
+            create_token(ParenthesisClose, ")"),
+            create_token(ParenthesisClose, ")"),
+            create_token(Operator, "="),
+            create_token(String, "JUAN PEREZ"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "UPPER(CONCAT(nombre, ' ', apellido)) = 'JUAN PEREZ'"
+        );
+        assert!(tokens.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_function_calls() {
+        let mut too_many_arguments = VecDeque::from(vec![
+            create_token(Identifier, "LENGTH"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "nombre"),
+            create_token(Identifier, "apellido"),
+            create_token(ParenthesisClose, ")"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut too_many_arguments).is_err());
+
+        let mut unclosed = VecDeque::from(vec![
+            create_token(Identifier, "TRIM"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "nombre"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut unclosed).is_err());
+    }
+
+    #[test]
+    fn test_parse_date_literals_and_functions() {
+        let mut tokens = VecDeque::from(vec![
+            create_token(Identifier, "extract"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "year"),
+            create_token(Keyword, "FROM"),
+            create_token(Identifier, "fecha"),
+            create_token(ParenthesisClose, ")"),
+            create_token(Operator, "="),
+            create_token(Number, "2024"),
+            create_token(Keyword, "AND"),
+            create_token(Identifier, "DATE_ADD"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "date"),
+            create_token(String, "2024-01-31"),
+            create_token(Identifier, "INTERVAL"),
+            create_token(Number, "2"),
+            create_token(Identifier, "day"),
+            create_token(ParenthesisClose, ")"),
+            create_token(Operator, "<"),
+            create_token(Identifier, "timestamp"),
+            create_token(String, "2024-02-02 10:00:00"),
+        ]);
+
+        let result = ExpressionBuilder::parse_expressions(&mut tokens).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "(EXTRACT(YEAR FROM fecha) = 2024) AND (DATE_ADD(DATE '2024-01-31', INTERVAL 2 DAY) < TIMESTAMP '2024-02-02 10:00:00')"
+        );
+        assert!(tokens.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_date_literals_and_functions() {
+        let mut invalid_literal = VecDeque::from(vec![
+            create_token(Identifier, "DATE"),
+            create_token(String, "31/01/2024"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut invalid_literal).is_err());
+
+        let mut missing_from = VecDeque::from(vec![
+            create_token(Identifier, "EXTRACT"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "YEAR"),
+            create_token(Identifier, "fecha"),
+            create_token(ParenthesisClose, ")"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut missing_from).is_err());
+
+        let mut unknown_part = VecDeque::from(vec![
+            create_token(Identifier, "DATE_SUB"),
+            create_token(ParenthesisOpen, "("),
+            create_token(Identifier, "fecha"),
+            create_token(Identifier, "INTERVAL"),
+            create_token(Number, "1"),
+            create_token(Identifier, "WEEK"),
+            create_token(ParenthesisClose, ")"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut unknown_part).is_err());
+    }
+
+    #[test]
+    fn test_parse_case_expressions() {
+        let mut searched = VecDeque::from(vec![
+            create_token(Keyword, "CASE"),
+            create_token(Keyword, "WHEN"),
+            create_token(Identifier, "edad"),
+            create_token(Operator, ">"),
+            create_token(Number, "60"),
+            create_token(Keyword, "THEN"),
+            create_token(String, "senior"),
+            create_token(Keyword, "ELSE"),
+            create_token(String, "adulto"),
+            create_token(Keyword, "END"),
+            create_token(Operator, "="),
+            create_token(String, "senior"),
+        ]);
+        let result = ExpressionBuilder::parse_expressions(&mut searched).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "CASE WHEN edad > 60 THEN 'senior' ELSE 'adulto' END = 'senior'"
+        );
+        assert!(searched.is_empty());
+
+        let mut simple = VecDeque::from(vec![
+            create_token(Keyword, "CASE"),
+            create_token(Identifier, "tipo"),
+            create_token(Keyword, "WHEN"),
+            create_token(String, "Fire"),
+            create_token(Keyword, "THEN"),
+            create_token(Number, "1"),
+            create_token(Keyword, "WHEN"),
+            create_token(String, "Water"),
+            create_token(Keyword, "THEN"),
+            create_token(Number, "2"),
+            create_token(Keyword, "END"),
+        ]);
+        let result = ExpressionBuilder::parse_expressions(&mut simple).unwrap();
+        assert_eq!(
+            result.to_string(),
+            "CASE tipo WHEN 'Fire' THEN 1 WHEN 'Water' THEN 2 END"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_case_expressions() {
+        let mut without_branches = VecDeque::from(vec![
+            create_token(Keyword, "CASE"),
+            create_token(Keyword, "ELSE"),
+            create_token(Number, "1"),
+            create_token(Keyword, "END"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut without_branches).is_err());
+
+        let mut without_end = VecDeque::from(vec![
+            create_token(Keyword, "CASE"),
+            create_token(Keyword, "WHEN"),
+            create_token(Identifier, "activo"),
+            create_token(Keyword, "THEN"),
+            create_token(Number, "1"),
+        ]);
+        assert!(ExpressionBuilder::parse_expressions(&mut without_end).is_err());
+    }
 }
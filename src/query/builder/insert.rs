@@ -1,14 +1,30 @@
 use crate::errored;
-use crate::query::builder::{unexpected_token_in_stage, validate_keywords, Builder};
+use crate::query::builder::expression::ExpressionBuilder;
+use crate::query::builder::{validate_keywords, Builder};
+use crate::query::structs::expression::ExpressionNode;
 use crate::query::structs::operation::Operation::Insert;
 use crate::query::structs::query::Query;
+use crate::query::structs::token::Token;
 use crate::query::structs::token::TokenKind::{Keyword, ParenthesisClose, ParenthesisOpen};
-use crate::query::structs::token::{Token, TokenKind};
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Syntax;
 use std::collections::VecDeque;
 
-const ALLOWED_KEYWORDS: &[&str] = &["VALUES"];
+const ALLOWED_KEYWORDS: &[&str] = &[
+    "VALUES",
+    "DEFAULT",
+    "NULL",
+    "AND",
+    "OR",
+    "NOT",
+    "IS NULL",
+    "IS NOT NULL",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+];
 
 /// Estructura `InsertBuilder` que permite construir una consulta de tipo INSERT.
 pub struct InsertBuilder {
@@ -29,43 +45,79 @@ impl InsertBuilder {
 
     /// Analiza los valores de inserción de una consulta SQL INSERT.
     ///
-    /// Este método espera encontrar la palabra clave `VALUES` seguida de un grupo de valores
-    /// entre paréntesis. Los valores pueden ser cadenas o números.
+    /// Este método espera encontrar la palabra clave `VALUES` seguida de uno o más grupos de
+    /// valores entre paréntesis. Cada valor es una expresión constante, construida con
+    /// `ExpressionBuilder::parse_arithmetic`: un literal (`'texto'`, `1.5`, `NULL`), una
+    /// operación (`1 + 2`), una llamada a una función (`UPPER('x')`) o una sub-consulta escalar.
+    ///
+    /// La palabra clave `DEFAULT` indica que la columna toma su valor por defecto, y se guarda
+    /// como una hoja con esa palabra clave.
     ///
     /// # Retorna
-    /// - Un `Result` que contiene un vector de vectores de `Token` representando los valores de inserción.
+    /// - Un `Result` que contiene las expresiones de cada grupo de valores a insertar.
     ///
     /// # Errores
-    /// - Retorna un error si no se encuentra la palabra clave `VALUES` o si los valores no están
-    ///   correctamente formateados.
-    fn parse_insert_values(&mut self) -> Result<Vec<Vec<Token>>, Errored> {
+    /// - Retorna un error si no se encuentra la palabra clave `VALUES`, si los valores no están
+    ///   correctamente formateados o si alguno hace referencia a columnas o agregaciones.
+    fn parse_insert_values(&mut self) -> Result<Vec<Vec<ExpressionNode>>, Errored> {
         self.pop_expecting("VALUES", Keyword)?;
         self.peek_expecting("(", ParenthesisOpen)?;
         let mut inserts = vec![];
-        let mut values = vec![];
-        while let Some(t) = self.tokens.front() {
-            match t.kind {
-                TokenKind::String | TokenKind::Number => {
-                    if let Some(token) = self.tokens.pop_front() {
-                        values.push(token);
+        while !self.tokens.is_empty() {
+            self.pop_expecting("(", ParenthesisOpen)?;
+            let mut values = vec![];
+            loop {
+                match self.tokens.front() {
+                    Some(t) if t.kind == ParenthesisClose => {
+                        self.tokens.pop_front();
+                        break;
                     }
+                    Some(t) if t.kind == Keyword && t.value == "DEFAULT" => {
+                        if let Some(t) = self.tokens.pop_front() {
+                            values.push(ExpressionNode::Leaf(t));
+                        }
+                    }
+                    Some(_) => values.push(parse_constant(&mut self.tokens)?),
+                    None => errored!(Syntax, "expected ) after VALUES but was end of query."),
                 }
-                ParenthesisOpen => {
-                    self.tokens.pop_front();
-                }
-                ParenthesisClose => {
-                    self.tokens.pop_front();
-                    inserts.push(values);
-                    values = vec![];
-                }
-                _ => unexpected_token_in_stage("VALUES", t)?,
             }
+            inserts.push(values);
         }
         Ok(inserts)
     }
 
+    /// Separa los tokens de la consulta SELECT cuyas filas se insertan (`INSERT INTO tabla
+    /// (columnas) SELECT ...`), que comienza con el primer `SELECT` o `WITH` fuera de paréntesis.
+    ///
+    /// Los tokens de la consulta SELECT se validan al construirla, por lo que se quitan de los
+    /// tokens del INSERT antes de validar sus palabras clave.
+    ///
+    /// # Retorna
+    /// - Los tokens de la consulta SELECT, o `None` si la inserción usa `VALUES`.
+    fn split_source(&mut self) -> Option<Vec<Token>> {
+        let mut depth = 0;
+        let mut start = None;
+        for (i, t) in self.tokens.iter().enumerate() {
+            match t.kind {
+                ParenthesisOpen => depth += 1,
+                ParenthesisClose => depth -= 1,
+                Keyword if depth == 0 && (t.value == "SELECT" || t.value == "WITH") => {
+                    start = Some(i);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        start.map(|i| self.tokens.split_off(i).into())
+    }
+
     /// Este método asegura que el número de valores en cada inserción coincida con el número
-    /// de columnas definidas en la consulta.
+    /// de columnas definidas en la consulta. Si la consulta omite las columnas, la cantidad
+    /// depende del encabezado de la tabla, por lo que se valida al ejecutar la inserción.
+    ///
+    /// Si las filas se obtienen de una consulta SELECT, se valida la cantidad de columnas que
+    /// proyecta. Cuando proyecta todas las columnas (`SELECT *`) la cantidad se conoce recién
+    /// al leer su tabla, por lo que se valida al ejecutar la inserción.
     ///
     /// # Parámetros
     /// - `query`: Referencia a la consulta `Query` que contiene las columnas y los valores de inserción.
@@ -76,10 +128,24 @@ impl InsertBuilder {
     /// # Errores
     /// - Retorna un error si el número de columnas y el número de valores en la inserción no coinciden.
     fn validate_inserts(&self, query: &Query) -> Result<(), Errored> {
+        if let Some(source) = &query.source {
+            let projected = source.columns.len();
+            if projected > 0 && !query.columns.is_empty() && projected != query.columns.len() {
+                errored!(
+                    Syntax,
+                    "expected {} columns but SELECT projects {}.",
+                    query.columns.len(),
+                    projected
+                )
+            }
+        }
+        if query.columns.is_empty() {
+            return Ok(());
+        }
         for insert in &query.inserts {
             let columns = query.columns.len();
             if insert.len() != columns {
-                let values: Vec<&String> = insert.iter().map(|t| &t.value).collect();
+                let values: Vec<String> = insert.iter().map(|v| v.to_string()).collect();
                 errored!(
                     Syntax,
                     "expected {} columns but insert has:\n{:?}",
@@ -98,6 +164,11 @@ impl Builder for InsertBuilder {
     /// Este método analiza los tokens, identifica las columnas, los valores a insertar y valida
     /// la estructura de la consulta.
     ///
+    /// Los valores pueden ser tuplas de expresiones constantes (`VALUES (...)`) o las filas
+    /// resultantes de una consulta SELECT (`INSERT INTO archivo (id, nombre) SELECT id, nombre
+    /// FROM usuarios`). Si se omite la lista de columnas, se insertan todas las columnas de la
+    /// tabla, en el orden de su encabezado.
+    ///
     /// # Retorna
     /// - Un `Result` que contiene la consulta `Query` si se construye exitosamente.
     ///
@@ -106,12 +177,17 @@ impl Builder for InsertBuilder {
     ///   inválidas.
     fn build(&mut self) -> Result<Query, Errored> {
         let mut query = Query::default();
+        let source = self.split_source();
         self.validate_keywords()?;
         query.operation = Insert;
         query.table = self.parse_table(Insert)?;
-        self.peek_expecting("(", ParenthesisOpen)?;
-        query.columns = self.parse_columns()?;
-        query.inserts = self.parse_insert_values()?;
+        if self.peek_expecting("(", ParenthesisOpen).is_ok() {
+            query.columns = self.parse_columns()?;
+        }
+        match source {
+            Some(tokens) => query.source = Some(Box::new(Query::from(tokens)?)),
+            None => query.inserts = self.parse_insert_values()?,
+        }
         self.expect_none()?;
         self.validate_inserts(&query)?;
         Ok(query)
@@ -138,9 +214,37 @@ impl Builder for InsertBuilder {
     }
 }
 
+/// Analiza uno de los valores de una inserción, que debe ser una expresión constante.
+///
+/// # Errores
+///
+/// Retorna un error si la expresión no está bien formada o si hace referencia a columnas o
+/// agregaciones, que no tienen valor fuera de una fila.
+fn parse_constant(tokens: &mut VecDeque<Token>) -> Result<ExpressionNode, Errored> {
+    let value = ExpressionBuilder::parse_arithmetic(tokens)?;
+    if let Some(column) = value.identifiers().first() {
+        errored!(
+            Syntax,
+            "VALUES can only contain constant expressions, got column: {}",
+            column.value
+        )
+    }
+    if let Some(aggregate) = value.aggregates().first() {
+        errored!(
+            Syntax,
+            "VALUES can only contain constant expressions, got: {}",
+            aggregate
+        )
+    }
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::query::structs::expression::ExpressionNode::Leaf;
+    use crate::query::structs::operation::Operation::Select;
+    use crate::query::structs::token::TokenKind;
     use crate::query::structs::token::TokenKind::{Identifier, Number, String};
     use crate::query::tokenizer::Tokenizer;
 
@@ -171,7 +275,10 @@ mod tests {
         );
         assert_eq!(
             query.inserts,
-            vec![vec![to_token("1", Number), to_token("Laptop", String),]]
+            vec![vec![
+                Leaf(to_token("1", Number)),
+                Leaf(to_token("Laptop", String)),
+            ]]
         );
     }
 
@@ -190,8 +297,11 @@ mod tests {
         assert_eq!(
             query.inserts,
             vec![
-                vec![to_token("1", Number), to_token("Laptop", String),],
-                vec![to_token("2", Number), to_token("PS4", String),]
+                vec![
+                    Leaf(to_token("1", Number)),
+                    Leaf(to_token("Laptop", String)),
+                ],
+                vec![Leaf(to_token("2", Number)), Leaf(to_token("PS4", String))]
             ]
         );
     }
@@ -225,4 +335,85 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("expected"));
     }
+
+    #[test]
+    fn test_insert_select() {
+        let sql = "INSERT INTO archivo (id, nombre) SELECT id, nombre FROM usuarios WHERE edad > 30 UNION SELECT id, producto FROM ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Insert);
+        assert_eq!(query.table, "archivo");
+        assert_eq!(
+            query.columns,
+            vec![to_token("id", Identifier), to_token("nombre", Identifier)]
+        );
+        assert!(query.inserts.is_empty());
+        let source = query.source.unwrap();
+        assert_eq!(source.operation, Select);
+        assert_eq!(source.table, "usuarios");
+        assert_eq!(source.compounds.len(), 1);
+    }
+
+    #[test]
+    fn test_insert_select_invalid() {
+        let sql = "INSERT INTO archivo (id, nombre) SELECT id FROM usuarios";
+        let result = Query::from(tokenize(sql));
+        assert!(result.is_err_and(|e| e.to_string().contains("expected 2 columns")));
+
+        let sql = "INSERT INTO archivo (id) VALUES (1) SELECT id FROM usuarios";
+        assert!(Query::from(tokenize(sql)).is_err());
+
+        let sql = "INSERT INTO archivo (id) SELECT FROM usuarios";
+        assert!(Query::from(tokenize(sql)).is_err());
+
+        let sql = "INSERT INTO archivo (id) SELECT * FROM usuarios";
+        assert!(Query::from(tokenize(sql)).is_ok());
+    }
+
+    #[test]
+    fn test_insert_expressions_and_default() {
+        let sql = "INSERT INTO ordenes (id, producto, cantidad) VALUES (1 + 2, UPPER('x'), DEFAULT), (-1, NULL, 2 * (3 - 1))";
+        let query = Query::from(tokenize(sql)).unwrap();
+
+        let values: Vec<Vec<std::string::String>> = query
+            .inserts
+            .iter()
+            .map(|insert| insert.iter().map(|v| v.to_string()).collect())
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                vec!["1 + 2", "UPPER('x')", "DEFAULT"],
+                vec!["-1", "NULL", "2 * (3 - 1)"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_insert_without_columns() {
+        let sql = "INSERT INTO ordenes VALUES (1, 'Laptop', 3)";
+        let query = Query::from(tokenize(sql)).unwrap();
+        assert!(query.columns.is_empty());
+        assert_eq!(query.inserts[0].len(), 3);
+
+        let sql = "INSERT INTO archivo SELECT * FROM ordenes";
+        let query = Query::from(tokenize(sql)).unwrap();
+        assert!(query.columns.is_empty());
+        assert!(query.source.is_some());
+    }
+
+    #[test]
+    fn test_insert_invalid_expressions() {
+        let invalid = [
+            "INSERT INTO ordenes (id) VALUES (id + 1)",
+            "INSERT INTO ordenes (id) VALUES (COUNT(*))",
+            "INSERT INTO ordenes (id) VALUES (1 +)",
+            "INSERT INTO ordenes (id) VALUES (1) 2",
+            "INSERT INTO ordenes (id) VALUES (1) WHERE 1 = 1",
+        ];
+        for sql in invalid {
+            assert!(Query::from(tokenize(sql)).is_err(), "{}", sql);
+        }
+    }
 }
@@ -1,4 +1,7 @@
+pub mod alter;
+pub mod create;
 pub mod delete;
+pub mod drop;
 pub mod expression;
 pub mod insert;
 pub mod select;
@@ -8,7 +11,9 @@ use crate::errored;
 use crate::query::builder::expression::ExpressionBuilder;
 use crate::query::structs::expression::ExpressionNode;
 use crate::query::structs::operation::Operation;
-use crate::query::structs::operation::Operation::{Delete, Insert, Select, Unknown, Update};
+use crate::query::structs::operation::Operation::{
+    Alter, Create, Delete, Drop, Insert, Select, Unknown, Update,
+};
 use crate::query::structs::query::Query;
 use crate::query::structs::token::TokenKind::{
     Identifier, Keyword, Operator, ParenthesisClose, ParenthesisOpen,
@@ -126,10 +131,18 @@ pub trait Builder {
     ///
     /// # Errores
     ///
-    /// Retorna un error `Errored` si no se encuentra o se procesa incorrectamente la cláusula `WHERE`.
+    /// Retorna un error `Errored` si no se encuentra o se procesa incorrectamente la cláusula `WHERE`,
+    /// o si la condición utiliza funciones de agregación.
     fn parse_where(&mut self) -> Result<ExpressionNode, Errored> {
         self.pop_expecting("WHERE", Keyword)?;
-        ExpressionBuilder::parse_expressions(self.tokens())
+        let conditions = ExpressionBuilder::parse_expressions(self.tokens())?;
+        if !conditions.aggregates().is_empty() {
+            errored!(
+                Syntax,
+                "aggregate functions are not allowed in WHERE clause."
+            )
+        }
+        Ok(conditions)
     }
 
     /// Valida que no haya más tokens después de la consulta.
@@ -204,7 +217,7 @@ pub trait Builder {
 ///
 /// # Retorno
 ///
-/// Retorna el tipo de operación (`Operation`), como `Select`, `Insert`, `Update`, `Delete`, o `Unknown` si no se reconoce la palabra clave.
+/// Retorna el tipo de operación (`Operation`), como `Select`, `Insert`, `Update`, `Delete`, `Create`, `Drop`, `Alter`, o `Unknown` si no se reconoce la palabra clave.
 pub fn get_kind(token: Option<Token>) -> Operation {
     match token {
         Some(t) => match t.value.as_str() {
@@ -212,6 +225,9 @@ pub fn get_kind(token: Option<Token>) -> Operation {
             "INSERT INTO" => Insert,
             "UPDATE" => Update,
             "DELETE" => Delete,
+            "CREATE TABLE" => Create,
+            "DROP TABLE" => Drop,
+            "ALTER TABLE" => Alter,
             _ => Unknown,
         },
         None => Unknown,
@@ -220,6 +236,10 @@ pub fn get_kind(token: Option<Token>) -> Operation {
 
 /// Valida que solo se usen palabras clave permitidas en una consulta SQL.
 ///
+/// Las palabras clave dentro de una sub-consulta (`(SELECT ...)`) no se validan, ya que la
+/// sub-consulta se valida por separado al construirse como una consulta `SELECT`. Tampoco se
+/// valida el `FROM` que separa los argumentos de `EXTRACT(YEAR FROM fecha)`.
+///
 /// # Parámetros
 ///
 /// - `allowed`: Un arreglo de palabras clave permitidas.
@@ -238,8 +258,34 @@ fn validate_keywords(
     tokens: &VecDeque<Token>,
     operation: Operation,
 ) -> Result<(), Errored> {
-    let keywords: VecDeque<&Token> = tokens.iter().filter(|t| t.kind == Keyword).collect();
-    for word in keywords {
+    let mut spans = vec![];
+    for (i, word) in tokens.iter().enumerate() {
+        match word.kind {
+            ParenthesisOpen => {
+                let select = tokens.get(i + 1);
+                let function = i.checked_sub(1).and_then(|i| tokens.get(i));
+                spans.push(
+                    if select.is_some_and(|t| t.kind == Keyword && t.value == "SELECT") {
+                        Span::Subquery
+                    } else if function.is_some_and(|t| {
+                        t.kind == Identifier && t.value.eq_ignore_ascii_case("EXTRACT")
+                    }) {
+                        Span::Extract
+                    } else {
+                        Span::Group
+                    },
+                );
+                continue;
+            }
+            ParenthesisClose => {
+                spans.pop();
+                continue;
+            }
+            Keyword if spans.contains(&Span::Subquery) => continue,
+            Keyword if word.value == "FROM" && spans.last() == Some(&Span::Extract) => continue,
+            Keyword => {}
+            _ => continue,
+        }
         if !allowed.contains(&&*word.value) {
             errored!(
                 Syntax,
@@ -252,6 +298,17 @@ fn validate_keywords(
     Ok(())
 }
 
+/// Tipo de paréntesis abierto durante la validación de las palabras clave de una consulta.
+#[derive(PartialEq)]
+enum Span {
+    /// Una sub-consulta, `(SELECT ...)`.
+    Subquery,
+    /// Los argumentos de `EXTRACT`, que incluyen un `FROM`.
+    Extract,
+    /// Cualquier otro paréntesis.
+    Group,
+}
+
 /// Lanza un error cuando se encuentra un token inesperado durante el análisis de una consulta.
 ///
 /// # Parámetros
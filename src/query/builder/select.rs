@@ -1,15 +1,65 @@
+use crate::errored;
+use crate::query::builder::expression::ExpressionBuilder;
 use crate::query::builder::{unexpected_token_in_stage, validate_keywords, Builder};
+use crate::query::structs::aggregate::Aggregate;
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::join::JoinKind::{Inner, Left};
+use crate::query::structs::join::{Join, JoinKind};
 use crate::query::structs::operation::Operation::Select;
 use crate::query::structs::ordering::OrderKind::{Asc, Desc};
 use crate::query::structs::ordering::Ordering;
 use crate::query::structs::query::Query;
 use crate::query::structs::token::Token;
-use crate::query::structs::token::TokenKind::{Identifier, Keyword};
+use crate::query::structs::token::TokenKind;
+use crate::query::structs::token::TokenKind::{
+    Identifier, Keyword, Number, Operator, ParenthesisOpen,
+};
+use crate::query::structs::window::Window;
 use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
 use std::collections::VecDeque;
 
 const ALLOWED_KEYWORDS: &[&str] = &[
-    "SELECT", "FROM", "WHERE", "ORDER BY", "ASC", "DESC", "AND", "OR", "NOT",
+    "SELECT",
+    "DISTINCT",
+    "FROM",
+    "WHERE",
+    "ORDER BY",
+    "GROUP BY",
+    "HAVING",
+    "LIMIT",
+    "OFFSET",
+    "ASC",
+    "DESC",
+    "AS",
+    "AND",
+    "OR",
+    "NOT",
+    "IS NULL",
+    "IS NOT NULL",
+    "NULL",
+    "LIKE",
+    "NOT LIKE",
+    "ILIKE",
+    "NOT ILIKE",
+    "ESCAPE",
+    "IN",
+    "NOT IN",
+    "BETWEEN",
+    "NOT BETWEEN",
+    "EXISTS",
+    "OVER",
+    "PARTITION BY",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+    "JOIN",
+    "INNER JOIN",
+    "LEFT JOIN",
+    "LEFT OUTER JOIN",
+    "ON",
 ];
 
 /// Esta estructura procesa los tokens de una consulta SQL y construye una consulta SELECT
@@ -30,22 +80,233 @@ impl SelectBuilder {
         Self { tokens }
     }
 
+    /// Analiza las columnas de la proyección de una consulta SELECT.
+    ///
+    /// A diferencia de `Builder::parse_columns`, la proyección de un SELECT admite funciones
+    /// de agregación (`COUNT(*)`, `SUM(cantidad)`, etc). Cada agregación se registra en
+    /// `aggregates` y se proyecta como una columna con su nombre SQL.
+    ///
+    /// También admite literales (`'texto'`, `1.5`, `NULL`, `DATE '2024-01-01'`), expresiones
+    /// aritméticas (`precio * cantidad`) y funciones escalares (`UPPER(nombre)`) o condicionales
+    /// (`CASE WHEN ... END`), que se registran en `computed` y se evalúan en cada fila
+    /// resultante. Si no tienen alias, se proyectan como una columna nombrada con su
+    /// representación SQL (`precio * cantidad`).
+    ///
+    /// Las funciones de ventana (`RANK() OVER (ORDER BY edad)`) se registran en `windows` y se
+    /// proyectan, al igual que las agregaciones, como una columna con su nombre SQL.
+    ///
+    /// Cada columna puede estar seguida de un alias (`AS alias`), que se guarda en `aliases`
+    /// en la misma posición que la columna.
+    ///
+    /// # Parámetros
+    /// - `aggregates`: Las agregaciones registradas hasta el momento en la consulta.
+    /// - `computed`: Las expresiones calculadas registradas hasta el momento en la consulta.
+    /// - `windows`: Las funciones de ventana registradas hasta el momento en la consulta.
+    /// - `aliases`: Los alias de las columnas proyectadas.
+    ///
+    /// # Retorna
+    /// - Un `Result` con las columnas proyectadas, vacío si se utilizó el operador `*`.
+    ///
+    /// # Errores
+    /// - Retorna un error si se encuentra un token inesperado o si no hay columnas antes del `FROM`.
+    fn parse_projection(
+        &mut self,
+        aggregates: &mut Vec<Aggregate>,
+        computed: &mut Vec<ExpressionNode>,
+        windows: &mut Vec<Window>,
+        aliases: &mut Vec<Option<String>>,
+    ) -> Result<Vec<Token>, Errored> {
+        let mut fields: Vec<Token> = vec![];
+        while let Some(t) = self.tokens.front() {
+            match t.kind {
+                Identifier | Number | TokenKind::String | ParenthesisOpen => {
+                    let expression = ExpressionBuilder::parse_arithmetic(&mut self.tokens)?;
+                    fields.push(register_projection(
+                        aggregates, computed, windows, expression,
+                    )?);
+                    aliases.push(self.parse_alias()?);
+                }
+                Keyword if t.value == "CASE" || t.value == "NULL" => {
+                    let expression = ExpressionBuilder::parse_arithmetic(&mut self.tokens)?;
+                    fields.push(register_projection(
+                        aggregates, computed, windows, expression,
+                    )?);
+                    aliases.push(self.parse_alias()?);
+                }
+                Keyword if t.value == "FROM" => {
+                    if fields.is_empty() {
+                        errored!(Syntax, "read FROM without any * or fields in query.")
+                    }
+                    break;
+                }
+                Operator if t.value == "*" && fields.is_empty() => {
+                    self.tokens.pop_front();
+                    break;
+                }
+                _ => unexpected_token_in_stage("COLUMN", t)?,
+            }
+        }
+        Ok(fields)
+    }
+
+    /// Analiza el alias opcional (`AS alias`) de una columna de la proyección.
+    ///
+    /// # Retorna
+    /// - Un `Result` con el alias, o `None` si el siguiente token no es `AS`.
+    ///
+    /// # Errores
+    /// - Retorna un error si `AS` no está seguido de un identificador.
+    fn parse_alias(&mut self) -> Result<Option<String>, Errored> {
+        if self.peek_expecting("AS", Keyword).is_err() {
+            return Ok(None);
+        }
+        self.tokens.pop_front();
+        match self.tokens.pop_front() {
+            Some(t) if t.kind == Identifier && !t.value.contains('.') => Ok(Some(t.value)),
+            Some(t) => unexpected_token_in_stage("ALIAS", &t).map(|_| None),
+            None => errored!(Syntax, "expected alias after AS but was end of query."),
+        }
+    }
+
+    /// Analiza el alias opcional de una tabla, que puede escribirse con o sin `AS`
+    /// (`FROM usuarios AS u` o `FROM usuarios u`).
+    ///
+    /// # Retorna
+    /// - Un `Result` con el alias, o `None` si la tabla no lo declara.
+    ///
+    /// # Errores
+    /// - Retorna un error si `AS` no está seguido de un identificador.
+    fn parse_table_alias(&mut self) -> Result<Option<String>, Errored> {
+        match self.tokens.front() {
+            Some(t) if t.kind == Identifier && !t.value.contains('.') => {
+                Ok(self.tokens.pop_front().map(|t| t.value))
+            }
+            _ => self.parse_alias(),
+        }
+    }
+
+    /// Analiza las columnas de la cláusula `GROUP BY`.
+    ///
+    /// # Retorna
+    /// - Un `Result` con las columnas por las que se agrupa la consulta.
+    ///
+    /// # Errores
+    /// - Retorna un error si la cláusula no tiene columnas o contiene tokens que no son columnas.
+    fn parse_group_by(&mut self) -> Result<Vec<Token>, Errored> {
+        self.pop_expecting("GROUP BY", Keyword)?;
+        let mut groups = vec![];
+        while let Some(t) = self.tokens.front() {
+            match t.kind {
+                Identifier => {
+                    if let Some(group) = self.tokens.pop_front() {
+                        groups.push(group);
+                    }
+                }
+                Keyword => break,
+                _ => unexpected_token_in_stage("GROUP_BY", t)?,
+            }
+        }
+        if groups.is_empty() {
+            errored!(Syntax, "GROUP BY clause must have at least one column.")
+        }
+        Ok(groups)
+    }
+
+    /// Analiza la cláusula `HAVING` de la consulta.
+    ///
+    /// La condición se construye con `ExpressionBuilder::parse_expressions`, y cada agregación
+    /// que aparezca en ella se registra en `aggregates` para que sea calculada en cada grupo.
+    ///
+    /// # Parámetros
+    /// - `aggregates`: Las agregaciones registradas hasta el momento en la consulta.
+    ///
+    /// # Retorna
+    /// - Un `Result` con la expresión que deben cumplir los grupos.
+    ///
+    /// # Errores
+    /// - Retorna un error si la condición no está correctamente formada o si utiliza funciones
+    ///   de ventana, que se calculan luego de filtrar los grupos.
+    fn parse_having(&mut self, aggregates: &mut Vec<Aggregate>) -> Result<ExpressionNode, Errored> {
+        self.pop_expecting("HAVING", Keyword)?;
+        let having = ExpressionBuilder::parse_expressions(&mut self.tokens)?;
+        if !having.windows().is_empty() {
+            errored!(Syntax, "window functions are not allowed in HAVING.")
+        }
+        for aggregate in having.aggregates() {
+            register_aggregate(aggregates, aggregate.clone());
+        }
+        Ok(having)
+    }
+
+    /// Analiza y extrae las uniones (`JOIN`) de la consulta.
+    ///
+    /// Este método procesa los tokens que siguen a la tabla principal, consumiendo cada
+    /// palabra clave de unión seguida del nombre de la tabla, su alias opcional y la
+    /// condición `ON`.
+    ///
+    /// # Retorna
+    /// - Un `Result` que contiene un vector de `Join` con las tablas a unir.
+    ///
+    /// # Errores
+    /// - Retorna un error si falta la tabla o la condición `ON` de alguna unión.
+    fn parse_joins(&mut self) -> Result<Vec<Join>, Errored> {
+        let mut joins = vec![];
+        while let Some(kind) = self.tokens.front().and_then(get_join_kind) {
+            self.tokens.pop_front();
+            let t = self
+                .tokens
+                .pop_front()
+                .ok_or_else(|| Syntax("could not find table identifier to join.".to_string()))?;
+            if t.kind != Identifier {
+                unexpected_token_in_stage("JOIN", &t)?;
+            }
+            let alias = self.parse_table_alias()?;
+            self.pop_expecting("ON", Keyword)?;
+            let conditions = ExpressionBuilder::parse_expressions(&mut self.tokens)?;
+            joins.push(Join {
+                kind,
+                table: t.value,
+                alias,
+                conditions,
+            })
+        }
+        Ok(joins)
+    }
+
     /// Analiza y extrae las expresiones de ordenamiento de la consulta.
     ///
     /// Este método procesa los tokens después de la cláusula `ORDER BY` y construye
     /// una lista de ordenamientos basados en los campos y la dirección (ASC o DESC).
     ///
+    /// Los campos también pueden ser funciones de agregación, las cuales se registran
+    /// en `aggregates` para ser calculadas junto al resto.
+    ///
+    /// El análisis termina al llegar al final de la consulta o a la cláusula `LIMIT`/`OFFSET`.
+    ///
     /// # Retorna
     /// - Un `Result` que contiene un vector de `Ordering` representando las expresiones de ordenamiento.
     ///
     /// # Errores
     /// - Retorna un error si se encuentra un token inesperado en la fase de ordenamiento.
-    fn parse_ordering(&mut self) -> Result<Vec<Ordering>, Errored> {
+    fn parse_ordering(
+        &mut self,
+        aggregates: &mut Vec<Aggregate>,
+    ) -> Result<Vec<Ordering>, Errored> {
         let mut ordering = vec![];
-        while let Some(t) = self.tokens.pop_front() {
-            if t.kind != Identifier {
-                unexpected_token_in_stage("ORDER_BY", &t)?
+        while let Some(t) = self.tokens.front() {
+            if t.kind == Keyword && (t.value == "LIMIT" || t.value == "OFFSET") {
+                break;
             }
+            let t = if ExpressionBuilder::is_aggregate_call(&self.tokens) {
+                let aggregate = ExpressionBuilder::parse_aggregate(&mut self.tokens)?;
+                register_aggregate(aggregates, aggregate)
+            } else {
+                let t = self.tokens.pop_front().unwrap_or_default();
+                if t.kind != Identifier {
+                    unexpected_token_in_stage("ORDER_BY", &t)?
+                }
+                t
+            };
             let mut new_order = Ordering {
                 field: t,
                 ..Ordering::default()
@@ -63,6 +324,37 @@ impl SelectBuilder {
         }
         Ok(ordering)
     }
+
+    /// Analiza las cláusulas `LIMIT` y `OFFSET`, que siguen al ordenamiento de la consulta.
+    ///
+    /// # Parámetros
+    /// - `keyword`: La palabra clave a consumir (`LIMIT` u `OFFSET`).
+    ///
+    /// # Retorna
+    /// - Un `Result` con la cantidad de filas indicada luego de la palabra clave.
+    ///
+    /// # Errores
+    /// - Retorna un error si la palabra clave no está seguida de un número entero no negativo.
+    fn parse_limit(&mut self, keyword: &str) -> Result<usize, Errored> {
+        self.pop_expecting(keyword, Keyword)?;
+        match self.tokens.pop_front() {
+            Some(t) if t.kind == Number => match t.value.parse::<usize>() {
+                Ok(rows) => Ok(rows),
+                Err(_) => errored!(
+                    Syntax,
+                    "{} must be a non-negative integer, got: {}",
+                    keyword,
+                    t.value
+                ),
+            },
+            Some(t) => unexpected_token_in_stage(keyword, &t).map(|_| 0),
+            None => errored!(
+                Syntax,
+                "expected number after {} but was end of query.",
+                keyword
+            ),
+        }
+    }
 }
 
 impl Builder for SelectBuilder {
@@ -81,18 +373,44 @@ impl Builder for SelectBuilder {
         let mut query = Query::default();
         self.validate_keywords()?;
         query.operation = Select;
-        query.columns = self.parse_columns()?;
+        if self.peek_expecting("DISTINCT", Keyword).is_ok() {
+            self.tokens.pop_front();
+            query.distinct = true;
+        }
+        query.columns = self.parse_projection(
+            &mut query.aggregates,
+            &mut query.computed,
+            &mut query.windows,
+            &mut query.aliases,
+        )?;
         query.table = self.parse_table(Select)?;
+        query.table_alias = self.parse_table_alias()?;
+        query.joins = self.parse_joins()?;
+        validate_qualifiers(&query)?;
         if self.peek_expecting("WHERE", Keyword).is_ok() {
             query.conditions = self.parse_where()?;
-        }
-        match self.peek_expecting("ORDER BY", Keyword) {
-            Ok(_) => {
-                self.tokens.pop_front();
-                query.ordering = self.parse_ordering()?;
+            if !query.conditions.windows().is_empty() {
+                errored!(Syntax, "window functions are not allowed in WHERE.")
             }
-            Err(_) => self.expect_none()?,
         }
+        if self.peek_expecting("GROUP BY", Keyword).is_ok() {
+            query.group_by = self.parse_group_by()?;
+        }
+        if self.peek_expecting("HAVING", Keyword).is_ok() {
+            query.having = self.parse_having(&mut query.aggregates)?;
+        }
+        if self.peek_expecting("ORDER BY", Keyword).is_ok() {
+            self.tokens.pop_front();
+            query.ordering = self.parse_ordering(&mut query.aggregates)?;
+            resolve_ordering_aliases(&mut query);
+        }
+        if self.peek_expecting("LIMIT", Keyword).is_ok() {
+            query.limit = Some(self.parse_limit("LIMIT")?);
+        }
+        if self.peek_expecting("OFFSET", Keyword).is_ok() {
+            query.offset = self.parse_limit("OFFSET")?;
+        }
+        self.expect_none()?;
         Ok(query)
     }
 
@@ -119,9 +437,164 @@ impl Builder for SelectBuilder {
     }
 }
 
+/// Valida que la tabla principal y las tablas unidas puedan distinguirse entre sí.
+///
+/// Las columnas de cada tabla se califican con su alias o con su nombre (ver `Join::qualifier`),
+/// por lo que unir una tabla consigo misma requiere que al menos una de ellas tenga alias.
+///
+/// # Errores
+/// - Retorna un error si dos tablas de la consulta se califican con el mismo nombre.
+fn validate_qualifiers(query: &Query) -> Result<(), Errored> {
+    let mut qualifiers = vec![query.qualifier()];
+    for join in &query.joins {
+        if qualifiers.contains(&join.qualifier()) {
+            errored!(
+                Syntax,
+                "table name or alias {} is used more than once.",
+                join.qualifier()
+            )
+        }
+        qualifiers.push(join.qualifier());
+    }
+    Ok(())
+}
+
+/// Reemplaza los campos del ordenamiento que referencian el alias de una columna proyectada
+/// (`ORDER BY nombre_completo`) por la columna correspondiente.
+fn resolve_ordering_aliases(query: &mut Query) {
+    for order in &mut query.ordering {
+        let position = query
+            .aliases
+            .iter()
+            .position(|alias| alias.as_deref() == Some(order.field.value.as_str()));
+        if let Some(column) = position.and_then(|i| query.columns.get(i)) {
+            order.field = column.clone();
+        }
+    }
+}
+
+/// Registra una agregación en la lista de agregaciones de la consulta, evitando duplicados.
+///
+/// # Retorna
+/// - Un token identificador con el nombre de la columna que contendrá el valor de la agregación.
+fn register_aggregate(aggregates: &mut Vec<Aggregate>, aggregate: Aggregate) -> Token {
+    let column = Token {
+        value: aggregate.to_string(),
+        kind: Identifier,
+    };
+    if !aggregates.contains(&aggregate) {
+        aggregates.push(aggregate);
+    }
+    column
+}
+
+/// Registra una función de ventana de la consulta, evitando duplicados, junto a las
+/// agregaciones de su partición u ordenamiento.
+///
+/// # Retorna
+/// - Un token identificador con el nombre SQL de la función, que es el nombre de la columna
+///   que contendrá su valor en cada fila.
+fn register_window(
+    aggregates: &mut Vec<Aggregate>,
+    windows: &mut Vec<Window>,
+    window: Window,
+) -> Token {
+    for aggregate in &window.aggregates {
+        register_aggregate(aggregates, aggregate.clone());
+    }
+    let column = Token {
+        value: window.to_string(),
+        kind: Identifier,
+    };
+    if !windows.contains(&window) {
+        windows.push(window);
+    }
+    column
+}
+
+/// Registra una expresión de la proyección según su tipo.
+///
+/// Las columnas se proyectan tal cual, las agregaciones se registran con `register_aggregate`,
+/// las funciones de ventana con `register_window` y los literales, operaciones aritméticas, llamadas a funciones y expresiones `CASE` se
+/// registran como expresiones calculadas (ver `register_computed`).
+///
+/// # Retorna
+/// - Un token identificador con el nombre de la columna que contendrá el valor proyectado.
+///
+/// # Errores
+/// - Retorna un error si la expresión no es una columna, una agregación, una función de
+///   ventana, un literal, una operación aritmética, una llamada a una función o una expresión
+///   `CASE`.
+fn register_projection(
+    aggregates: &mut Vec<Aggregate>,
+    computed: &mut Vec<ExpressionNode>,
+    windows: &mut Vec<Window>,
+    expression: ExpressionNode,
+) -> Result<Token, Errored> {
+    match expression {
+        ExpressionNode::Leaf(t) if t.kind == Identifier => Ok(t),
+        ExpressionNode::Aggregate(aggregate) => Ok(register_aggregate(aggregates, aggregate)),
+        ExpressionNode::Window(window) => Ok(register_window(aggregates, windows, window)),
+        ExpressionNode::Statement { ref operator, .. } if operator.is_arithmetic() => {
+            Ok(register_computed(aggregates, computed, windows, expression))
+        }
+        ExpressionNode::Leaf(_)
+        | ExpressionNode::Constant(_)
+        | ExpressionNode::Function(_)
+        | ExpressionNode::Case { .. } => {
+            Ok(register_computed(aggregates, computed, windows, expression))
+        }
+        _ => errored!(Syntax, "invalid expression in projection: {}", expression),
+    }
+}
+
+/// Registra una expresión calculada de la proyección, evitando duplicados, junto a las
+/// agregaciones y funciones de ventana que contenga.
+///
+/// # Retorna
+/// - Un token identificador con la representación SQL de la expresión, que es el nombre
+///   de la columna que contendrá su valor.
+fn register_computed(
+    aggregates: &mut Vec<Aggregate>,
+    computed: &mut Vec<ExpressionNode>,
+    windows: &mut Vec<Window>,
+    expression: ExpressionNode,
+) -> Token {
+    for aggregate in expression.aggregates() {
+        register_aggregate(aggregates, aggregate.clone());
+    }
+    for window in expression.windows() {
+        register_window(aggregates, windows, window.clone());
+    }
+    let column = Token {
+        value: expression.to_string(),
+        kind: Identifier,
+    };
+    if !computed.contains(&expression) {
+        computed.push(expression);
+    }
+    column
+}
+
+/// Determina el tipo de unión a partir de un token.
+///
+/// # Retorna
+/// - El `JoinKind` correspondiente si el token es una palabra clave de unión, o `None` si no lo es.
+fn get_join_kind(token: &Token) -> Option<JoinKind> {
+    if token.kind != Keyword {
+        return None;
+    }
+    match token.value.as_str() {
+        "JOIN" | "INNER JOIN" => Some(Inner),
+        "LEFT JOIN" | "LEFT OUTER JOIN" => Some(Left),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::query::structs::expression::ExpressionNode::Empty;
+    use crate::query::structs::join::JoinKind::{Inner, Left};
     use crate::query::structs::operation::Operation::Select;
     use crate::query::structs::ordering::OrderKind::{Asc, Desc};
     use crate::query::structs::ordering::Ordering;
@@ -129,6 +602,7 @@ mod tests {
     use crate::query::structs::token::TokenKind::Identifier;
     use crate::query::structs::token::{Token, TokenKind};
     use crate::query::tokenizer::Tokenizer;
+    use crate::utils::errors::Errored;
 
     fn tokenize(sql: &str) -> Vec<Token> {
         let mut tokenizer = Tokenizer::new();
@@ -208,6 +682,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_select_with_joins() {
+        let sql = "SELECT users.name, ordenes.producto FROM users \
+            INNER JOIN ordenes ON users.id = ordenes.id_cliente \
+            LEFT JOIN pagos ON pagos.id_orden = ordenes.id WHERE users.id > 1";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Select);
+        assert_eq!(
+            query.columns,
+            vec![
+                to_token("users.name", Identifier),
+                to_token("ordenes.producto", Identifier),
+            ]
+        );
+        assert_eq!(query.table, "users");
+        assert_eq!(query.joins.len(), 2);
+        assert_eq!(query.joins[0].kind, Inner);
+        assert_eq!(query.joins[0].table, "ordenes");
+        assert_ne!(query.joins[0].conditions, Empty);
+        assert_eq!(query.joins[1].kind, Left);
+        assert_eq!(query.joins[1].table, "pagos");
+        assert_ne!(query.conditions, Empty);
+    }
+
+    #[test]
+    fn test_select_join_missing_on() {
+        let sql = "SELECT * FROM users JOIN ordenes WHERE users.id = 1";
+        let tokens = tokenize(sql);
+        let result = Query::from(tokens);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("ON"));
+    }
+
+    #[test]
+    fn test_select_with_group_by() {
+        let sql = "SELECT producto, COUNT(*), SUM(cantidad) FROM ordenes \
+            WHERE cantidad > 0 GROUP BY producto ORDER BY COUNT(*) DESC";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.operation, Select);
+        assert_eq!(
+            query.columns,
+            vec![
+                to_token("producto", Identifier),
+                to_token("COUNT(*)", Identifier),
+                to_token("SUM(cantidad)", Identifier),
+            ]
+        );
+        assert_eq!(query.group_by, vec![to_token("producto", Identifier)]);
+        assert_eq!(query.aggregates.len(), 2);
+        assert_eq!(query.ordering[0].field, to_token("COUNT(*)", Identifier));
+        assert!(query.is_grouped());
+    }
+
+    #[test]
+    fn test_select_with_having() {
+        let sql = "SELECT producto FROM ordenes GROUP BY producto \
+            HAVING COUNT(*) > 1 AND MAX(cantidad) < 3 ORDER BY producto";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.columns, vec![to_token("producto", Identifier)]);
+        assert_ne!(query.having, Empty);
+        let aggregates: Vec<String> = query.aggregates.iter().map(|a| a.to_string()).collect();
+        assert_eq!(aggregates, vec!["COUNT(*)", "MAX(cantidad)"]);
+        assert_eq!(query.ordering.len(), 1);
+    }
+
+    #[test]
+    fn test_select_aggregate_in_where() {
+        let sql = "SELECT producto FROM ordenes WHERE COUNT(*) > 1";
+        let tokens = tokenize(sql);
+        let result = Query::from(tokens);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("aggregate"));
+    }
+
+    #[test]
+    fn test_select_distinct() {
+        let sql = "SELECT DISTINCT id_cliente FROM ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert!(query.distinct);
+        assert_eq!(query.columns.len(), 1);
+        assert_eq!(query.columns[0].value, "id_cliente");
+    }
+
+    #[test]
+    fn test_select_count_distinct() {
+        let sql = "SELECT COUNT(DISTINCT id_cliente) FROM ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert!(!query.distinct);
+        assert_eq!(query.aggregates.len(), 1);
+        assert!(query.aggregates[0].distinct);
+        assert_eq!(query.columns[0].value, "COUNT(DISTINCT id_cliente)");
+    }
+
+    #[test]
+    fn test_select_with_limit_and_offset() {
+        let sql = "SELECT id FROM ordenes ORDER BY id DESC LIMIT 3 OFFSET 2";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.ordering.len(), 1);
+        assert_eq!(query.limit, Some(3));
+        assert_eq!(query.offset, 2);
+    }
+
+    #[test]
+    fn test_select_with_limit_only() {
+        let sql = "SELECT id FROM ordenes WHERE cantidad > 1 LIMIT 1";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert!(query.ordering.is_empty());
+        assert_eq!(query.limit, Some(1));
+        assert_eq!(query.offset, 0);
+    }
+
+    #[test]
+    fn test_select_invalid_limit() {
+        let sql = "SELECT id FROM ordenes LIMIT id";
+        let tokens = tokenize(sql);
+        let result = Query::from(tokens);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("LIMIT"));
+    }
+
+    #[test]
+    fn test_select_negative_limit() {
+        for sql in [
+            "SELECT id FROM ordenes LIMIT -1",
+            "SELECT id FROM ordenes LIMIT 2 OFFSET -3",
+            "SELECT id FROM ordenes LIMIT 1.5",
+        ] {
+            let result = Query::from(tokenize(sql));
+            assert!(
+                matches!(result, Err(Errored::Syntax(_))),
+                "{}: {:?}",
+                sql,
+                result
+            );
+        }
+    }
+
     #[test]
     fn test_select_invalid_keyword() {
         let sql = "SELECT id, name FROM users ORDER BY id DESC VALUES";
@@ -227,4 +855,157 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("FROM"));
     }
+
+    #[test]
+    fn test_select_arithmetic_projection() {
+        let sql = "SELECT id, precio * cantidad, SUM(cantidad) + 1 FROM ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(
+            query.columns,
+            vec![
+                to_token("id", Identifier),
+                to_token("precio * cantidad", Identifier),
+                to_token("SUM(cantidad) + 1", Identifier),
+            ]
+        );
+        assert_eq!(query.computed.len(), 2);
+        assert_eq!(query.aggregates.len(), 1);
+    }
+
+    #[test]
+    fn test_select_invalid_projection_expression() {
+        let sql = "SELECT id = 1 FROM ordenes";
+        let tokens = tokenize(sql);
+        let result = Query::from(tokens);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_select_with_aliases() {
+        let sql = "SELECT u.nombre AS cliente, COUNT(*) AS total, o.producto FROM users AS u \
+            JOIN ordenes o ON u.id = o.id_cliente GROUP BY u.nombre, o.producto ORDER BY total DESC";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(
+            query.aliases,
+            vec![Some("cliente".to_string()), Some("total".to_string()), None]
+        );
+        assert_eq!(query.table_alias, Some("u".to_string()));
+        assert_eq!(query.qualifier(), "u");
+        assert_eq!(query.joins[0].alias, Some("o".to_string()));
+        assert_eq!(query.joins[0].qualifier(), "o");
+        assert_eq!(
+            query.ordering[0],
+            Ordering {
+                field: to_token("COUNT(*)", Identifier),
+                kind: Desc,
+            }
+        );
+    }
+
+    #[test]
+    fn test_select_invalid_aliases() {
+        let invalid = [
+            "SELECT id AS FROM ordenes",
+            "SELECT id AS o.id FROM ordenes",
+            "SELECT id FROM ordenes AS",
+            "SELECT id FROM ordenes o JOIN users o ON o.id = o.id_cliente",
+            "SELECT id FROM ordenes JOIN ordenes ON ordenes.id = ordenes.id",
+        ];
+        for sql in invalid {
+            let result = Query::from(tokenize(sql));
+            assert!(result.is_err(), "debería fallar: {}", sql);
+        }
+    }
+
+    #[test]
+    fn test_select_literal_projection() {
+        let sql = "SELECT id, 'pendiente' AS estado, 1.5, NULL, DATE '2024-01-01' FROM ordenes";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(
+            query.columns,
+            vec![
+                to_token("id", Identifier),
+                to_token("'pendiente'", Identifier),
+                to_token("1.5", Identifier),
+                to_token("NULL", Identifier),
+                to_token("DATE '2024-01-01'", Identifier),
+            ]
+        );
+        assert_eq!(query.computed.len(), 4);
+        assert_eq!(query.aliases[1], Some("estado".to_string()));
+    }
+
+    #[test]
+    fn test_select_window_functions() {
+        let sql = "SELECT nombre, RANK() OVER (PARTITION BY ciudad ORDER BY edad DESC) AS puesto, LAG(edad, 1, 0) OVER (ORDER BY id) - edad FROM usuarios";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(
+            query.columns,
+            vec![
+                to_token("nombre", Identifier),
+                to_token(
+                    "RANK() OVER (PARTITION BY ciudad ORDER BY edad DESC)",
+                    Identifier
+                ),
+                to_token("LAG(edad, 1, 0) OVER (ORDER BY id) - edad", Identifier),
+            ]
+        );
+        assert_eq!(query.windows.len(), 2);
+        assert_eq!(query.computed.len(), 1);
+        assert!(query.aggregates.is_empty());
+        assert_eq!(query.aliases[1], Some("puesto".to_string()));
+
+        let sql = "SELECT id, SUM(precio) OVER (PARTITION BY usuario ORDER BY id) FROM ordenes";
+        let query = Query::from(tokenize(sql)).unwrap();
+        assert_eq!(query.windows.len(), 1);
+        assert!(!query.is_grouped());
+    }
+
+    #[test]
+    fn test_select_window_ordered_by_aggregate() {
+        let sql = "SELECT usuario, SUM(precio), RANK() OVER (PARTITION BY MAX(id) ORDER BY SUM(precio) DESC) FROM ordenes GROUP BY usuario";
+        let query = Query::from(tokenize(sql)).unwrap();
+
+        assert_eq!(
+            query.columns[2],
+            to_token(
+                "RANK() OVER (PARTITION BY MAX(id) ORDER BY SUM(precio) DESC)",
+                Identifier
+            )
+        );
+        let aggregates: Vec<String> = query.aggregates.iter().map(|a| a.to_string()).collect();
+        assert_eq!(aggregates, vec!["SUM(precio)", "MAX(id)"]);
+
+        let sql = "SELECT id, RANK() OVER (ORDER BY precio * cantidad) FROM ordenes";
+        let result = Query::from(tokenize(sql));
+        assert!(result.is_err_and(|e| e.to_string().contains("aggregate functions")));
+    }
+
+    #[test]
+    fn test_select_invalid_window_functions() {
+        let invalid = [
+            "SELECT ROW_NUMBER() FROM usuarios",
+            "SELECT RANK(edad) OVER (ORDER BY edad) FROM usuarios",
+            "SELECT LAG() OVER (ORDER BY edad) FROM usuarios",
+            "SELECT LEAD(edad, 1, ciudad) OVER (ORDER BY edad) FROM usuarios",
+            "SELECT ROW_NUMBER() OVER (PARTITION BY ORDER BY edad) FROM usuarios",
+            "SELECT ROW_NUMBER() OVER (ORDER BY) FROM usuarios",
+            "SELECT ROW_NUMBER() OVER ORDER BY edad FROM usuarios",
+            "SELECT nombre FROM usuarios WHERE ROW_NUMBER() OVER (ORDER BY edad) = 1",
+            "SELECT ciudad FROM usuarios GROUP BY ciudad HAVING RANK() OVER (ORDER BY ciudad) = 1",
+            "UPDATE usuarios SET edad = RANK() OVER (ORDER BY edad)",
+        ];
+        for sql in invalid {
+            assert!(Query::from(tokenize(sql)).is_err(), "{}", sql);
+        }
+    }
 }
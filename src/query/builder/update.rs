@@ -10,7 +10,30 @@ use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Syntax;
 use std::collections::VecDeque;
 
-const ALLOWED_KEYWORDS: &[&str] = &["SET", "WHERE", "AND", "OR"];
+const ALLOWED_KEYWORDS: &[&str] = &[
+    "SET",
+    "WHERE",
+    "AND",
+    "OR",
+    "IS NULL",
+    "IS NOT NULL",
+    "NULL",
+    "LIKE",
+    "NOT LIKE",
+    "ILIKE",
+    "NOT ILIKE",
+    "ESCAPE",
+    "IN",
+    "NOT IN",
+    "BETWEEN",
+    "NOT BETWEEN",
+    "EXISTS",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+];
 
 /// Esta estructura procesa los tokens de una consulta SQL y permite construir una consulta
 /// UPDATE con los valores a actualizar y las condiciones asociadas.
@@ -173,6 +196,29 @@ mod tests {
         assert_ne!(query.conditions, Empty);
     }
 
+    #[test]
+    fn test_update_with_function() {
+        let sql = "UPDATE clientes SET email = LOWER(TRIM(email)) WHERE id = 1";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.updates.len(), 1);
+        assert_eq!(query.updates[0].to_string(), "email = LOWER(TRIM(email))");
+        assert_ne!(query.conditions, Empty);
+    }
+
+    #[test]
+    fn test_update_with_expression() {
+        let sql = "UPDATE ordenes SET cantidad = cantidad + 1, id = 2 WHERE id = 1";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(query.updates.len(), 2);
+        assert_eq!(query.updates[0].to_string(), "cantidad = (cantidad + 1)");
+        assert_eq!(query.updates[1].to_string(), "id = 2");
+        assert_ne!(query.conditions, Empty);
+    }
+
     #[test]
     fn test_update_invalid_keyword() {
         let sql = "UPDATE ordenes SET quantity = 5 ORDER BY id";
@@ -192,4 +238,24 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("SET"));
     }
+
+    #[test]
+    fn test_update_with_subquery() {
+        let sql = "UPDATE ordenes SET cantidad = 0 WHERE id_cliente IN (SELECT id FROM clientes ORDER BY id) OR EXISTS (SELECT * FROM bajas)";
+        let tokens = tokenize(sql);
+        let query = Query::from(tokens).unwrap();
+
+        assert_eq!(
+            query.conditions.to_string(),
+            "(id_cliente IN (SELECT id FROM clientes)) OR EXISTS (SELECT * FROM bajas)"
+        );
+    }
+
+    #[test]
+    fn test_update_with_invalid_subquery() {
+        let sql =
+            "UPDATE ordenes SET cantidad = 0 WHERE id IN (SELECT id FROM clientes SET id = 1)";
+        let tokens = tokenize(sql);
+        assert!(Query::from(tokens).is_err());
+    }
 }
@@ -0,0 +1,56 @@
+use crate::query::executor::Executor;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
+use crate::utils::files::{
+    csv_records, extract_header, get_table_file, rewrite_table, split_csv_fields,
+};
+use std::io::{BufReader, Write};
+
+impl Executor {
+    /// Ejecuta la operación de modificación de las columnas de una tabla.
+    /// # Proceso
+    ///
+    /// 1. Abre el archivo de la tabla especificada y crea un archivo temporal para escribir la tabla modificada.
+    /// 2. Lee el encabezado del archivo y calcula el nuevo encabezado, validando la modificación.
+    /// 3. Calcula el nuevo esquema de la tabla, validando el valor por defecto de la columna agregada.
+    /// 4. Reescribe cada registro en el archivo temporal, agregando o quitando el valor correspondiente.
+    /// 5. Reemplaza el archivo original por el temporal y guarda el nuevo esquema, si la tabla tiene uno.
+    ///
+    /// # Errores
+    ///
+    /// Puede retornar un error si la modificación no es válida para la tabla, o si ocurre un problema
+    /// al leer o escribir los archivos.
+    pub fn run_alter(&self) -> Result<(), Errored> {
+        let alteration = self
+            .query
+            .alteration
+            .as_ref()
+            .ok_or_else(|| Syntax("missing ALTER TABLE action.".to_string()))?;
+        let table = get_table_file(&self.table_path)?;
+        let mut reader = BufReader::new(&table);
+        let header = extract_header(&mut reader)?;
+        let new_header = alteration.apply_to_header(&header)?;
+        let schema = alteration.apply_to_schema(self.schema.as_ref())?;
+        let null_marker = self
+            .schema
+            .as_ref()
+            .map(|s| s.null_marker())
+            .unwrap_or_default();
+        rewrite_table(&self.query.table, &self.table_path, |writer| {
+            writeln!(writer, "{}", new_header.join(","))?;
+            for record in csv_records(reader) {
+                let fields = split_csv_fields(&record?)?
+                    .iter()
+                    .map(|field| field.to_csv(null_marker))
+                    .collect();
+                let fields = alteration.apply_to_fields(&header, fields, null_marker)?;
+                writeln!(writer, "{}", fields.join(","))?
+            }
+            Ok(())
+        })?;
+        if let Some(schema) = schema {
+            schema.save(&self.table_path)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use crate::query::executor::compound::as_row;
+use crate::query::executor::subquery::Rows;
+use crate::query::executor::Executor;
+use crate::query::structs::common_table::CommonTable;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::Null;
+use crate::query::structs::schema::{ColumnType, Schema};
+use crate::utils::errors::Errored;
+use crate::utils::files::{build_table_path, escape_csv, get_table_path, get_temp_file};
+use std::collections::HashMap;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Tablas virtuales de una consulta (`WITH nombre AS (...)`), materializadas en archivos
+/// temporales dentro del directorio temporal del sistema, fuera del directorio de las tablas.
+///
+/// Cada tabla virtual se escribe junto a un esquema (ver `Schema`) con el tipo de sus columnas
+/// y la representación de `NULL` de la tabla de su consulta, de manera que sus valores se leen
+/// tal como fueron seleccionados (por ejemplo, un código postal `02110` sigue siendo un texto).
+///
+/// Los archivos temporales se eliminan cuando la estructura se descarta, es decir, al terminar
+/// la ejecución de la consulta, tanto si fue exitosa como si falló.
+#[derive(Default)]
+pub(super) struct VirtualTables {
+    pub(super) paths: HashMap<String, PathBuf>,
+}
+
+impl VirtualTables {
+    /// Materializa las tablas virtuales de una consulta, en el orden en que fueron definidas.
+    ///
+    /// Cada tabla virtual puede leer de las tablas virtuales definidas antes que ella, además
+    /// de las tablas virtuales ya disponibles en `inherited` (las de una consulta que contiene
+    /// a esta como sub-consulta).
+    ///
+    /// # Parámetros
+    ///
+    /// - `tables_path`: El directorio de las tablas.
+    /// - `inherited`: Las tablas virtuales disponibles antes de materializar las nuevas.
+    /// - `common_tables`: Las tablas virtuales a materializar.
+    ///
+    /// # Retorna
+    ///
+    /// Las tablas virtuales materializadas. Sus rutas, junto a las de `inherited`, deben usarse
+    /// para resolver las tablas de la consulta (ver `resolve_table_path`).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la ejecución de alguna tabla virtual o la escritura de sus archivos.
+    pub(super) fn materialize(
+        tables_path: &Path,
+        inherited: &HashMap<String, PathBuf>,
+        common_tables: Vec<CommonTable>,
+    ) -> Result<Self, Errored> {
+        let mut tables = VirtualTables::default();
+        let mut available = inherited.clone();
+        for common_table in common_tables {
+            let (mut executor, _virtual_tables) =
+                Executor::prepare(tables_path, &available, common_table.query)?;
+            let null_marker = executor
+                .schema
+                .as_ref()
+                .map(|s| s.null_marker())
+                .unwrap_or_default()
+                .to_string();
+            let (columns, rows) = executor.query_results()?;
+            let (file, path) = get_temp_file(
+                &common_table.name,
+                &build_table_path(&env::temp_dir(), &common_table.name)?,
+            )?;
+            tables
+                .paths
+                .insert(common_table.name.to_string(), path.clone());
+            let schema = result_schema(&columns, &rows, &null_marker)?;
+            schema.save(&path)?;
+            let mut writer = BufWriter::new(file);
+            let names: Vec<String> = columns.iter().map(|c| escape_csv(c)).collect();
+            writeln!(writer, "{}", names.join(","))?;
+            for values in rows {
+                let row = as_row(&columns, values, Some(&schema));
+                writeln!(writer, "{}", row.as_csv_row())?;
+            }
+            writer.flush()?;
+            available.insert(common_table.name, path);
+        }
+        Ok(tables)
+    }
+}
+
+impl Drop for VirtualTables {
+    /// Elimina los archivos temporales de las tablas virtuales y sus esquemas.
+    fn drop(&mut self) {
+        for path in self.paths.values() {
+            let _ = fs::remove_file(path);
+            let _ = Schema::remove(path);
+        }
+    }
+}
+
+/// Construye el esquema de una tabla virtual a partir de los valores seleccionados.
+///
+/// Cada columna se declara con el tipo de sus valores no nulos (los enteros y flotantes
+/// mezclados se declaran como flotantes). Las columnas sin valores, con valores de distintos
+/// tipos o cuyo nombre contiene espacios (que no puede escribirse en el esquema) no se
+/// declaran, por lo que el tipo de sus valores se infiere al leerlos.
+///
+/// # Errores
+///
+/// Retorna un error si no se puede declarar alguna columna.
+fn result_schema(columns: &[String], rows: &Rows, null_marker: &str) -> Result<Schema, Errored> {
+    let mut schema = Schema::with_null_marker(null_marker);
+    for (index, column) in columns.iter().enumerate() {
+        if column.contains(char::is_whitespace) || schema.column_type(column).is_some() {
+            continue;
+        }
+        if let Some(kind) = common_type(rows.iter().map(|values| &values[index])) {
+            schema.add_column(column.to_string(), kind)?;
+        }
+    }
+    Ok(schema)
+}
+
+/// Obtiene el tipo común a los valores no nulos de una columna, si existe.
+fn common_type<'a, I>(values: I) -> Option<ColumnType>
+where
+    I: Iterator<Item = &'a ExpressionResult>,
+{
+    let mut common = None;
+    for value in values.filter(|value| **value != Null) {
+        let kind = ColumnType::of(value)?;
+        common = match (common, kind) {
+            (None, kind) => Some(kind),
+            (Some(common), kind) if common == kind => Some(common),
+            (Some(ColumnType::Int), ColumnType::Float)
+            | (Some(ColumnType::Float), ColumnType::Int) => Some(ColumnType::Float),
+            _ => return None,
+        };
+    }
+    common
+}
+
+/// Obtiene la ruta del archivo de una tabla, buscándola primero entre las tablas virtuales.
+///
+/// # Parámetros
+///
+/// - `tables_path`: El directorio de las tablas.
+/// - `virtual_tables`: Las rutas de las tablas virtuales disponibles, por nombre.
+/// - `table`: El nombre de la tabla.
+///
+/// # Errores
+///
+/// Retorna un error si la tabla no es virtual y no existe en el directorio.
+pub(super) fn resolve_table_path(
+    tables_path: &Path,
+    virtual_tables: &HashMap<String, PathBuf>,
+    table: &str,
+) -> Result<PathBuf, Errored> {
+    match virtual_tables.get(table) {
+        Some(path) => Ok(path.to_path_buf()),
+        None => get_table_path(tables_path, table),
+    }
+}
@@ -0,0 +1,132 @@
+use crate::errored;
+use crate::query::executor::select::compare_rows;
+use crate::query::executor::subquery::Rows;
+use crate::query::executor::Executor;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::Null;
+use crate::query::structs::ordering::Ordering;
+use crate::query::structs::row::{resolve_column, Row};
+use crate::query::structs::schema::Schema;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Column;
+use crate::utils::files::escape_csv;
+use std::mem;
+
+impl Executor {
+    /// Ejecuta una consulta que combina varios SELECT con operaciones de conjuntos e imprime
+    /// el resultado combinado en la salida estándar.
+    ///
+    /// El encabezado se nombra con las columnas de la primera consulta, y los valores `NULL`
+    /// se escriben con la representación de `NULL` de su tabla, como en `run_select`.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla alguna de las consultas o la combinación de sus resultados
+    /// (ver `compound_rows`).
+    pub(super) fn run_compound(&mut self) -> Result<(), Errored> {
+        let (columns, rows) = self.compound_rows()?;
+        let names: Vec<String> = columns.iter().map(|c| escape_csv(c)).collect();
+        println!("{}", names.join(","));
+        for values in rows {
+            as_row(&columns, values, self.schema.as_ref()).print_projection(&columns);
+        }
+        Ok(())
+    }
+
+    /// Obtiene las filas resultantes de combinar la consulta con cada una de sus `compounds`.
+    ///
+    /// # Proceso
+    ///
+    /// 1. Ejecuta la primera consulta sin su ordenamiento ni su paginación, que corresponden
+    ///    al resultado combinado.
+    /// 2. Ejecuta cada consulta combinada y une sus filas con las acumuladas según la operación
+    ///    (ver `CompoundKind::combine`), de izquierda a derecha.
+    /// 3. Ordena el resultado combinado por las columnas de la primera consulta y lo recorta
+    ///    según el `OFFSET` y el `LIMIT`.
+    ///
+    /// # Retorna
+    ///
+    /// Los nombres de las columnas de la primera consulta y los valores de cada fila resultante.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla alguna de las consultas, si no proyectan la misma cantidad de
+    /// columnas o si el ordenamiento utiliza una columna que no existe en el resultado.
+    pub(super) fn compound_rows(&mut self) -> Result<(Vec<String>, Rows), Errored> {
+        let ordering = mem::take(&mut self.query.ordering);
+        let limit = self.query.limit.take();
+        let offset = mem::take(&mut self.query.offset);
+        let compounds = mem::take(&mut self.query.compounds);
+        let (columns, mut rows) = self.selected_values()?;
+        for compound in compounds {
+            let (other_columns, other_rows) = self.run_subquery(compound.query)?;
+            if other_columns.len() != columns.len() {
+                errored!(
+                    Column,
+                    "queries combined with {} must project the same number of columns, got ({}) and ({}).",
+                    compound.kind,
+                    columns.len(),
+                    other_columns.len()
+                )
+            }
+            rows = compound.kind.combine(rows, other_rows);
+        }
+        let rows = sort_values(&columns, rows, &ordering)?;
+        let rows = rows
+            .into_iter()
+            .skip(offset)
+            .take(limit.unwrap_or(usize::MAX))
+            .collect();
+        Ok((columns, rows))
+    }
+}
+
+/// Convierte los valores de una fila resultante en una `Row` con las columnas dadas.
+///
+/// El esquema solo se usa para escribir los valores `NULL` con la representación de su tabla.
+pub(super) fn as_row<'a>(
+    columns: &'a Vec<String>,
+    values: Vec<ExpressionResult>,
+    schema: Option<&'a Schema>,
+) -> Row<'a> {
+    Row {
+        header: columns,
+        schema,
+        values: columns.iter().cloned().zip(values).collect(),
+    }
+}
+
+/// Ordena las filas de un resultado combinado con los mismos criterios que `sort_rows`.
+///
+/// # Errores
+///
+/// Retorna un error si alguno de los campos de ordenamiento no es una de las columnas.
+fn sort_values(columns: &Vec<String>, rows: Rows, ordering: &[Ordering]) -> Result<Rows, Errored> {
+    if ordering.is_empty() {
+        return Ok(rows);
+    }
+    for order in ordering {
+        if resolve_column(columns, &order.field.value)?.is_none() {
+            errored!(
+                Column,
+                "order by failed, column {} does not exist",
+                &order.field.value
+            )
+        }
+    }
+    let mut sorted: Vec<Row> = rows
+        .into_iter()
+        .map(|values| as_row(columns, values, None))
+        .collect();
+    sorted.sort_by(|a, b| compare_rows(ordering, a, b));
+    let rows = sorted
+        .into_iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|c| row.values.get(c).cloned().unwrap_or(Null))
+                .collect()
+        })
+        .collect();
+    Ok(rows)
+}
@@ -0,0 +1,40 @@
+use crate::errored;
+use crate::query::executor::Executor;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Table;
+use crate::utils::files::escape_csv;
+use std::fs::File;
+use std::io::Write;
+
+impl Executor {
+    /// Ejecuta la operación de creación de una tabla.
+    /// # Proceso
+    ///
+    /// 1. Verifica que la tabla no exista en el directorio de tablas. Si existe y la consulta
+    ///    usa `IF NOT EXISTS`, la operación termina sin hacer cambios.
+    /// 2. Crea el archivo de la tabla, escribiendo como encabezado las columnas definidas.
+    /// 3. Guarda el esquema de la tabla (`tabla.schema`) con el tipo de cada columna.
+    ///
+    /// # Errores
+    ///
+    /// Puede retornar un error si la tabla ya existe o si ocurre un problema al escribir los archivos.
+    pub fn run_create(&self) -> Result<(), Errored> {
+        if self.table_path.exists() {
+            if self.query.if_exists {
+                return Ok(());
+            }
+            errored!(Table, "table {} already exists.", self.query.table)
+        }
+        let header: Vec<String> = self
+            .query
+            .schema
+            .column_names()
+            .iter()
+            .map(|column| escape_csv(column))
+            .collect();
+        let mut table = File::create(&self.table_path)?;
+        writeln!(table, "{}", header.join(","))?;
+        self.query.schema.save(&self.table_path)?;
+        Ok(())
+    }
+}
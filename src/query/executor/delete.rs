@@ -2,9 +2,9 @@ use crate::query::executor::Executor;
 use crate::query::structs::row::Row;
 use crate::utils::errors::Errored;
 use crate::utils::files::{
-    delete_temp_file, extract_header, get_table_file, get_temp_file, split_csv,
+    csv_records, extract_header, get_table_file, rewrite_table, split_csv_fields,
 };
-use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::io::{BufReader, Write};
 
 impl Executor {
     /// Ejecuta la operación de eliminación de registros en la tabla especificada.
@@ -23,25 +23,25 @@ impl Executor {
     /// # Errores
     ///
     /// Puede retornar un error si ocurre un problema al abrir los archivos, leer el encabezado, procesar las líneas o eliminar el archivo temporal.
+    /// Ante cualquier error, el archivo temporal se elimina y la tabla queda sin cambios (ver `rewrite_table`).
     pub fn run_delete(&self) -> Result<(), Errored> {
         let table = get_table_file(&self.table_path)?;
-        let (temp_table, temp_path) = get_temp_file(&self.query.table, &self.table_path)?;
         let mut reader = BufReader::new(&table);
-        let mut writer = BufWriter::new(temp_table);
         let header = extract_header(&mut reader)?;
-        writeln!(writer, "{}", header.join(","))?;
-        for line in reader.lines() {
-            let l = line?;
-            let fields = split_csv(&l);
-            let mut row = Row::new(&header);
-            row.read_new_row(fields)?;
-            if row.matches_condition(&self.query)? {
-                continue;
-            } else {
-                writeln!(writer, "{}", l)?
+        rewrite_table(&self.query.table, &self.table_path, |writer| {
+            writeln!(writer, "{}", header.join(","))?;
+            for record in csv_records(reader) {
+                let l = record?;
+                let fields = split_csv_fields(&l)?;
+                let mut row = Row::with_schema(&header, self.schema.as_ref());
+                row.read_fields(fields)?;
+                if row.matches_condition(&self.query)? {
+                    continue;
+                } else {
+                    writeln!(writer, "{}", l)?
+                }
             }
-        }
-        delete_temp_file(&self.table_path, &temp_path)?;
-        Ok(())
+            Ok(())
+        })
     }
 }
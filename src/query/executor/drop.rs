@@ -0,0 +1,30 @@
+use crate::errored;
+use crate::query::executor::Executor;
+use crate::query::structs::schema::Schema;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Table;
+use std::fs;
+
+impl Executor {
+    /// Ejecuta la operación de eliminación de una tabla.
+    /// # Proceso
+    ///
+    /// 1. Verifica que la tabla exista en el directorio de tablas. Si no existe y la consulta
+    ///    usa `IF EXISTS`, la operación termina sin hacer cambios.
+    /// 2. Elimina el archivo de la tabla y, si lo tiene, su archivo de esquema.
+    ///
+    /// # Errores
+    ///
+    /// Puede retornar un error si la tabla no existe o si ocurre un problema al eliminar los archivos.
+    pub fn run_drop(&self) -> Result<(), Errored> {
+        if !self.table_path.is_file() {
+            if self.query.if_exists {
+                return Ok(());
+            }
+            errored!(Table, "table {} does not exist.", self.query.table)
+        }
+        fs::remove_file(&self.table_path)?;
+        Schema::remove(&self.table_path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use crate::errored;
+use crate::query::executor::Executor;
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::row::Row;
+use crate::query::structs::token::Token;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Column;
+use std::collections::HashMap;
+
+impl Executor {
+    /// Agrupa las filas coincidentes y calcula las agregaciones de la consulta para cada grupo.
+    ///
+    /// # Proceso
+    ///
+    /// 1. Divide las filas en grupos según los valores de las columnas de `self.query.group_by`,
+    ///    conservando el orden en que aparece cada grupo por primera vez.
+    /// 2. Si la consulta no tiene `GROUP BY`, todas las filas forman un único grupo (incluso si no hay filas).
+    /// 3. Por cada grupo se genera una nueva fila que conserva los valores de la primera fila del grupo
+    ///    y agrega una columna por cada agregación, nombrada como la agregación misma (por ejemplo `COUNT(*)`).
+    ///
+    /// # Parámetros
+    ///
+    /// - `group_header`: El encabezado de las filas agrupadas, que incluye las columnas de las agregaciones.
+    /// - `rows`: Las filas que cumplieron las condiciones de la consulta.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna columna de agrupamiento no existe o si falla el cálculo de una agregación.
+    pub(super) fn group_rows<'a>(
+        &self,
+        group_header: &'a Vec<String>,
+        rows: Vec<Row>,
+    ) -> Result<Vec<Row<'a>>, Errored> {
+        let mut index: HashMap<Vec<String>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<Row>> = vec![];
+        for row in rows {
+            let mut key = vec![];
+            for column in &self.query.group_by {
+                key.push(ExpressionNode::get_variable_value(&row.values, column)?.to_string());
+            }
+            match index.get(&key) {
+                Some(i) => groups[*i].push(row),
+                None => {
+                    index.insert(key, groups.len());
+                    groups.push(vec![row]);
+                }
+            }
+        }
+        if groups.is_empty() && self.query.group_by.is_empty() {
+            groups.push(vec![]);
+        }
+        let mut grouped_rows = vec![];
+        for group in groups {
+            let mut grouped = Row::new(group_header);
+            if let Some(first) = group.first() {
+                grouped.values = first.values.clone();
+            }
+            for aggregate in &self.query.aggregates {
+                let value = aggregate.compute(&group)?;
+                grouped.set_value(&aggregate.to_string(), value)?;
+            }
+            grouped_rows.push(grouped);
+        }
+        Ok(grouped_rows)
+    }
+
+    /// Valida que las columnas que se leen de cada grupo tengan un único valor por grupo.
+    ///
+    /// Las filas agrupadas conservan los valores de la primera fila de su grupo (ver
+    /// `group_rows`), por lo que una columna que no está en el `GROUP BY` tomaría el valor de
+    /// una fila cualquiera. Por eso, las columnas proyectadas, las de las expresiones calculadas,
+    /// las de la condición `HAVING`, las de las funciones de ventana y las del ordenamiento deben
+    /// ser columnas de agrupamiento o estar dentro de una agregación.
+    ///
+    /// El ordenamiento y las funciones de ventana pueden además usar las agregaciones, las
+    /// expresiones calculadas y los alias de la proyección.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna de esas columnas no es de agrupamiento.
+    pub(super) fn validate_grouping(&self) -> Result<(), Errored> {
+        let derived: Vec<String> = self
+            .query
+            .aggregates
+            .iter()
+            .map(|a| a.to_string())
+            .chain(self.query.windows.iter().map(|w| w.to_string()))
+            .chain(self.query.computed.iter().map(|e| e.to_string()))
+            .collect();
+        let aliases: Vec<&String> = self.query.aliases.iter().flatten().collect();
+        let read = self
+            .query
+            .columns
+            .iter()
+            .filter(|c| !derived.contains(&c.value))
+            .chain(self.query.computed.iter().flat_map(|e| e.identifiers()))
+            .chain(self.query.having.identifiers());
+        let ordered = self
+            .query
+            .windows
+            .iter()
+            .flat_map(|w| w.columns())
+            .chain(self.query.ordering.iter().map(|o| &o.field))
+            .filter(|c| !derived.contains(&c.value) && !aliases.contains(&&c.value));
+        for column in read.chain(ordered).filter(|c| c.value != "*") {
+            if !self.query.group_by.iter().any(|g| same_column(g, column)) {
+                errored!(
+                    Column,
+                    "column {} must appear in GROUP BY or be used in an aggregate function.",
+                    column.value
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Indica si dos referencias nombran a la misma columna, considerando que solo una de ellas
+/// puede estar calificada con su tabla (`clientes.id` e `id`).
+fn same_column(a: &Token, b: &Token) -> bool {
+    let field = |name: &str| {
+        name.split_once('.')
+            .map_or(name.to_string(), |(_, f)| f.to_string())
+    };
+    match (a.value.contains('.'), b.value.contains('.')) {
+        (true, true) | (false, false) => a.value == b.value,
+        _ => field(&a.value) == field(&b.value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::structs::token::TokenKind::Identifier;
+
+    fn column(value: &str) -> Token {
+        Token {
+            value: value.to_string(),
+            kind: Identifier,
+        }
+    }
+
+    #[test]
+    fn test_same_column() {
+        assert!(same_column(&column("edad"), &column("edad")));
+        assert!(same_column(&column("c.edad"), &column("edad")));
+        assert!(same_column(&column("edad"), &column("c.edad")));
+        assert!(!same_column(&column("c.edad"), &column("o.edad")));
+        assert!(!same_column(&column("edad"), &column("nombre")));
+    }
+}
@@ -1,9 +1,18 @@
+use crate::errored;
 use crate::query::executor::Executor;
+use crate::query::structs::expression::ExpressionResult::Null;
+use crate::query::structs::expression::{ExpressionNode, ExpressionResult};
+use crate::query::structs::query::Query;
 use crate::query::structs::row::Row;
+use crate::query::structs::token::Token;
+use crate::query::structs::token::TokenKind::{Identifier, Keyword};
 use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::{Column, Syntax};
 use crate::utils::files;
 use crate::utils::files::{extract_header, get_table_file};
+use std::collections::HashMap;
 use std::io::{BufReader, Write};
+use std::mem;
 
 impl Executor {
     /// Ejecuta la operación de inserción de registros en la tabla especificada.
@@ -11,28 +20,175 @@ impl Executor {
     ///
     /// 1. Abre el archivo de la tabla especificada en `self.table_path`.
     /// 2. Lee el encabezado del archivo para obtener los nombres de las columnas.
-    /// 3. Asegura que el archivo termine en una nueva línea.
+    /// 3. Valida que cada inserción tenga un valor por columna. Si la consulta omite la lista
+    ///    de columnas, se insertan todas las del encabezado, en orden.
     /// 4. Para cada inserción en `self.query.inserts`:
-    ///    - Convierte los valores de la inserción en una fila de valores.
-    ///    - Crea una nueva fila (`Row`) y la llena con los valores.
-    ///    - Escribe la fila como una línea CSV en el archivo.
+    ///    - Evalúa los valores de la inserción (ver `insert_value`).
+    ///    - Crea una nueva fila (`Row`) y la llena con los valores, dejando en `NULL` las columnas omitidas.
+    ///    - Convierte la fila en una línea CSV, validándola contra el esquema de la tabla.
+    /// 5. Asegura que el archivo termine en una nueva línea y escribe todas las líneas juntas.
+    ///
+    /// Si la consulta inserta las filas de un SELECT (`self.query.source`), en lugar de los
+    /// valores de `self.query.inserts` se insertan las filas resultantes (ver `insert_selected`).
+    ///
+    /// La inserción es atómica: si alguna fila no puede evaluarse o no respeta el esquema, la
+    /// tabla queda sin cambios.
     ///
     /// # Errores
     ///
     /// Puede retornar un error si ocurre un problema al abrir el archivo de la tabla, leer el encabezado,
-    /// agregar la nueva línea o escribir en el archivo.
-    pub fn run_insert(&self) -> Result<(), Errored> {
+    /// evaluar o validar alguna fila, agregar la nueva línea o escribir en el archivo.
+    pub fn run_insert(&mut self) -> Result<(), Errored> {
         let mut table = get_table_file(&self.table_path)?;
         let mut reader = BufReader::new(&table);
         let header = extract_header(&mut reader)?;
+        let columns = self.insert_columns(&header);
+        let lines = match mem::take(&mut self.query.source) {
+            Some(source) => self.insert_selected(&header, &columns, *source)?,
+            None => self.insert_values(&header, &columns)?,
+        };
         files::make_file_end_in_newline(&mut table)?;
-        for insert in &self.query.inserts {
-            let fields: Vec<String> = insert.iter().map(|t| t.value.to_string()).collect();
-            let mut row = Row::new(&header);
-            row.clear()?;
-            row.insert_values(&self.query.columns, fields)?;
-            writeln!(table, "{}", row.as_csv_row())?
+        let mut content = String::new();
+        for line in lines {
+            content.push_str(&line);
+            content.push('\n');
         }
+        table.write_all(content.as_bytes())?;
         Ok(())
     }
+
+    /// Obtiene las líneas CSV de las filas de `self.query.inserts`, sin escribirlas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna inserción no tiene un valor por columna, si falla la
+    /// evaluación de algún valor o si alguna fila no respeta el esquema de la tabla.
+    fn insert_values(
+        &self,
+        header: &Vec<String>,
+        columns: &[Token],
+    ) -> Result<Vec<String>, Errored> {
+        for insert in &self.query.inserts {
+            if insert.len() != columns.len() {
+                let values: Vec<String> = insert.iter().map(|v| v.to_string()).collect();
+                errored!(
+                    Syntax,
+                    "expected {} columns but insert has:\n{:?}",
+                    columns.len(),
+                    values
+                )
+            }
+        }
+        let mut lines = vec![];
+        for insert in &self.query.inserts {
+            let mut fields = vec![];
+            for value in insert {
+                fields.push(insert_value(value)?);
+            }
+            lines.push(self.insert_line(header, columns, fields)?);
+        }
+        Ok(lines)
+    }
+
+    /// Obtiene las columnas en las que se insertan los valores: las de la consulta o, si la
+    /// consulta las omite (`INSERT INTO tabla VALUES (...)`), todas las del encabezado.
+    fn insert_columns(&self, header: &[String]) -> Vec<Token> {
+        if !self.query.columns.is_empty() {
+            return self.query.columns.clone();
+        }
+        header
+            .iter()
+            .map(|column| Token {
+                value: column.to_string(),
+                kind: Identifier,
+            })
+            .collect()
+    }
+
+    /// Obtiene las líneas CSV de las filas resultantes de una consulta SELECT, sin escribirlas.
+    ///
+    /// La consulta se ejecuta con un nuevo `Executor`, igual que una sub-consulta, y cada fila
+    /// resultante se convierte en una línea a medida que se obtiene (ver `stream_selected_values`).
+    ///
+    /// Las líneas se escriben luego de terminar de leer la tabla de origen, por lo que una
+    /// tabla puede insertar filas leídas de sí misma.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la consulta, si no proyecta tantas columnas como las de la
+    /// inserción o si alguna fila no respeta el esquema de la tabla.
+    fn insert_selected(
+        &self,
+        header: &Vec<String>,
+        columns: &[Token],
+        source: Query,
+    ) -> Result<Vec<String>, Errored> {
+        let (mut executor, _virtual_tables) =
+            Executor::prepare(&self.tables_path, &self.virtual_tables, source)?;
+        executor.resolve_subqueries()?;
+        if !executor.query.compounds.is_empty() {
+            let (selected, rows) = executor.compound_rows()?;
+            validate_selected(columns, &selected)?;
+            return rows
+                .into_iter()
+                .map(|values| self.insert_line(header, columns, values))
+                .collect();
+        }
+        let mut lines = vec![];
+        let selected = executor.stream_selected_values(&mut |selected, values| {
+            validate_selected(columns, selected)?;
+            lines.push(self.insert_line(header, columns, values)?);
+            Ok(())
+        })?;
+        validate_selected(columns, &selected)?;
+        Ok(lines)
+    }
+
+    /// Obtiene la línea CSV de una nueva fila con los valores de las columnas de la inserción
+    /// (ver `insert_columns`), dejando en `NULL` las columnas omitidas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna columna no existe o si algún valor no respeta el esquema de
+    /// la tabla.
+    fn insert_line(
+        &self,
+        header: &Vec<String>,
+        columns: &[Token],
+        fields: Vec<ExpressionResult>,
+    ) -> Result<String, Errored> {
+        let mut row = Row::with_schema(header, self.schema.as_ref());
+        row.clear()?;
+        row.insert_values(columns, fields)?;
+        Ok(row.as_csv_row())
+    }
+}
+
+/// Valida que la consulta SELECT de una inserción proyecte tantas columnas como las de la
+/// inserción.
+fn validate_selected(columns: &[Token], selected: &[String]) -> Result<(), Errored> {
+    if selected.len() != columns.len() {
+        errored!(
+            Column,
+            "expected {} columns but SELECT projects {}.",
+            columns.len(),
+            selected.len()
+        )
+    }
+    Ok(())
+}
+
+/// Evalúa uno de los valores de una inserción.
+///
+/// Las tablas no guardan valores por defecto para sus columnas, por lo que `DEFAULT` inserta
+/// `NULL`, al igual que una columna omitida.
+///
+/// # Errores
+///
+/// Retorna un error si falla la evaluación de la expresión.
+fn insert_value(value: &ExpressionNode) -> Result<ExpressionResult, Errored> {
+    match value {
+        ExpressionNode::Leaf(t) if t.kind == Keyword && t.value == "DEFAULT" => Ok(Null),
+        _ => value.evaluate(&HashMap::new()),
+    }
 }
@@ -0,0 +1,100 @@
+use crate::query::executor::common_table::resolve_table_path;
+use crate::query::executor::Executor;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::Null;
+use crate::query::structs::join::JoinKind::Left;
+use crate::query::structs::row::Row;
+use crate::query::structs::schema::Schema;
+use crate::utils::errors::Errored;
+use crate::utils::files::{csv_records, extract_header, get_table_file, split_csv_fields};
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Registros de una tabla cargada en memoria, indexados por columna.
+type Records = Vec<HashMap<String, ExpressionResult>>;
+
+impl Executor {
+    /// Une la tabla principal de la consulta con cada una de las tablas de `self.query.joins`.
+    ///
+    /// # Proceso
+    ///
+    /// 1. Carga la tabla principal en memoria, calificando cada columna con el nombre de su tabla
+    ///    (o con su alias, si la consulta lo declara).
+    /// 2. Por cada unión, carga la tabla a unir y combina cada registro acumulado con cada
+    ///    registro de la nueva tabla, conservando solo las combinaciones que cumplen la condición `ON`.
+    /// 3. En las uniones `LEFT`, los registros de la izquierda sin coincidencias se conservan
+    ///    completando con `NULL` las columnas de la derecha.
+    ///
+    /// # Retorna
+    ///
+    /// El encabezado combinado (con columnas calificadas) y los registros resultantes de la unión.
+    ///
+    /// # Errores
+    ///
+    /// Puede retornar un error si alguna tabla no existe, no puede leerse, o si la condición
+    /// de unión no puede evaluarse.
+    pub(super) fn join_tables(&self) -> Result<(Vec<String>, Records), Errored> {
+        let (mut header, mut records) =
+            read_qualified_table(&self.table_path, self.query.qualifier())?;
+        for join in &self.query.joins {
+            let join_path =
+                resolve_table_path(&self.tables_path, &self.virtual_tables, &join.table)?;
+            let (join_header, join_records) = read_qualified_table(&join_path, join.qualifier())?;
+            let mut joined_header = header.clone();
+            joined_header.extend(join_header.iter().cloned());
+            let mut joined_records = vec![];
+            for left in records {
+                let mut matched = false;
+                for right in &join_records {
+                    let mut row = Row::new(&joined_header);
+                    row.values = left.clone();
+                    row.values.extend(right.clone());
+                    if row.matches_expression(&join.conditions)? {
+                        joined_records.push(row.values);
+                        matched = true;
+                    }
+                }
+                if !matched && join.kind == Left {
+                    let mut values = left;
+                    for column in &join_header {
+                        values.insert(column.to_string(), Null);
+                    }
+                    joined_records.push(values);
+                }
+            }
+            header = joined_header;
+            records = joined_records;
+        }
+        Ok((header, records))
+    }
+}
+
+/// Lee una tabla completa en memoria, calificando sus columnas con el nombre de la tabla.
+///
+/// Si la tabla tiene un esquema, sus valores se interpretan según los tipos declarados.
+///
+/// # Parámetros
+///
+/// - `path`: La ruta al archivo de la tabla.
+/// - `table`: El nombre con el que se calificarán las columnas.
+///
+/// # Retorna
+///
+/// Una tupla con el encabezado calificado y los registros de la tabla.
+fn read_qualified_table(path: &Path, table: &str) -> Result<(Vec<String>, Records), Errored> {
+    let file = get_table_file(path)?;
+    let mut reader = BufReader::new(&file);
+    let header: Vec<String> = extract_header(&mut reader)?
+        .iter()
+        .map(|column| format!("{}.{}", table, column))
+        .collect();
+    let schema = Schema::load(path)?.map(|schema| schema.qualified(table));
+    let mut records = vec![];
+    for record in csv_records(reader) {
+        let mut row = Row::with_schema(&header, schema.as_ref());
+        row.read_fields(split_csv_fields(&record?)?)?;
+        records.push(row.values);
+    }
+    Ok((header, records))
+}
@@ -0,0 +1,188 @@
+use crate::query::executor::select::compare_rows;
+use crate::query::structs::ordering::Ordering;
+use crate::query::structs::row::Row;
+use std::cmp;
+use std::collections::BinaryHeap;
+
+/// Acumula las filas que cumplen las condiciones de un SELECT, conservando solo las
+/// necesarias cuando la consulta tiene `LIMIT`.
+///
+/// - Sin límite, se conservan todas las filas.
+/// - Con límite y sin `ORDER BY`, se conservan las primeras filas y se avisa cuando ya no
+///   hace falta seguir leyendo la tabla (`is_complete`).
+/// - Con límite y con `ORDER BY`, se mantiene un heap acotado con las mejores filas vistas
+///   hasta el momento (top-N), descartando la peor cada vez que se supera la capacidad.
+pub(super) struct MatchedRows<'a, 'q> {
+    rows: Vec<Row<'a>>,
+    heap: BinaryHeap<RankedRow<'a, 'q>>,
+    capacity: Option<usize>,
+    ordering: &'q [Ordering],
+    pushed: usize,
+}
+
+/// Fila que se compara según los criterios de ordenamiento de la consulta, para poder
+/// ubicarla dentro de un `BinaryHeap`.
+///
+/// Las filas empatadas se comparan por `sequence`, el orden en que fueron agregadas, de forma
+/// que el resultado coincida con el ordenamiento estable de todas las filas (ver `sort_rows`).
+struct RankedRow<'a, 'q> {
+    row: Row<'a>,
+    ordering: &'q [Ordering],
+    sequence: usize,
+}
+
+impl<'a, 'q> MatchedRows<'a, 'q> {
+    /// Crea un acumulador de filas.
+    ///
+    /// # Parámetros
+    ///
+    /// - `capacity`: La cantidad máxima de filas a conservar, o `None` si deben conservarse todas.
+    /// - `ordering`: Los criterios de ordenamiento de la consulta.
+    pub(super) fn new(capacity: Option<usize>, ordering: &'q [Ordering]) -> Self {
+        Self {
+            rows: vec![],
+            heap: BinaryHeap::new(),
+            capacity,
+            ordering,
+            pushed: 0,
+        }
+    }
+
+    /// Agrega una fila coincidente al acumulador.
+    pub(super) fn push(&mut self, row: Row<'a>) {
+        match self.capacity {
+            Some(capacity) if !self.ordering.is_empty() => {
+                self.heap.push(RankedRow {
+                    row,
+                    ordering: self.ordering,
+                    sequence: self.pushed,
+                });
+                self.pushed += 1;
+                if self.heap.len() > capacity {
+                    self.heap.pop();
+                }
+            }
+            _ => self.rows.push(row),
+        }
+    }
+
+    /// Indica si ya se juntaron todas las filas necesarias y se puede dejar de leer la tabla.
+    ///
+    /// Solo es posible cortar antes cuando no hay ordenamiento, ya que en ese caso las
+    /// primeras filas coincidentes son las que forman parte del resultado.
+    pub(super) fn is_complete(&self) -> bool {
+        match self.capacity {
+            Some(capacity) => self.ordering.is_empty() && self.rows.len() >= capacity,
+            None => false,
+        }
+    }
+
+    /// Consume el acumulador y devuelve las filas conservadas.
+    pub(super) fn into_rows(self) -> Vec<Row<'a>> {
+        if self.heap.is_empty() {
+            return self.rows;
+        }
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|ranked| ranked.row)
+            .collect()
+    }
+}
+
+impl Ord for RankedRow<'_, '_> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        compare_rows(self.ordering, &self.row, &other.row).then(self.sequence.cmp(&other.sequence))
+    }
+}
+
+impl PartialOrd for RankedRow<'_, '_> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for RankedRow<'_, '_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == cmp::Ordering::Equal
+    }
+}
+
+impl Eq for RankedRow<'_, '_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::structs::ordering::OrderKind::Desc;
+    use crate::query::structs::token::Token;
+    use crate::query::structs::token::TokenKind::Identifier;
+
+    fn row_with<'a>(header: &'a Vec<String>, id: &str) -> Row<'a> {
+        let mut row = Row::new(header);
+        row.set("id", id.to_string()).unwrap();
+        row
+    }
+
+    fn ids(rows: Vec<Row>) -> Vec<String> {
+        rows.iter().map(|r| r.as_csv_row()).collect()
+    }
+
+    #[test]
+    fn test_unbounded_keeps_all_rows() {
+        let header = vec!["id".to_string()];
+        let mut matched = MatchedRows::new(None, &[]);
+        for id in ["3", "1", "2"] {
+            matched.push(row_with(&header, id));
+            assert!(!matched.is_complete());
+        }
+        assert_eq!(ids(matched.into_rows()), vec!["3", "1", "2"]);
+    }
+
+    #[test]
+    fn test_limit_without_ordering_is_complete() {
+        let header = vec!["id".to_string()];
+        let mut matched = MatchedRows::new(Some(2), &[]);
+        matched.push(row_with(&header, "3"));
+        assert!(!matched.is_complete());
+        matched.push(row_with(&header, "1"));
+        assert!(matched.is_complete());
+        assert_eq!(ids(matched.into_rows()), vec!["3", "1"]);
+    }
+
+    #[test]
+    fn test_limit_with_ordering_keeps_top_rows() {
+        let header = vec!["id".to_string()];
+        let ordering = vec![Ordering {
+            field: Token {
+                value: "id".to_string(),
+                kind: Identifier,
+            },
+            kind: Desc,
+        }];
+        let mut matched = MatchedRows::new(Some(2), &ordering);
+        for id in ["3", "10", "1", "7"] {
+            matched.push(row_with(&header, id));
+            assert!(!matched.is_complete());
+        }
+        assert_eq!(ids(matched.into_rows()), vec!["10", "7"]);
+    }
+
+    #[test]
+    fn test_limit_with_ordering_keeps_first_tied_rows() {
+        let header = vec!["id".to_string(), "precio".to_string()];
+        let ordering = vec![Ordering {
+            field: Token {
+                value: "precio".to_string(),
+                kind: Identifier,
+            },
+            kind: Desc,
+        }];
+        let mut matched = MatchedRows::new(Some(3), &ordering);
+        for (id, precio) in [("1", "5"), ("2", "9"), ("3", "5"), ("4", "5"), ("5", "9")] {
+            let mut row = row_with(&header, id);
+            row.set("precio", precio.to_string()).unwrap();
+            matched.push(row);
+        }
+        assert_eq!(ids(matched.into_rows()), vec!["2,9", "5,9", "1,5"]);
+    }
+}
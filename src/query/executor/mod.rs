@@ -1,15 +1,29 @@
 use crate::errored;
+use crate::query::executor::common_table::{resolve_table_path, VirtualTables};
 use crate::query::structs::operation::Operation::*;
 use crate::query::structs::query::Query;
+use crate::query::structs::schema::Schema;
 use crate::utils::errors::Errored;
-use crate::utils::errors::Errored::Syntax;
-use crate::utils::files::get_table_path;
+use crate::utils::errors::Errored::{Column, Syntax};
+use crate::utils::files::build_table_path;
+use std::collections::HashMap;
+use std::mem;
 use std::path::{Path, PathBuf};
 
+mod alter;
+mod common_table;
+mod compound;
+mod create;
 mod delete;
+mod drop;
+mod group;
 mod insert;
+mod join;
+mod limit;
 mod select;
+mod subquery;
 mod update;
+mod window;
 
 /// Ejecuta una consulta SQL en una tabla especificada.
 ///
@@ -21,11 +35,17 @@ mod update;
 /// y el archivo destino.
 ///
 /// # Estructura
+/// - `tables_path`: Ruta del directorio que contiene a todas las tablas.
 /// - `table_path`: Ruta del archivo de la tabla sobre la cual se ejecutará la consulta.
+/// - `schema`: El esquema de la tabla, si tiene un archivo `.schema` junto a ella.
 /// - `query`: La consulta SQL a ejecutar, representada como un objeto `Query`.
+/// - `virtual_tables`: Las rutas de las tablas virtuales (`WITH`) disponibles, por nombre.
 pub struct Executor {
+    tables_path: PathBuf,
     table_path: PathBuf,
+    schema: Option<Schema>,
     query: Query,
+    virtual_tables: HashMap<String, PathBuf>,
 }
 
 impl Executor {
@@ -33,20 +53,92 @@ impl Executor {
     ///
     /// # Argumentos
     ///
+    /// - `tables_path`: La ruta del directorio donde se encuentran las tablas.
     /// - `table_path`: La ruta del archivo de la tabla sobre la cual se ejecutará la consulta.
+    /// - `schema`: El esquema de la tabla, o `None` si la tabla no declara los tipos de sus columnas.
     /// - `query`: La consulta SQL a ejecutar, representada como un objeto `Query`.
+    /// - `virtual_tables`: Las rutas de las tablas virtuales disponibles, por nombre.
     ///
     /// # Retorna
     ///
     /// Una nueva instancia de `Executor`.
-    fn new(table_path: PathBuf, query: Query) -> Self {
-        Executor { table_path, query }
+    fn new(
+        tables_path: PathBuf,
+        table_path: PathBuf,
+        schema: Option<Schema>,
+        query: Query,
+        virtual_tables: HashMap<String, PathBuf>,
+    ) -> Self {
+        Executor {
+            tables_path,
+            table_path,
+            schema,
+            query,
+            virtual_tables,
+        }
+    }
+
+    /// Prepara la ejecución de una consulta.
+    ///
+    /// Materializa las tablas virtuales de la consulta (`WITH`) y obtiene la ruta y el esquema
+    /// de su tabla, buscándola primero entre las tablas virtuales (ver `resolve_table_path`).
+    ///
+    /// # Parámetros
+    ///
+    /// - `tables_path`: Ruta al directorio donde se encuentran los archivos de las tablas.
+    /// - `inherited`: Las tablas virtuales ya disponibles, si la consulta es una sub-consulta.
+    /// - `query`: La consulta SQL a ejecutar.
+    ///
+    /// # Retorna
+    ///
+    /// El `Executor` de la consulta y sus tablas virtuales, que deben conservarse mientras se
+    /// ejecuta la consulta, ya que al descartarlas se eliminan sus archivos.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la materialización de alguna tabla virtual, si la tabla de
+    /// la consulta no existe o tiene un esquema inválido, o si alguna columna está calificada
+    /// con una tabla que no es la de la consulta (ver `validate_qualifiers`).
+    fn prepare(
+        tables_path: &Path,
+        inherited: &HashMap<String, PathBuf>,
+        mut query: Query,
+    ) -> Result<(Self, VirtualTables), Errored> {
+        let common_tables = mem::take(&mut query.common_tables);
+        let virtual_tables = VirtualTables::materialize(tables_path, inherited, common_tables)?;
+        let mut available = inherited.clone();
+        available.extend(virtual_tables.paths.clone());
+        let (table_path, schema) = match query.operation {
+            Create | Drop => (build_table_path(tables_path, &query.table)?, None),
+            _ => {
+                let table_path = resolve_table_path(tables_path, &available, &query.table)?;
+                let schema = Schema::load(&table_path)?;
+                (table_path, schema)
+            }
+        };
+        if query.joins.is_empty() {
+            validate_qualifiers(&query)?;
+        }
+        let executor = Executor::new(
+            tables_path.to_path_buf(),
+            table_path,
+            schema,
+            query,
+            available,
+        );
+        Ok((executor, virtual_tables))
     }
 
     /// Ejecuta la consulta SQL especificada.
     ///
-    /// Este método determina el tipo de operación (selección, actualización, eliminación, inserción)
-    /// basado en la consulta y llama al método correspondiente para realizar la operación.
+    /// Este método determina el tipo de operación (selección, actualización, eliminación, inserción,
+    /// creación, modificación o eliminación de tablas) basado en la consulta y llama al método correspondiente
+    /// para realizar la operación.
+    ///
+    /// Antes de ejecutarla, materializa sus tablas virtuales (`WITH`), carga el esquema de la
+    /// tabla (`tabla.schema`) si existe, para que los valores se interpreten y validen según el
+    /// tipo declarado de cada columna, y ejecuta las sub-consultas de sus condiciones (ver
+    /// `prepare` y `resolve_subqueries`).
     ///
     /// # Argumentos
     ///
@@ -72,14 +164,46 @@ impl Executor {
     /// }
     /// ```
     pub fn run(path: &str, query: Query) -> Result<(), Errored> {
-        let table_path = get_table_path(Path::new(path), &query.table)?;
-        let mut executor = Executor::new(table_path, query);
+        let (mut executor, _virtual_tables) =
+            Executor::prepare(Path::new(path), &HashMap::new(), query)?;
+        executor.resolve_subqueries()?;
         match executor.query.operation {
+            Select if !executor.query.compounds.is_empty() => executor.run_compound(),
             Select => executor.run_select(),
             Update => executor.run_update(),
             Delete => executor.run_delete(),
             Insert => executor.run_insert(),
+            Create => executor.run_create(),
+            Drop => executor.run_drop(),
+            Alter => executor.run_alter(),
             _ => errored!(Syntax, "unknown operation trying to be executed."),
         }
     }
 }
+
+/// Valida que las columnas calificadas (`tabla.columna`) de una consulta sobre una única tabla
+/// usen el nombre de esa tabla o su alias.
+///
+/// Las columnas de una tabla sin uniones no están calificadas, por lo que `resolve_column`
+/// ignora el calificador al buscarlas. Esta validación evita que un calificador cualquiera,
+/// como el de la tabla de una consulta externa, se resuelva contra la tabla equivocada.
+///
+/// # Errores
+///
+/// Retorna un error si alguna columna está calificada con otra tabla.
+fn validate_qualifiers(query: &Query) -> Result<(), Errored> {
+    let qualifiers = query.qualifiers();
+    for column in query.identifiers() {
+        if let Some((qualifier, _)) = column.value.split_once('.') {
+            if !qualifiers.contains(&qualifier) {
+                errored!(
+                    Column,
+                    "column {} does not exist, table {} is not part of the query.",
+                    column.value,
+                    qualifier
+                )
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,14 +1,19 @@
 use crate::errored;
+use crate::query::executor::limit::MatchedRows;
 use crate::query::executor::Executor;
 use crate::query::structs::comparator::ExpressionComparator;
 use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::ordering;
 use crate::query::structs::ordering::OrderKind;
-use crate::query::structs::row::Row;
+use crate::query::structs::row::{resolve_column, Row};
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Column;
-use crate::utils::files::{extract_header, get_table_file, split_csv};
+use crate::utils::files::{
+    csv_records, escape_csv, extract_header, get_table_file, split_csv_fields,
+};
 use std::cmp::Ordering;
-use std::io::{BufRead, BufReader};
+use std::collections::HashSet;
+use std::io::BufReader;
 
 impl Executor {
     /// Ejecuta la operación de selección de registros en la tabla especificada.
@@ -20,33 +25,264 @@ impl Executor {
     /// 4. Lee y procesa cada línea del archivo:
     ///    - Divide la línea en campos y los convierte en una fila (`Row`).
     ///    - Verifica si la fila cumple con las condiciones de la consulta.
-    /// 5. Ordena las filas coincidentes según los criterios de ordenamiento.
-    /// 6. Imprime el encabezado y las filas coincidentes en la salida estándar.
+    /// 5. Calcula las funciones de ventana de la consulta sobre las filas coincidentes.
+    /// 6. Ordena las filas coincidentes según los criterios de ordenamiento y, si la consulta es
+    ///    `DISTINCT`, descarta las que repiten una proyección ya vista.
+    /// 7. Imprime el encabezado y las filas coincidentes en la salida estándar.
+    ///
+    /// Si la consulta une varias tablas, las filas se obtienen de `join_tables` en lugar de
+    /// leerse línea por línea desde el archivo.
+    ///
+    /// Si la consulta tiene `LIMIT`, las filas coincidentes se acumulan en un `MatchedRows`:
+    /// sin `ORDER BY` se deja de leer la tabla apenas se juntan las filas necesarias, y con
+    /// `ORDER BY` solo se conservan en memoria las mejores filas vistas.
     ///
     /// # Errores
     ///
     /// Puede retornar un error si ocurre un problema al abrir el archivo de la tabla, leer el encabezado,
     /// procesar las líneas, validar las columnas de proyección o realizar el ordenamiento.
-    pub fn run_select(&mut self) -> Result<(), Errored> {
+    pub fn run_select(&self) -> Result<(), Errored> {
+        self.select_rows(&mut |header, rows| {
+            self.output_projection(header, rows);
+            Ok(())
+        })
+    }
+
+    /// Obtiene las filas resultantes de la selección y se las entrega a `output`, junto al
+    /// encabezado de las mismas, en lugar de imprimirlas.
+    ///
+    /// Es el proceso descrito en `run_select`, compartido con la ejecución de sub-consultas.
+    ///
+    /// # Parámetros
+    ///
+    /// - `output`: Función que recibe el encabezado y las filas ya ordenadas y paginadas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la lectura de la tabla, alguna etapa de la selección o `output`.
+    pub(super) fn select_rows<F>(&self, output: &mut F) -> Result<(), Errored>
+    where
+        F: FnMut(&[String], &[Row]) -> Result<(), Errored>,
+    {
+        if !self.query.joins.is_empty() {
+            return self.run_joined_select(output);
+        }
         let table = get_table_file(&self.table_path)?;
         let mut reader = BufReader::new(&table);
         let header = extract_header(&mut reader)?;
         self.validate_projection(&header)?;
-        let mut matched_rows: Vec<Row> = vec![];
-        for line in reader.lines() {
-            let l = line?;
-            let fields = split_csv(&l);
-            let mut row = Row::new(&header);
-            row.read_new_row(fields)?;
+        let mut matched_rows = MatchedRows::new(self.rows_to_keep(), &self.query.ordering);
+        for record in csv_records(reader) {
+            let l = record?;
+            let fields = split_csv_fields(&l)?;
+            let mut row = Row::with_schema(&header, self.schema.as_ref());
+            row.read_fields(fields)?;
+            if row.matches_condition(&self.query)? {
+                self.compute_matched(&mut row)?;
+                matched_rows.push(row);
+                if matched_rows.is_complete() {
+                    break;
+                }
+            }
+        }
+        let matched_rows = matched_rows.into_rows();
+        self.finish_select(&header, matched_rows, output)
+    }
+
+    /// Ejecuta una selección sobre el resultado de unir varias tablas.
+    ///
+    /// Las filas unidas se filtran con las condiciones de la consulta de la misma forma
+    /// que en una selección simple, pero usando el encabezado combinado de todas las tablas.
+    ///
+    /// # Errores
+    ///
+    /// Puede retornar un error si falla la unión de las tablas o la evaluación de las condiciones.
+    fn run_joined_select<F>(&self, output: &mut F) -> Result<(), Errored>
+    where
+        F: FnMut(&[String], &[Row]) -> Result<(), Errored>,
+    {
+        let (header, records) = self.join_tables()?;
+        self.validate_projection(&header)?;
+        let mut matched_rows = MatchedRows::new(self.rows_to_keep(), &self.query.ordering);
+        for values in records {
+            let mut row = Row {
+                header: &header,
+                schema: None,
+                values,
+            };
             if row.matches_condition(&self.query)? {
-                matched_rows.push(row)
+                self.compute_matched(&mut row)?;
+                matched_rows.push(row);
+                if matched_rows.is_complete() {
+                    break;
+                }
+            }
+        }
+        let matched_rows = matched_rows.into_rows();
+        self.finish_select(&header, matched_rows, output)
+    }
+
+    /// Ordena las filas que cumplieron las condiciones de la consulta y se las entrega a `output`.
+    ///
+    /// Si la consulta se agrupa, antes de ordenar se reemplazan las filas coincidentes por
+    /// una fila por grupo, que contiene además el valor de cada agregación. Los grupos se
+    /// filtran con la condición `HAVING` evaluada sobre esos valores agregados.
+    ///
+    /// Luego se calculan las funciones de ventana sobre todas las filas (o grupos) resultantes,
+    /// antes de ordenarlas y paginarlas (ver `compute_windows`).
+    ///
+    /// Las expresiones calculadas de la proyección de una consulta agrupada o con funciones de
+    /// ventana se evalúan al final, por lo que pueden operar con columnas, agregaciones y
+    /// funciones de ventana. En el resto de las consultas ya fueron evaluadas sobre cada fila
+    /// coincidente (ver `compute_matched`).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla el agrupamiento, el cálculo de alguna expresión o el
+    /// ordenamiento de las filas.
+    fn finish_select<F>(
+        &self,
+        header: &[String],
+        mut matched_rows: Vec<Row>,
+        output: &mut F,
+    ) -> Result<(), Errored>
+    where
+        F: FnMut(&[String], &[Row]) -> Result<(), Errored>,
+    {
+        if self.query.is_grouped() {
+            let mut group_header = header.to_vec();
+            group_header.extend(self.query.aggregates.iter().map(|a| a.to_string()));
+            let mut grouped_rows = vec![];
+            for group in self.group_rows(&group_header, matched_rows)? {
+                if group.matches_expression(&self.query.having)? {
+                    grouped_rows.push(group);
+                }
+            }
+            self.compute_windows(&mut grouped_rows)?;
+            for group in &mut grouped_rows {
+                self.compute_columns(group)?;
             }
+            self.sort_rows(&mut grouped_rows, &group_header)?;
+            let grouped_rows = self.distinct_rows(grouped_rows);
+            return output(&group_header, self.paginate(&grouped_rows));
+        }
+        if !self.query.windows.is_empty() {
+            self.compute_windows(&mut matched_rows)?;
+            for row in &mut matched_rows {
+                self.compute_columns(row)?;
+            }
+        }
+        self.sort_rows(&mut matched_rows, header)?;
+        let matched_rows = self.distinct_rows(matched_rows);
+        output(header, self.paginate(&matched_rows))
+    }
+
+    /// Evalúa las expresiones calculadas de la proyección sobre una fila.
+    ///
+    /// El resultado de cada expresión se guarda en los valores de la fila con el nombre de la
+    /// expresión, que es el mismo con el que aparece entre las columnas proyectadas.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la evaluación de alguna expresión.
+    fn compute_columns(&self, row: &mut Row) -> Result<(), Errored> {
+        for expression in &self.query.computed {
+            let value = expression.evaluate(&row.values)?;
+            row.values.insert(expression.to_string(), value);
         }
-        self.sort_rows(&mut matched_rows, &header)?;
-        self.output_projection(&header, &matched_rows);
         Ok(())
     }
 
+    /// Evalúa las expresiones calculadas sobre una fila que cumplió las condiciones, salvo que
+    /// la consulta se agrupe o tenga funciones de ventana (en ese caso se evalúan luego, sobre
+    /// cada grupo o una vez calculadas las ventanas).
+    ///
+    /// Al evaluarlas antes de acumular la fila, el ordenamiento puede usar sus valores aun
+    /// cuando solo se conservan las mejores filas vistas (ver `MatchedRows`).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la evaluación de alguna expresión.
+    fn compute_matched(&self, row: &mut Row) -> Result<(), Errored> {
+        if self.query.is_grouped() || !self.query.windows.is_empty() {
+            return Ok(());
+        }
+        self.compute_columns(row)
+    }
+
+    /// Descarta las filas cuya proyección ya fue vista, si la consulta es `SELECT DISTINCT`.
+    ///
+    /// Dos filas se consideran iguales si producen la misma línea de salida, es decir, el mismo
+    /// resultado de `Row::as_csv_projection` sobre las columnas proyectadas. Se conserva la
+    /// primera aparición de cada fila, manteniendo el ordenamiento previo.
+    fn distinct_rows<'a>(&self, rows: Vec<Row<'a>>) -> Vec<Row<'a>> {
+        if !self.query.distinct {
+            return rows;
+        }
+        let columns = self.projected_columns();
+        let mut seen = HashSet::new();
+        rows.into_iter()
+            .filter(|row| {
+                let projection = if columns.is_empty() {
+                    row.as_csv_row()
+                } else {
+                    row.as_csv_projection(&columns)
+                };
+                seen.insert(projection)
+            })
+            .collect()
+    }
+
+    /// Obtiene los nombres de las columnas proyectadas, vacío si se utilizó el operador `*`.
+    pub(super) fn projected_columns(&self) -> Vec<String> {
+        self.query
+            .columns
+            .iter()
+            .map(|t| t.value.to_string())
+            .collect()
+    }
+
+    /// Obtiene los nombres con los que se imprimen las columnas proyectadas en el encabezado:
+    /// el alias de cada columna, o su propio nombre si no tiene alias.
+    pub(super) fn output_columns(&self) -> Vec<String> {
+        self.query
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| match self.query.aliases.get(i) {
+                Some(Some(alias)) => alias.to_string(),
+                _ => column.value.to_string(),
+            })
+            .collect()
+    }
+
+    /// Calcula cuántas filas coincidentes es necesario conservar para responder la consulta.
+    ///
+    /// # Retorna
+    ///
+    /// La suma de `LIMIT` y `OFFSET`, o `None` si no hay límite, si la consulta se agrupa
+    /// o tiene funciones de ventana (en ese caso todas las filas participan del cálculo de los
+    /// grupos o de las ventanas) o si es `DISTINCT` (no se sabe de antemano cuántas filas se
+    /// van a descartar por repetidas).
+    fn rows_to_keep(&self) -> Option<usize> {
+        if self.query.is_grouped() || !self.query.windows.is_empty() || self.query.distinct {
+            return None;
+        }
+        self.query
+            .limit
+            .map(|limit| limit.saturating_add(self.query.offset))
+    }
+
+    /// Recorta las filas ya ordenadas según el `OFFSET` y el `LIMIT` de la consulta.
+    fn paginate<'r, 'a>(&self, rows: &'r [Row<'a>]) -> &'r [Row<'a>] {
+        let start = self.query.offset.min(rows.len());
+        let end = match self.query.limit {
+            Some(limit) => start.saturating_add(limit).min(rows.len()),
+            None => rows.len(),
+        };
+        &rows[start..end]
+    }
+
     /// Ordena las filas coincidentes según los criterios de ordenamiento especificados en la consulta.
     ///
     /// Este método toma las filas coincidentes y las ordena en función de los campos y el tipo de ordenamiento
@@ -56,6 +292,10 @@ impl Executor {
     /// Si hay varios ordenamientos en la consulta, primero se evalua uno y si el resultado es igual,
     /// se compara por el siguiente.
     ///
+    /// Además de las columnas del encabezado, se puede ordenar por las expresiones calculadas
+    /// y las funciones de ventana de la proyección, generalmente a través de su alias
+    /// (`ORDER BY total`).
+    ///
     /// # Errores
     ///
     /// Retorna un error si alguno de los campos de ordenamiento no existe en el encabezado.
@@ -63,9 +303,16 @@ impl Executor {
     /// # Ejemplo
     ///
     /// Este método es llamado internamente por `run_select`, por lo que no tiene un ejemplo de uso independiente.
-    fn sort_rows(&mut self, matched_rows: &mut [Row], header: &[String]) -> Result<(), Errored> {
+    fn sort_rows(&self, matched_rows: &mut [Row], header: &[String]) -> Result<(), Errored> {
+        let computed: Vec<String> = self
+            .query
+            .computed
+            .iter()
+            .map(|e| e.to_string())
+            .chain(self.query.windows.iter().map(|w| w.to_string()))
+            .collect();
         for order in &self.query.ordering {
-            if !header.contains(&order.field.value) {
+            if resolve_column(header.iter().chain(&computed), &order.field.value)?.is_none() {
                 errored!(
                     Column,
                     "order by failed, column {} does not exist",
@@ -73,24 +320,7 @@ impl Executor {
                 )
             }
         }
-        matched_rows.sort_by(|a, b| {
-            for order in &self.query.ordering {
-                let l = ExpressionNode::get_variable_value(&a.values, &order.field);
-                let r = ExpressionNode::get_variable_value(&b.values, &order.field);
-                if let (Ok(a), Ok(b)) = (l, r) {
-                    let comparison_result = match order.kind {
-                        OrderKind::Asc => ExpressionComparator::compare_ordering(&a, &b)
-                            .unwrap_or(Ordering::Equal),
-                        OrderKind::Desc => ExpressionComparator::compare_ordering(&b, &a)
-                            .unwrap_or(Ordering::Equal),
-                    };
-                    if comparison_result != Ordering::Equal {
-                        return comparison_result;
-                    }
-                }
-            }
-            Ordering::Equal
-        });
+        matched_rows.sort_by(|a, b| compare_rows(&self.query.ordering, a, b));
         Ok(())
     }
 
@@ -101,23 +331,24 @@ impl Executor {
     ///
     /// Ademas, se encarga de imprimir la proyección del header del csv.
     /// Si las columnas proyectadas son vacias, se asume que el operador * esta siendo usado,
-    /// de lo contrario se imprime el header proyectado a las columnas.
+    /// de lo contrario se imprime el header proyectado a las columnas, nombrando cada una con su
+    /// alias si lo tiene (ver `output_columns`). Los nombres de columnas que lo necesiten, como
+    /// `SUBSTR(nombre, 1, 3)`, se escriben entre comillas.
     ///
     /// # Ejemplo
     ///
     /// Este método es llamado internamente por `run_select`, por lo que no tiene un ejemplo de uso independiente.
     fn output_projection(&self, header: &[String], matched_rows: &[Row]) {
-        let mut columns = vec![];
-        if self.query.columns.is_empty() {
+        let columns = self.projected_columns();
+        if columns.is_empty() {
             println!("{}", header.join(","));
         } else {
-            columns = self
-                .query
-                .columns
+            let names: Vec<String> = self
+                .output_columns()
                 .iter()
-                .map(|t| t.value.to_string())
+                .map(|c| escape_csv(c))
                 .collect();
-            println!("{}", columns.join(","));
+            println!("{}", names.join(","));
         }
         for row in matched_rows {
             row.print_projection(&columns)
@@ -129,17 +360,43 @@ impl Executor {
     /// Este método verifica que todas las columnas que se desean proyectar en la consulta SQL (`self.query.columns`)
     /// estén presentes en el encabezado del archivo de la tabla. Si alguna columna no existe, retorna un error.
     ///
+    /// Las columnas que corresponden a agregaciones, funciones de ventana o expresiones calculadas
+    /// no se buscan en el encabezado, en su lugar se validan las columnas sobre las que operan,
+    /// al igual que las columnas del `GROUP BY`.
+    ///
+    /// Si la consulta se agrupa, valida además que las columnas leídas de cada grupo sean de
+    /// agrupamiento (ver `validate_grouping`).
+    ///
     /// # Errores
     ///
-    /// Retorna un error si alguna columna en la proyección no existe en el encabezado.
+    /// Retorna un error si alguna columna en la proyección no existe en el encabezado, o si la
+    /// consulta se agrupa y alguna columna no es de agrupamiento.
     ///
     /// # Ejemplo
     ///
     /// Este método es llamado internamente por `run_select`, por lo que no tiene un ejemplo de uso independiente.
     fn validate_projection(&self, header: &[String]) -> Result<(), Errored> {
-        for column in &self.query.columns {
+        let derived: Vec<String> = self
+            .query
+            .aggregates
+            .iter()
+            .map(|a| a.to_string())
+            .chain(self.query.windows.iter().map(|w| w.to_string()))
+            .chain(self.query.computed.iter().map(|e| e.to_string()))
+            .collect();
+        let columns = self
+            .query
+            .columns
+            .iter()
+            .chain(self.query.windows.iter().flat_map(|w| w.columns()))
+            .filter(|c| !derived.contains(&c.value))
+            .chain(&self.query.group_by)
+            .chain(self.query.aggregates.iter().map(|a| &a.field))
+            .chain(self.query.computed.iter().flat_map(|e| e.identifiers()))
+            .filter(|c| c.value != "*");
+        for column in columns {
             let value = &column.value;
-            if !header.contains(value) {
+            if resolve_column(header, value)?.is_none() {
                 errored!(
                     Column,
                     "column {} in projection does not exist in table.",
@@ -147,6 +404,38 @@ impl Executor {
                 )
             }
         }
+        if self.query.is_grouped() {
+            self.validate_grouping()?;
+        }
         Ok(())
     }
 }
+
+/// Compara dos filas según una lista de criterios de ordenamiento.
+///
+/// Primero se compara por el primer criterio y, si el resultado es igual, se compara por el siguiente.
+/// Los valores que no pueden obtenerse o compararse se consideran iguales.
+///
+/// # Retorna
+///
+/// El orden relativo entre las filas `a` y `b`.
+pub(super) fn compare_rows(ordering: &[ordering::Ordering], a: &Row, b: &Row) -> Ordering {
+    for order in ordering {
+        let l = ExpressionNode::get_variable_value(&a.values, &order.field);
+        let r = ExpressionNode::get_variable_value(&b.values, &order.field);
+        if let (Ok(a), Ok(b)) = (l, r) {
+            let comparison_result = match order.kind {
+                OrderKind::Asc => {
+                    ExpressionComparator::compare_ordering(&a, &b).unwrap_or(Ordering::Equal)
+                }
+                OrderKind::Desc => {
+                    ExpressionComparator::compare_ordering(&b, &a).unwrap_or(Ordering::Equal)
+                }
+            };
+            if comparison_result != Ordering::Equal {
+                return comparison_result;
+            }
+        }
+    }
+    Ordering::Equal
+}
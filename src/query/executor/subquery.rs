@@ -0,0 +1,364 @@
+use crate::errored;
+use crate::query::executor::common_table::resolve_table_path;
+use crate::query::executor::Executor;
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::expression::ExpressionResult::{self, Bool, Null};
+use crate::query::structs::function::Function;
+use crate::query::structs::query::Query;
+use crate::query::structs::token::Token;
+use crate::query::structs::token::TokenKind::Identifier;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::{Column, Default};
+use crate::utils::files::{extract_header, get_table_file};
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+/// Valores de las filas resultantes de una consulta, en el orden de sus columnas proyectadas.
+pub(super) type Rows = Vec<Vec<ExpressionResult>>;
+
+impl Executor {
+    /// Ejecuta las sub-consultas de las condiciones de la consulta y las reemplaza por sus resultados.
+    ///
+    /// También se resuelven las sub-consultas de las condiciones de cada unión, las de las
+    /// actualizaciones (`SET id = (SELECT MAX(id) FROM usuarios) + 1`) y las escalares de los
+    /// valores de una inserción (`VALUES ((SELECT MAX(id) FROM usuarios) + 1)`).
+    ///
+    /// Las sub-consultas no dependen de la fila que se está evaluando, por lo que cada una se
+    /// ejecuta una única vez, antes de recorrer la tabla principal:
+    ///
+    /// - `EXISTS (SELECT ...)` se reemplaza por `true` si la sub-consulta tiene alguna fila.
+    /// - `x IN (SELECT ...)` se reemplaza por la lista de valores de la sub-consulta, que se
+    ///   busca con un conjunto si es grande (ver `ExpressionNode::in_list`).
+    /// - Una sub-consulta escalar se reemplaza por su único valor, o `NULL` si no tiene filas.
+    ///   Si es el patrón de un `LIKE`, el patrón se interpreta una única vez (ver `ExpressionNode::like`).
+    ///
+    /// Por lo mismo, no se admiten sub-consultas correlacionadas (ver `reject_correlated`).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna sub-consulta falla o es correlacionada, si la sub-consulta de un `IN` o una escalar
+    /// no proyecta exactamente una columna, o si una sub-consulta escalar devuelve varias filas.
+    pub(super) fn resolve_subqueries(&mut self) -> Result<(), Errored> {
+        let conditions = mem::take(&mut self.query.conditions);
+        self.query.conditions = self.resolve(conditions)?;
+        let having = mem::take(&mut self.query.having);
+        self.query.having = self.resolve(having)?;
+        let updates = mem::take(&mut self.query.updates);
+        self.query.updates = self.resolve_all(updates)?;
+        let mut joins = mem::take(&mut self.query.joins);
+        for join in &mut joins {
+            join.conditions = self.resolve(mem::take(&mut join.conditions))?;
+        }
+        self.query.joins = joins;
+        let inserts = mem::take(&mut self.query.inserts);
+        self.query.inserts = inserts
+            .into_iter()
+            .map(|values| self.resolve_all(values))
+            .collect::<Result<_, Errored>>()?;
+        Ok(())
+    }
+
+    /// Reemplaza recursivamente las sub-consultas de una expresión por sus resultados.
+    fn resolve(&self, node: ExpressionNode) -> Result<ExpressionNode, Errored> {
+        let resolved = match node {
+            ExpressionNode::Statement {
+                operator,
+                left,
+                right,
+            } => ExpressionNode::Statement {
+                operator,
+                left: Box::new(self.resolve(*left)?),
+                right: Box::new(self.resolve(*right)?),
+            },
+            ExpressionNode::Like {
+                expression,
+                operator,
+                pattern,
+                ..
+            } => ExpressionNode::like(
+                self.resolve(*expression)?,
+                operator,
+                self.resolve(*pattern)?,
+            ),
+            ExpressionNode::InList {
+                expression,
+                mut list,
+                negated,
+                ..
+            } => {
+                let expression = self.resolve(*expression)?;
+                let list = match list.as_mut_slice() {
+                    [ExpressionNode::Subquery(query)] => self
+                        .single_column(*mem::take(query))?
+                        .into_iter()
+                        .map(ExpressionNode::Constant)
+                        .collect(),
+                    _ => self.resolve_all(list)?,
+                };
+                ExpressionNode::in_list(expression, list, negated)
+            }
+            ExpressionNode::Between {
+                expression,
+                low,
+                high,
+                negated,
+            } => ExpressionNode::Between {
+                expression: Box::new(self.resolve(*expression)?),
+                low: Box::new(self.resolve(*low)?),
+                high: Box::new(self.resolve(*high)?),
+                negated,
+            },
+            ExpressionNode::Function(function) => ExpressionNode::Function(Function {
+                kind: function.kind,
+                arguments: self.resolve_all(function.arguments)?,
+            }),
+            ExpressionNode::Case {
+                operand,
+                branches,
+                default,
+            } => ExpressionNode::Case {
+                operand: match operand {
+                    Some(operand) => Some(Box::new(self.resolve(*operand)?)),
+                    None => None,
+                },
+                branches: branches
+                    .into_iter()
+                    .map(|(when, then)| Ok((self.resolve(when)?, self.resolve(then)?)))
+                    .collect::<Result<_, Errored>>()?,
+                default: match default {
+                    Some(default) => Some(Box::new(self.resolve(*default)?)),
+                    None => None,
+                },
+            },
+            ExpressionNode::Subquery(query) => {
+                let mut values = self.single_column(*query)?;
+                if values.len() > 1 {
+                    errored!(
+                        Default,
+                        "scalar subquery returned {} rows, expected at most one.",
+                        values.len()
+                    )
+                }
+                ExpressionNode::Constant(values.pop().unwrap_or(Null))
+            }
+            ExpressionNode::Exists(mut query) => {
+                self.reject_correlated(&query)?;
+                query.limit = Some(query.limit.map_or(1, |limit| limit.min(1)));
+                let rows = self.run_subquery(*query)?.1;
+                ExpressionNode::Constant(Bool(!rows.is_empty()))
+            }
+            node => node,
+        };
+        Ok(resolved)
+    }
+
+    /// Reemplaza las sub-consultas de cada una de las expresiones.
+    fn resolve_all(&self, nodes: Vec<ExpressionNode>) -> Result<Vec<ExpressionNode>, Errored> {
+        nodes.into_iter().map(|node| self.resolve(node)).collect()
+    }
+
+    /// Verifica que una sub-consulta no sea correlacionada, es decir, que no utilice columnas
+    /// de las tablas de esta consulta (`WHERE ordenes.id_cliente = clientes.id`).
+    ///
+    /// Como cada sub-consulta se ejecuta una única vez, antes de recorrer la tabla principal,
+    /// no hay una fila externa contra la cual resolver esas columnas.
+    ///
+    /// Una columna sin calificar se considera externa si no pertenece a ninguna de las tablas de
+    /// la sub-consulta pero sí a alguna de las de esta consulta. Si pertenece a ambas, se resuelve
+    /// contra la sub-consulta, como en SQL estándar.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la sub-consulta, o alguna de las consultas que combina, utiliza una
+    /// columna de esta consulta, o si no existe alguna de sus tablas.
+    fn reject_correlated(&self, query: &Query) -> Result<(), Errored> {
+        let outer = self.query.qualifiers();
+        let outer_columns = self.table_columns(&self.query)?;
+        let queries = std::iter::once(query).chain(query.compounds.iter().map(|c| &c.query));
+        for query in queries {
+            let inner = query.qualifiers();
+            let inner_columns = self.table_columns(query)?;
+            for column in query.identifiers() {
+                let correlated = match column.value.split_once('.') {
+                    Some((qualifier, _)) => {
+                        outer.contains(&qualifier) && !inner.contains(&qualifier)
+                    }
+                    None => {
+                        !inner_columns.contains(&column.value)
+                            && !query.aliases.contains(&Some(column.value.to_string()))
+                            && outer_columns.contains(&column.value)
+                    }
+                };
+                if correlated {
+                    errored!(
+                        Column,
+                        "correlated subqueries are not supported, column {} references the outer query.",
+                        column.value
+                    )
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Obtiene los nombres de las columnas de la tabla principal de una consulta y de cada una
+    /// de sus tablas unidas, buscándolas también entre las tablas virtuales.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna de las tablas no existe o no se puede leer su encabezado.
+    fn table_columns(&self, query: &Query) -> Result<Vec<String>, Errored> {
+        let tables = std::iter::once(&query.table).chain(query.joins.iter().map(|j| &j.table));
+        let mut columns = vec![];
+        for table in tables {
+            let path = resolve_table_path(&self.tables_path, &self.virtual_tables, table)?;
+            let file = get_table_file(&path)?;
+            columns.extend(extract_header(&mut BufReader::new(&file))?);
+        }
+        Ok(columns)
+    }
+
+    /// Ejecuta una sub-consulta que debe proyectar una única columna.
+    ///
+    /// # Retorna
+    ///
+    /// Los valores de la columna proyectada, uno por cada fila resultante.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la sub-consulta falla o si no proyecta exactamente una columna.
+    fn single_column(&self, query: Query) -> Result<Vec<ExpressionResult>, Errored> {
+        self.reject_correlated(&query)?;
+        let (columns, rows) = self.run_subquery(query)?;
+        if columns.len() != 1 {
+            errored!(
+                Column,
+                "subquery must return exactly one column, got ({}).",
+                columns.len()
+            )
+        }
+        Ok(rows.into_iter().flatten().collect())
+    }
+
+    /// Ejecuta una sub-consulta `SELECT` sobre su propia tabla, ubicada en el mismo
+    /// directorio que la tabla de la consulta principal o entre sus tablas virtuales.
+    ///
+    /// La sub-consulta se ejecuta con un nuevo `Executor`, por lo que puede contener a su vez
+    /// otras sub-consultas o combinar varios SELECT (ver `compound_rows`).
+    ///
+    /// # Retorna
+    ///
+    /// Los nombres de las columnas proyectadas y los valores de cada fila resultante.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la tabla de la sub-consulta no existe o si falla su ejecución.
+    pub(super) fn run_subquery(&self, query: Query) -> Result<(Vec<String>, Rows), Errored> {
+        Executor::run_query(&self.tables_path, &self.virtual_tables, query)
+    }
+
+    /// Ejecuta una consulta `SELECT` y obtiene sus resultados en lugar de imprimirlos.
+    ///
+    /// # Parámetros
+    ///
+    /// - `tables_path`: El directorio de las tablas.
+    /// - `virtual_tables`: Las tablas virtuales disponibles para la consulta, por nombre.
+    /// - `query`: La consulta a ejecutar.
+    ///
+    /// # Retorna
+    ///
+    /// Los nombres de las columnas proyectadas y los valores de cada fila resultante.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna tabla de la consulta no existe o si falla su ejecución.
+    pub(super) fn run_query(
+        tables_path: &Path,
+        virtual_tables: &HashMap<String, PathBuf>,
+        query: Query,
+    ) -> Result<(Vec<String>, Rows), Errored> {
+        let (mut executor, _virtual_tables) =
+            Executor::prepare(tables_path, virtual_tables, query)?;
+        executor.query_results()
+    }
+
+    /// Ejecuta la consulta `SELECT` del ejecutor, ya preparado, y obtiene sus resultados en
+    /// lugar de imprimirlos (ver `run_query`).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla alguna de sus sub-consultas o su ejecución.
+    pub(super) fn query_results(&mut self) -> Result<(Vec<String>, Rows), Errored> {
+        self.resolve_subqueries()?;
+        if self.query.compounds.is_empty() {
+            self.selected_values()
+        } else {
+            self.compound_rows()
+        }
+    }
+
+    /// Ejecuta la selección de la consulta y obtiene los valores proyectados de cada fila,
+    /// en lugar de imprimirlos.
+    ///
+    /// # Retorna
+    ///
+    /// Los nombres de las columnas tal como se imprimen en el encabezado (ver `output_columns`)
+    /// y los valores de cada fila resultante, en el mismo orden.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la selección.
+    pub(super) fn selected_values(&self) -> Result<(Vec<String>, Rows), Errored> {
+        let mut rows = vec![];
+        let columns = self.stream_selected_values(&mut |_, values| {
+            rows.push(values);
+            Ok(())
+        })?;
+        Ok((columns, rows))
+    }
+
+    /// Ejecuta la selección de la consulta y entrega los valores proyectados de cada fila a
+    /// `output` a medida que se obtienen, sin acumularlos.
+    ///
+    /// # Parámetros
+    ///
+    /// - `output`: Función que recibe los nombres de las columnas y los valores de una fila.
+    ///
+    /// # Retorna
+    ///
+    /// Los nombres de las columnas tal como se imprimen en el encabezado (ver `output_columns`).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la selección o `output`.
+    pub(super) fn stream_selected_values<F>(&self, output: &mut F) -> Result<Vec<String>, Errored>
+    where
+        F: FnMut(&[String], Vec<ExpressionResult>) -> Result<(), Errored>,
+    {
+        let projected = self.projected_columns();
+        let mut columns = self.output_columns();
+        self.select_rows(&mut |header, matched_rows| {
+            let fields = if projected.is_empty() {
+                columns = header.to_vec();
+                header
+            } else {
+                &projected
+            };
+            for row in matched_rows {
+                let mut values = vec![];
+                for field in fields {
+                    let column = Token {
+                        value: field.to_string(),
+                        kind: Identifier,
+                    };
+                    values.push(ExpressionNode::get_variable_value(&row.values, &column)?);
+                }
+                output(&columns, values)?;
+            }
+            Ok(())
+        })?;
+        Ok(columns)
+    }
+}
@@ -0,0 +1,157 @@
+use crate::query::executor::select::compare_rows;
+use crate::query::executor::Executor;
+use crate::query::structs::aggregate::{Aggregate, AggregateKind};
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::{Int, Null};
+use crate::query::structs::row::Row;
+use crate::query::structs::window::Window;
+use crate::query::structs::window::WindowKind::{Lag, Lead, Rank, RowNumber, Sum};
+use crate::utils::errors::Errored;
+use std::cmp::Ordering::Equal;
+use std::collections::HashMap;
+use std::ops::Range;
+
+impl Executor {
+    /// Calcula las funciones de ventana de la consulta sobre las filas resultantes.
+    ///
+    /// El valor de cada función se guarda en los valores de cada fila con el nombre SQL de la
+    /// función, que es el mismo con el que aparece entre las columnas proyectadas. Las filas
+    /// conservan su orden: el ordenamiento de cada ventana solo determina el recorrido de su
+    /// partición, y es independiente del `ORDER BY` de la consulta.
+    ///
+    /// # Proceso
+    ///
+    /// 1. Divide las filas en particiones según los valores de las columnas del `PARTITION BY`,
+    ///    de la misma forma en que `group_rows` arma los grupos.
+    /// 2. Ordena las filas de cada partición con los mismos criterios que `sort_rows`
+    ///    (ver `compare_rows`), conservando el orden original entre las filas empatadas.
+    /// 3. Recorre cada partición calculando el valor de la función para cada fila.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna columna de la ventana no existe o si falla la suma de valores.
+    pub(super) fn compute_windows(&self, rows: &mut [Row]) -> Result<(), Errored> {
+        for window in &self.query.windows {
+            let values = window_values(window, rows)?;
+            let name = window.to_string();
+            for (row, value) in rows.iter_mut().zip(values) {
+                row.values.insert(name.to_string(), value);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Calcula el valor de una función de ventana para cada fila, en el orden de las filas.
+fn window_values(window: &Window, rows: &[Row]) -> Result<Vec<ExpressionResult>, Errored> {
+    let mut values = vec![Null; rows.len()];
+    for partition in partitions(window, rows)? {
+        match &window.kind {
+            RowNumber => {
+                for (position, &i) in partition.iter().enumerate() {
+                    values[i] = Int(position as i64 + 1);
+                }
+            }
+            Rank => {
+                for peers in peer_groups(window, rows, &partition) {
+                    let rank = Int(peers.start as i64 + 1);
+                    for &i in &partition[peers] {
+                        values[i] = rank.clone();
+                    }
+                }
+            }
+            Lag { offset, default } | Lead { offset, default } => {
+                for (position, &i) in partition.iter().enumerate() {
+                    let target = match window.kind {
+                        Lag { .. } => position.checked_sub(*offset),
+                        _ => position.checked_add(*offset),
+                    };
+                    values[i] = match target.and_then(|target| partition.get(target)) {
+                        Some(&j) => field_value(window, &rows[j])?,
+                        None => default.clone(),
+                    };
+                }
+            }
+            Sum => {
+                let aggregate = Aggregate {
+                    kind: AggregateKind::Sum,
+                    field: window.field.clone().unwrap_or_default(),
+                    distinct: false,
+                };
+                let mut total = Null;
+                for peers in peer_groups(window, rows, &partition) {
+                    for &i in &partition[peers.clone()] {
+                        total = match (field_value(window, &rows[i])?, total) {
+                            (Null, total) => total,
+                            (value, Null) => aggregate.sum(&[value])?,
+                            (value, total) => aggregate.sum(&[total, value])?,
+                        };
+                    }
+                    for &i in &partition[peers] {
+                        values[i] = total.clone();
+                    }
+                }
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// Divide las filas en las particiones de la ventana, conservando el orden en que aparece cada
+/// partición por primera vez, y ordena cada una según el ordenamiento de la ventana.
+///
+/// # Retorna
+///
+/// Los índices de las filas de cada partición, en el orden en que deben recorrerse.
+fn partitions(window: &Window, rows: &[Row]) -> Result<Vec<Vec<usize>>, Errored> {
+    let mut index: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut partitions: Vec<Vec<usize>> = vec![];
+    for (i, row) in rows.iter().enumerate() {
+        let mut key = vec![];
+        for column in &window.partition_by {
+            key.push(ExpressionNode::get_variable_value(&row.values, column)?.to_string());
+        }
+        match index.get(&key) {
+            Some(p) => partitions[*p].push(i),
+            None => {
+                index.insert(key, partitions.len());
+                partitions.push(vec![i]);
+            }
+        }
+    }
+    for partition in &mut partitions {
+        partition.sort_by(|a, b| compare_rows(&window.ordering, &rows[*a], &rows[*b]));
+    }
+    Ok(partitions)
+}
+
+/// Separa una partición ya ordenada en grupos de filas consecutivas empatadas según el
+/// ordenamiento de la ventana. Sin ordenamiento, toda la partición es un único grupo.
+///
+/// # Retorna
+///
+/// El rango de posiciones dentro de la partición que ocupa cada grupo.
+fn peer_groups(window: &Window, rows: &[Row], partition: &[usize]) -> Vec<Range<usize>> {
+    let mut groups: Vec<Range<usize>> = vec![];
+    for (position, &i) in partition.iter().enumerate() {
+        match groups.last_mut() {
+            Some(group)
+                if compare_rows(&window.ordering, &rows[partition[group.start]], &rows[i])
+                    == Equal =>
+            {
+                group.end = position + 1
+            }
+            _ => groups.push(position..position + 1),
+        }
+    }
+    groups
+}
+
+/// Obtiene el valor de la columna sobre la que opera la ventana en una fila.
+fn field_value(window: &Window, row: &Row) -> Result<ExpressionResult, Errored> {
+    match &window.field {
+        Some(field) => ExpressionNode::get_variable_value(&row.values, field),
+        None => Ok(Null),
+    }
+}
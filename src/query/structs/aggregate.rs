@@ -0,0 +1,280 @@
+use crate::errored;
+use crate::query::structs::aggregate::AggregateKind::*;
+use crate::query::structs::arithmetic::ExpressionCalculator;
+use crate::query::structs::comparator::ExpressionComparator;
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::expression::ExpressionOperator;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::{Float, Int, Null};
+use crate::query::structs::row::Row;
+use crate::query::structs::token::Token;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
+use std::cmp::Ordering::{Greater, Less};
+use std::collections::HashSet;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Estructura que representa una función de agregación dentro de una consulta SELECT.
+///
+/// Una agregación resume los valores de una columna a lo largo de un grupo de filas.
+///
+/// # Campos
+///
+/// * `kind` - La función de agregación a aplicar.
+/// * `field` - La columna sobre la que se aplica la función, o el operador `*` en el caso de `COUNT(*)`.
+/// * `distinct` - Indica si la función opera solo sobre los valores distintos de la columna.
+#[derive(Clone, PartialEq)]
+pub struct Aggregate {
+    pub kind: AggregateKind,
+    pub field: Token,
+    pub distinct: bool,
+}
+
+/// Enum que representa las funciones de agregación disponibles.
+///
+/// - `Count`: Cuenta las filas (o los valores no nulos de una columna).
+/// - `Sum`: Suma los valores numéricos de una columna.
+/// - `Avg`: Promedia los valores numéricos de una columna.
+/// - `Min`: Obtiene el menor valor de una columna.
+/// - `Max`: Obtiene el mayor valor de una columna.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregateKind {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+impl AggregateKind {
+    /// Obtiene la función de agregación correspondiente a un nombre, sin distinguir mayúsculas.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::aggregate::AggregateKind;
+    /// assert_eq!(AggregateKind::from_name("count"), Some(AggregateKind::Count));
+    /// assert_eq!(AggregateKind::from_name("name"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<AggregateKind> {
+        match name.to_uppercase().as_str() {
+            "COUNT" => Some(Count),
+            "SUM" => Some(Sum),
+            "AVG" => Some(Avg),
+            "MIN" => Some(Min),
+            "MAX" => Some(Max),
+            _ => None,
+        }
+    }
+}
+
+impl Aggregate {
+    /// Calcula el valor de la agregación sobre un grupo de filas.
+    ///
+    /// Los valores `NULL` de la columna son ignorados por todas las funciones,
+    /// excepto por `COUNT(*)` que cuenta todas las filas del grupo. Si la agregación es
+    /// `DISTINCT`, los valores repetidos se consideran una única vez.
+    ///
+    /// # Retorna
+    ///
+    /// Un `Result` con el valor de la agregación, o `NULL` si el grupo no tiene
+    /// valores sobre los que operar (excepto `COUNT`, que devuelve cero).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la columna no existe, si se intenta sumar o promediar valores
+    /// no numéricos, o si los valores no son comparables entre sí.
+    pub fn compute(&self, rows: &[Row]) -> Result<ExpressionResult, Errored> {
+        if self.field.value == "*" {
+            return Ok(Int(rows.len() as i64));
+        }
+        let mut values = vec![];
+        let mut seen = HashSet::new();
+        for row in rows {
+            let value = ExpressionNode::get_variable_value(&row.values, &self.field)?;
+            if value != Null && (!self.distinct || seen.insert(value.clone())) {
+                values.push(value);
+            }
+        }
+        match self.kind {
+            Count => Ok(Int(values.len() as i64)),
+            Sum => self.sum(&values),
+            Avg if values.is_empty() => Ok(Null),
+            Avg => {
+                let average = self.sum(&values)?.as_float() / values.len() as f64;
+                Ok(Float(average))
+            }
+            Min => Aggregate::pick(values, Less),
+            Max => Aggregate::pick(values, Greater),
+        }
+    }
+
+    /// Suma los valores de la columna, que deben ser numéricos.
+    ///
+    /// El resultado es un entero si todos los valores lo son, o un flotante si alguno
+    /// tiene parte decimal.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguno de los valores no es numérico o si la suma desborda.
+    pub fn sum(&self, values: &[ExpressionResult]) -> Result<ExpressionResult, Errored> {
+        let add = &ExpressionOperator::Add;
+        let mut total = Int(0);
+        for value in values {
+            total = match (&total, value) {
+                (Int(t), Int(i)) => ExpressionCalculator::calculate_ints(*t, *i, add)?,
+                (t, v) if v.is_numeric() => {
+                    ExpressionCalculator::calculate_floats(t.as_float(), v.as_float(), add)?
+                }
+                _ => errored!(
+                    Syntax,
+                    "{} can only be applied to numeric values, got: {:?}",
+                    self,
+                    value
+                ),
+            };
+        }
+        Ok(total)
+    }
+
+    /// Selecciona el valor que queda en el extremo indicado por `target` al comparar todos los valores.
+    fn pick(
+        values: Vec<ExpressionResult>,
+        target: std::cmp::Ordering,
+    ) -> Result<ExpressionResult, Errored> {
+        let mut picked: Option<ExpressionResult> = None;
+        for value in values {
+            picked = match picked {
+                Some(current)
+                    if ExpressionComparator::compare_ordering(&value, &current)? != target =>
+                {
+                    Some(current)
+                }
+                _ => Some(value),
+            };
+        }
+        Ok(picked.unwrap_or(Null))
+    }
+}
+
+impl Display for Aggregate {
+    /// Representa a la agregación tal cual se escribe en SQL, por ejemplo `COUNT(*)`
+    /// o `COUNT(DISTINCT id)`.
+    ///
+    /// Este nombre es el que se utiliza como columna del resultado agrupado.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = format!("{:?}", self.kind).to_uppercase();
+        let distinct = if self.distinct { "DISTINCT " } else { "" };
+        write!(f, "{}({}{})", name, distinct, self.field.value)
+    }
+}
+
+impl Debug for Aggregate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::structs::expression::ExpressionResult::Str;
+    use crate::query::structs::token::TokenKind::{Identifier, Operator};
+
+    fn aggregate(kind: AggregateKind, field: &str) -> Aggregate {
+        let kind_of_field = if field == "*" { Operator } else { Identifier };
+        Aggregate {
+            kind,
+            field: Token {
+                value: field.to_string(),
+                kind: kind_of_field,
+            },
+            distinct: false,
+        }
+    }
+
+    fn rows_for<'a>(header: &'a Vec<String>, values: &[&str]) -> Vec<Row<'a>> {
+        values
+            .iter()
+            .map(|v| {
+                let mut row = Row::new(header);
+                row.read_new_row(vec![v.to_string()]).unwrap();
+                row
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregate_name() {
+        assert_eq!(aggregate(Count, "*").to_string(), "COUNT(*)");
+        assert_eq!(aggregate(Sum, "precio").to_string(), "SUM(precio)");
+    }
+
+    #[test]
+    fn test_count() {
+        let header = vec!["cantidad".to_string()];
+        let rows = rows_for(&header, &["1", "", "3"]);
+        assert_eq!(aggregate(Count, "*").compute(&rows).unwrap(), Int(3));
+        assert_eq!(aggregate(Count, "cantidad").compute(&rows).unwrap(), Int(2));
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let header = vec!["producto".to_string()];
+        let rows = rows_for(&header, &["Mouse", "Laptop", "", "Mouse"]);
+        let mut count = aggregate(Count, "producto");
+        count.distinct = true;
+        assert_eq!(count.to_string(), "COUNT(DISTINCT producto)");
+        assert_eq!(count.compute(&rows).unwrap(), Int(2));
+    }
+
+    #[test]
+    fn test_sum_and_avg() {
+        let header = vec!["cantidad".to_string()];
+        let rows = rows_for(&header, &["1", "2", "4"]);
+        assert_eq!(aggregate(Sum, "cantidad").compute(&rows).unwrap(), Int(7));
+        assert_eq!(
+            aggregate(Avg, "cantidad").compute(&rows).unwrap(),
+            Float(2.3333333333333335)
+        );
+    }
+
+    #[test]
+    fn test_sum_and_avg_with_floats() {
+        let header = vec!["precio".to_string()];
+        let rows = rows_for(&header, &["1.5", "2", "", "4.25"]);
+        assert_eq!(
+            aggregate(Sum, "precio").compute(&rows).unwrap(),
+            Float(7.75)
+        );
+        assert_eq!(
+            aggregate(Avg, "precio").compute(&rows).unwrap(),
+            Float(7.75 / 3.0)
+        );
+        assert_eq!(
+            aggregate(Max, "precio").compute(&rows).unwrap(),
+            Float(4.25)
+        );
+    }
+
+    #[test]
+    fn test_sum_non_numeric() {
+        let header = vec!["producto".to_string()];
+        let rows = rows_for(&header, &["Laptop", "Mouse"]);
+        assert!(aggregate(Sum, "producto").compute(&rows).is_err());
+    }
+
+    #[test]
+    fn test_min_and_max() {
+        let header = vec!["producto".to_string()];
+        let rows = rows_for(&header, &["Mouse", "Laptop", "Teclado"]);
+        assert_eq!(
+            aggregate(Min, "producto").compute(&rows).unwrap(),
+            Str("Laptop".to_string())
+        );
+        assert_eq!(
+            aggregate(Max, "producto").compute(&rows).unwrap(),
+            Str("Teclado".to_string())
+        );
+    }
+}
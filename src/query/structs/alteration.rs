@@ -0,0 +1,264 @@
+use crate::errored;
+use crate::query::structs::schema::{ColumnType, Schema};
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::{Column, Table};
+use crate::utils::files::escape_csv_value;
+
+/// Enum que representa la modificación de la estructura de una tabla (`ALTER TABLE`).
+///
+/// - `AddColumn`: Agrega una columna al final de la tabla, con un tipo opcional y un valor
+///   por defecto (`DEFAULT`) para las filas existentes, que si se omite es `NULL`.
+/// - `DropColumn`: Elimina una columna de la tabla junto a todos sus valores.
+/// - `RenameColumn`: Cambia el nombre de una columna, conservando sus valores.
+#[derive(Debug, PartialEq)]
+pub enum Alteration {
+    AddColumn {
+        column: String,
+        kind: Option<ColumnType>,
+        default: Option<String>,
+    },
+    DropColumn(String),
+    RenameColumn {
+        from: String,
+        to: String,
+    },
+}
+
+impl Alteration {
+    /// Calcula el nuevo encabezado de la tabla luego de aplicar la modificación.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la columna a agregar ya existe, si la columna a eliminar o renombrar
+    /// no existe, si el nuevo nombre ya está en uso, o si se intenta eliminar la única columna.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::alteration::Alteration;
+    /// let header = vec!["id".to_string(), "nombre".to_string()];
+    /// let rename = Alteration::RenameColumn {
+    ///     from: "nombre".to_string(),
+    ///     to: "apellido".to_string(),
+    /// };
+    /// assert_eq!(rename.apply_to_header(&header).unwrap(), vec!["id", "apellido"]);
+    /// ```
+    pub fn apply_to_header(&self, header: &[String]) -> Result<Vec<String>, Errored> {
+        let mut new_header = header.to_vec();
+        match self {
+            Alteration::AddColumn { column, .. } => {
+                expect_missing(header, column)?;
+                new_header.push(column.to_string());
+            }
+            Alteration::DropColumn(column) => {
+                let index = expect_existing(header, column)?;
+                if header.len() == 1 {
+                    errored!(
+                        Table,
+                        "cannot drop {}, the only column of the table.",
+                        column
+                    )
+                }
+                new_header.remove(index);
+            }
+            Alteration::RenameColumn { from, to } => {
+                let index = expect_existing(header, from)?;
+                expect_missing(header, to)?;
+                new_header[index] = to.to_string();
+            }
+        }
+        Ok(new_header)
+    }
+
+    /// Aplica la modificación sobre los campos de una fila de la tabla, ya escritos en formato
+    /// CSV (ver `CsvField::to_csv`), de manera que los valores existentes no se modifican.
+    ///
+    /// # Parámetros
+    ///
+    /// - `header`: El encabezado de la tabla antes de la modificación.
+    /// - `fields`: Los campos de la fila, en el orden del encabezado.
+    /// - `null_marker`: La representación de `NULL` en la tabla, usada si la columna
+    ///   agregada no tiene valor por defecto.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la fila no tiene la misma cantidad de valores que el encabezado.
+    pub fn apply_to_fields(
+        &self,
+        header: &[String],
+        mut fields: Vec<String>,
+        null_marker: &str,
+    ) -> Result<Vec<String>, Errored> {
+        if fields.len() != header.len() {
+            errored!(
+                Table,
+                "row has ({}) fields but table needs ({}).",
+                fields.len(),
+                header.len()
+            )
+        }
+        match self {
+            Alteration::AddColumn { default, .. } => match default {
+                Some(default) => fields.push(escape_csv_value(default, null_marker)),
+                None => fields.push(null_marker.to_string()),
+            },
+            Alteration::DropColumn(column) => {
+                let index = expect_existing(header, column)?;
+                fields.remove(index);
+            }
+            Alteration::RenameColumn { .. } => {}
+        }
+        Ok(fields)
+    }
+
+    /// Aplica la modificación sobre el esquema de la tabla.
+    ///
+    /// Al agregar una columna sin tipo, la misma no se declara en el esquema y su tipo se
+    /// infiere a partir de cada valor, como en las tablas sin esquema.
+    ///
+    /// # Retorna
+    ///
+    /// El esquema modificado, o `None` si la tabla no tenía esquema y la modificación no lo requiere.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el valor por defecto no es válido para el tipo de la nueva columna.
+    /// Un valor por defecto `NULL` es válido para cualquier tipo.
+    pub fn apply_to_schema(&self, schema: Option<&Schema>) -> Result<Option<Schema>, Errored> {
+        match self {
+            Alteration::AddColumn {
+                column,
+                kind: Some(kind),
+                default,
+            } => {
+                let mut schema = schema.cloned().unwrap_or_default();
+                schema.add_column(column.to_string(), kind.clone())?;
+                if let Some(default) = default {
+                    schema.parse_value(column, default)?;
+                }
+                Ok(Some(schema))
+            }
+            Alteration::AddColumn { .. } => Ok(schema.cloned()),
+            Alteration::DropColumn(column) => Ok(schema.map(|s| s.without_column(column))),
+            Alteration::RenameColumn { from, to } => {
+                Ok(schema.map(|s| s.with_renamed_column(from, to)))
+            }
+        }
+    }
+}
+
+/// Verifica que la columna exista en el encabezado y devuelve su posición.
+fn expect_existing(header: &[String], column: &str) -> Result<usize, Errored> {
+    match header.iter().position(|c| c == column) {
+        Some(index) => Ok(index),
+        None => errored!(Column, "column {} does not exist in table.", column),
+    }
+}
+
+/// Verifica que la columna no exista en el encabezado.
+fn expect_missing(header: &[String], column: &str) -> Result<(), Errored> {
+    if header.iter().any(|c| c == column) {
+        errored!(Column, "column {} already exists in table.", column)
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header() -> Vec<String> {
+        vec!["id".to_string(), "producto".to_string()]
+    }
+
+    fn fields(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_add_column() {
+        let add = Alteration::AddColumn {
+            column: "cantidad".to_string(),
+            kind: Some(ColumnType::Int),
+            default: Some("1".to_string()),
+        };
+        assert_eq!(
+            add.apply_to_header(&header()).unwrap(),
+            vec!["id", "producto", "cantidad"]
+        );
+        assert_eq!(
+            add.apply_to_fields(&header(), fields(&["1", "Laptop"]), "")
+                .unwrap(),
+            vec!["1", "Laptop", "1"]
+        );
+        let schema = add.apply_to_schema(None).unwrap().unwrap();
+        assert_eq!(schema.column_type("cantidad"), Some(&ColumnType::Int));
+    }
+
+    #[test]
+    fn test_add_column_without_default() {
+        let add = Alteration::AddColumn {
+            column: "cantidad".to_string(),
+            kind: Some(ColumnType::Int),
+            default: None,
+        };
+        assert_eq!(
+            add.apply_to_fields(&header(), fields(&["1", "Laptop"]), "\\N")
+                .unwrap(),
+            vec!["1", "Laptop", "\\N"]
+        );
+        assert!(add.apply_to_schema(None).is_ok());
+    }
+
+    #[test]
+    fn test_add_invalid_column() {
+        let existing = Alteration::AddColumn {
+            column: "id".to_string(),
+            kind: None,
+            default: None,
+        };
+        assert!(existing.apply_to_header(&header()).is_err());
+
+        let invalid_default = Alteration::AddColumn {
+            column: "cantidad".to_string(),
+            kind: Some(ColumnType::Int),
+            default: Some("uno".to_string()),
+        };
+        assert!(invalid_default.apply_to_schema(None).is_err());
+    }
+
+    #[test]
+    fn test_drop_column() {
+        let drop = Alteration::DropColumn("id".to_string());
+        assert_eq!(drop.apply_to_header(&header()).unwrap(), vec!["producto"]);
+        assert_eq!(
+            drop.apply_to_fields(&header(), fields(&["1", "Laptop"]), "")
+                .unwrap(),
+            vec!["Laptop"]
+        );
+        assert!(drop.apply_to_fields(&header(), fields(&["1"]), "").is_err());
+
+        let only_column = vec!["id".to_string()];
+        assert!(drop.apply_to_header(&only_column).is_err());
+        let missing = Alteration::DropColumn("email".to_string());
+        assert!(missing.apply_to_header(&header()).is_err());
+    }
+
+    #[test]
+    fn test_rename_column() {
+        let rename = Alteration::RenameColumn {
+            from: "producto".to_string(),
+            to: "id".to_string(),
+        };
+        assert!(rename.apply_to_header(&header()).is_err());
+
+        let schema = Schema::parse("producto TEXT").unwrap();
+        let rename = Alteration::RenameColumn {
+            from: "producto".to_string(),
+            to: "articulo".to_string(),
+        };
+        let renamed = rename.apply_to_schema(Some(&schema)).unwrap().unwrap();
+        assert_eq!(renamed.column_type("articulo"), Some(&ColumnType::Text));
+        assert_eq!(renamed.column_type("producto"), None);
+    }
+}
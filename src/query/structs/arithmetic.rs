@@ -0,0 +1,133 @@
+use crate::errored;
+use crate::query::structs::expression::ExpressionResult::{Float, Int};
+use crate::query::structs::expression::{ExpressionOperator, ExpressionResult};
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::{Default, Syntax};
+
+/// Calculadora de expresiones que resuelve las operaciones aritméticas entre valores.
+/// Es utilizada con los valores "hoja" de las expresiones SQL, como en `cantidad + 1`.
+pub struct ExpressionCalculator;
+
+impl ExpressionCalculator {
+    /// Calcula el resultado de operar dos enteros con el operador especificado.
+    ///
+    /// # Parámetros
+    /// - `l`: El operando izquierdo.
+    /// - `r`: El operando derecho.
+    /// - `op`: El operador aritmético a utilizar.
+    ///
+    /// # Retorno
+    /// Retorna un `Result` que contiene un `ExpressionResult` con el resultado de la operación,
+    /// o un error `Errored` si el operador no es aritmético, si se divide por cero o si el
+    /// resultado excede el rango de los enteros.
+    pub fn calculate_ints(
+        l: i64,
+        r: i64,
+        op: &ExpressionOperator,
+    ) -> Result<ExpressionResult, Errored> {
+        let result = match op {
+            ExpressionOperator::Add => l.checked_add(r),
+            ExpressionOperator::Subtract => l.checked_sub(r),
+            ExpressionOperator::Multiply => l.checked_mul(r),
+            ExpressionOperator::Divide | ExpressionOperator::Modulo if r == 0 => {
+                errored!(Default, "division by zero: {} {} {}", l, op, r)
+            }
+            ExpressionOperator::Divide => l.checked_div(r),
+            ExpressionOperator::Modulo => l.checked_rem(r),
+            _ => errored!(Syntax, "invalid arithmetic operation for ints: {:?}", op),
+        };
+        match result {
+            Some(value) => Ok(Int(value)),
+            None => errored!(Default, "integer overflow: {} {} {}", l, op, r),
+        }
+    }
+
+    /// Calcula el resultado de operar dos flotantes con el operador especificado.
+    ///
+    /// Es utilizado cuando alguno de los operandos tiene parte decimal, convirtiendo el otro
+    /// operando a flotante si es un entero.
+    ///
+    /// # Parámetros
+    /// - `l`: El operando izquierdo.
+    /// - `r`: El operando derecho.
+    /// - `op`: El operador aritmético a utilizar.
+    ///
+    /// # Retorno
+    /// Retorna un `Result` que contiene un `ExpressionResult` con el resultado de la operación,
+    /// o un error `Errored` si el operador no es aritmético, si se divide por cero o si el
+    /// resultado no es un número finito.
+    pub fn calculate_floats(
+        l: f64,
+        r: f64,
+        op: &ExpressionOperator,
+    ) -> Result<ExpressionResult, Errored> {
+        let result = match op {
+            ExpressionOperator::Add => l + r,
+            ExpressionOperator::Subtract => l - r,
+            ExpressionOperator::Multiply => l * r,
+            ExpressionOperator::Divide | ExpressionOperator::Modulo if r == 0.0 => {
+                errored!(Default, "division by zero: {} {} {}", l, op, r)
+            }
+            ExpressionOperator::Divide => l / r,
+            ExpressionOperator::Modulo => l % r,
+            _ => errored!(Syntax, "invalid arithmetic operation for floats: {:?}", op),
+        };
+        if !result.is_finite() {
+            errored!(Default, "float overflow: {} {} {}", l, op, r)
+        }
+        Ok(Float(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::structs::expression::ExpressionOperator::*;
+
+    #[test]
+    fn test_calculate_ints() {
+        assert_eq!(
+            ExpressionCalculator::calculate_ints(5, 3, &Add).unwrap(),
+            Int(8)
+        );
+        assert_eq!(
+            ExpressionCalculator::calculate_ints(5, 3, &Subtract).unwrap(),
+            Int(2)
+        );
+        assert_eq!(
+            ExpressionCalculator::calculate_ints(5, 3, &Multiply).unwrap(),
+            Int(15)
+        );
+        assert_eq!(
+            ExpressionCalculator::calculate_ints(7, 2, &Divide).unwrap(),
+            Int(3)
+        );
+        assert_eq!(
+            ExpressionCalculator::calculate_ints(7, 2, &Modulo).unwrap(),
+            Int(1)
+        );
+    }
+
+    #[test]
+    fn test_calculate_ints_invalid() {
+        assert!(ExpressionCalculator::calculate_ints(5, 0, &Divide).is_err());
+        assert!(ExpressionCalculator::calculate_ints(5, 0, &Modulo).is_err());
+        assert!(ExpressionCalculator::calculate_ints(i64::MAX, 1, &Add).is_err());
+        assert!(ExpressionCalculator::calculate_ints(5, 3, &Equals).is_err());
+    }
+
+    #[test]
+    fn test_calculate_floats() {
+        assert_eq!(
+            ExpressionCalculator::calculate_floats(19.99, 2.0, &Multiply).unwrap(),
+            Float(39.98)
+        );
+        assert_eq!(
+            ExpressionCalculator::calculate_floats(7.0, 2.0, &Divide).unwrap(),
+            Float(3.5)
+        );
+        assert!(ExpressionCalculator::calculate_floats(5.0, 0.0, &Divide).is_err());
+        assert!(ExpressionCalculator::calculate_floats(f64::MAX, f64::MAX, &Add).is_err());
+        assert!(ExpressionCalculator::calculate_floats(5.0, 3.0, &Equals).is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::query::structs::query::Query;
+use std::fmt::{Debug, Formatter};
+
+/// Estructura que representa una tabla virtual definida con `WITH nombre AS (SELECT ...)`.
+///
+/// La tabla virtual se materializa antes de ejecutar la consulta principal, y desde ese
+/// momento puede usarse por su nombre como cualquier tabla del directorio, tanto en la
+/// consulta principal como en sus uniones, sub-consultas y en las tablas virtuales siguientes.
+///
+/// # Campos
+///
+/// * `name` - El nombre con el que se refiere a la tabla virtual.
+/// * `query` - La consulta SELECT cuyo resultado contiene la tabla virtual.
+#[derive(PartialEq)]
+pub struct CommonTable {
+    pub name: String,
+    pub query: Query,
+}
+
+impl Debug for CommonTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({} AS {})", &self.name, &self.query.table)
+    }
+}
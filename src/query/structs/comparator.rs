@@ -1,5 +1,8 @@
 use crate::errored;
-use crate::query::structs::expression::ExpressionResult::{Bool, Int, Str};
+use crate::query::structs::date::as_seconds;
+use crate::query::structs::expression::ExpressionResult::{
+    Bool, Date, Float, Int, Null, Str, Timestamp,
+};
 use crate::query::structs::expression::{ExpressionOperator, ExpressionResult};
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Syntax;
@@ -35,8 +38,41 @@ impl ExpressionComparator {
         }
     }
 
+    /// Compara dos flotantes utilizando el operador especificado.
+    ///
+    /// Es utilizado cuando alguno de los valores tiene parte decimal, convirtiendo el otro
+    /// valor a flotante si es un entero.
+    ///
+    /// # Parámetros
+    /// - `l`: El primer flotante a comparar.
+    /// - `r`: El segundo flotante a comparar.
+    /// - `op`: El operador de comparación a utilizar.
+    ///
+    /// # Retorno
+    /// Retorna un `Result` que contiene un `ExpressionResult` con el resultado de la comparación,
+    /// o un error `Errored` si el operador no es válido para flotantes.
+    pub fn compare_floats(
+        l: f64,
+        r: f64,
+        op: &ExpressionOperator,
+    ) -> Result<ExpressionResult, Errored> {
+        match op {
+            ExpressionOperator::Equals => Ok(Bool(l == r)),
+            ExpressionOperator::NotEquals => Ok(Bool(l != r)),
+            ExpressionOperator::GreaterThan => Ok(Bool(l > r)),
+            ExpressionOperator::LessThan => Ok(Bool(l < r)),
+            ExpressionOperator::GreaterOrEqual => Ok(Bool(l >= r)),
+            ExpressionOperator::LessOrEqual => Ok(Bool(l <= r)),
+            _ => errored!(Syntax, "invalid comparison for floats: {:?}", op),
+        }
+    }
+
     /// Compara dos cadenas de texto utilizando el operador especificado.
     ///
+    /// Además de las comparaciones simples, admite la comparación contra un patrón con
+    /// `LIKE` (o `ILIKE`, que no distingue mayúsculas), donde la cadena derecha es el patrón
+    /// (ver `Pattern`).
+    ///
     /// # Parámetros
     /// - `l`: La primera cadena de texto a comparar.
     /// - `r`: La segunda cadena de texto a comparar.
@@ -57,35 +93,121 @@ impl ExpressionComparator {
             ExpressionOperator::LessThan => Ok(Bool(l < r)),
             ExpressionOperator::GreaterOrEqual => Ok(Bool(l >= r)),
             ExpressionOperator::LessOrEqual => Ok(Bool(l <= r)),
+            ExpressionOperator::Like(escape) | ExpressionOperator::NotLike(escape) => {
+                ExpressionComparator::compare_pattern(l, &Pattern::compile(r, *escape, false)?, op)
+            }
+            ExpressionOperator::ILike(escape) | ExpressionOperator::NotILike(escape) => {
+                ExpressionComparator::compare_pattern(l, &Pattern::compile(r, *escape, true)?, op)
+            }
             _ => errored!(Syntax, "invalid comparison for str: {:?}", op),
         }
     }
 
-    /// Compara dos valores booleanos utilizando el operador especificado.
+    /// Compara una cadena de texto contra un patrón ya interpretado.
+    ///
+    /// # Parámetros
+    /// - `text`: La cadena de texto a comparar.
+    /// - `pattern`: El patrón contra el que se compara.
+    /// - `op`: El operador de patrones a utilizar (`LIKE`, `ILIKE` o sus negaciones).
+    ///
+    /// # Retorno
+    /// Retorna un `Result` que contiene un `ExpressionResult` con el resultado de la comparación,
+    /// o un error `Errored` si el operador no es de patrones.
+    pub fn compare_pattern(
+        text: &str,
+        pattern: &Pattern,
+        op: &ExpressionOperator,
+    ) -> Result<ExpressionResult, Errored> {
+        match op {
+            ExpressionOperator::Like(_) | ExpressionOperator::ILike(_) => {
+                Ok(Bool(pattern.matches(text)))
+            }
+            ExpressionOperator::NotLike(_) | ExpressionOperator::NotILike(_) => {
+                Ok(Bool(!pattern.matches(text)))
+            }
+            _ => errored!(Syntax, "invalid comparison for patterns: {:?}", op),
+        }
+    }
+
+    /// Compara dos fechas utilizando el operador especificado.
+    ///
+    /// Alguno de los valores debe ser una fecha (`Date` o `Timestamp`), y el otro puede ser
+    /// una fecha o un texto con formato de fecha, que se interpreta como tal. Una fecha sin
+    /// hora se compara como el comienzo de su día.
+    ///
+    /// Los patrones (`LIKE`, `ILIKE`) se comparan contra la fecha escrita en formato ISO, como
+    /// se haría con un texto.
     ///
     /// # Parámetros
-    /// - `l`: El primer valor booleano a comparar.
-    /// - `r`: El segundo valor booleano a comparar.
+    /// - `l`: La primera fecha a comparar.
+    /// - `r`: La segunda fecha a comparar.
     /// - `op`: El operador de comparación a utilizar.
     ///
     /// # Retorno
     /// Retorna un `Result` que contiene un `ExpressionResult` con el resultado de la comparación,
-    /// o un error `Errored` si el operador no es válido para valores booleanos.
-    pub fn compare_bools(
-        l: bool,
-        r: bool,
+    /// o un error `Errored` si alguno de los valores no es una fecha o el operador no es válido.
+    pub fn compare_dates(
+        l: &ExpressionResult,
+        r: &ExpressionResult,
         op: &ExpressionOperator,
     ) -> Result<ExpressionResult, Errored> {
         match op {
-            ExpressionOperator::And => Ok(Bool(l && r)),
-            ExpressionOperator::Or => Ok(Bool(l || r)),
-            ExpressionOperator::Not => Ok(Bool(!l)),
-            _ => errored!(Syntax, "invalid comparison for bool: {:?}", op),
+            ExpressionOperator::Like(_)
+            | ExpressionOperator::NotLike(_)
+            | ExpressionOperator::ILike(_)
+            | ExpressionOperator::NotILike(_) => {
+                ExpressionComparator::compare_str(&l.to_string(), &r.to_string(), op)
+            }
+            _ => ExpressionComparator::compare_ints(as_seconds(l)?, as_seconds(r)?, op)
+                .map_err(|_| Syntax(format!("invalid comparison for dates: {:?}", op))),
         }
     }
 
+    /// Compara dos valores lógicos utilizando el operador especificado.
+    ///
+    /// Sigue la lógica de tres valores de SQL, donde `None` representa un valor desconocido
+    /// (el resultado de una comparación con `NULL`):
+    ///
+    /// - `AND` es falso si algún lado es falso, verdadero si ambos lo son, y desconocido si no.
+    /// - `OR` es verdadero si algún lado es verdadero, falso si ambos lo son, y desconocido si no.
+    /// - `NOT` de un valor desconocido sigue siendo desconocido.
+    ///
+    /// # Parámetros
+    /// - `l`: El primer valor lógico a comparar.
+    /// - `r`: El segundo valor lógico a comparar.
+    /// - `op`: El operador de comparación a utilizar.
+    ///
+    /// # Retorno
+    /// Retorna un `Result` que contiene un `ExpressionResult` con el resultado de la comparación
+    /// (`Null` si es desconocido), o un error `Errored` si el operador no es válido para valores booleanos.
+    pub fn compare_bools(
+        l: Option<bool>,
+        r: Option<bool>,
+        op: &ExpressionOperator,
+    ) -> Result<ExpressionResult, Errored> {
+        let result = match op {
+            ExpressionOperator::And => match (l, r) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            ExpressionOperator::Or => match (l, r) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            ExpressionOperator::Not => l.map(|b| !b),
+            _ => errored!(Syntax, "invalid comparison for bool: {:?}", op),
+        };
+        Ok(result.map_or(Null, Bool))
+    }
+
     /// Compara dos resultados de expresiones para determinar su orden relativo.
     ///
+    /// Los valores `Null` se consideran menores a cualquier otro valor, los valores
+    /// numéricos se comparan por su valor aunque uno sea entero y el otro flotante, y las
+    /// fechas se comparan cronológicamente, tengan hora o no.
+    ///
     /// # Parámetros
     /// - `this`: El primer resultado de expresión a comparar.
     /// - `other`: El segundo resultado de expresión a comparar.
@@ -99,8 +221,18 @@ impl ExpressionComparator {
     ) -> Result<std::cmp::Ordering, Errored> {
         match (this, other) {
             (Int(a), Int(b)) => Ok(a.cmp(b)),
+            (Float(_), Int(_) | Float(_)) | (Int(_), Float(_)) => {
+                Ok(this.as_float().total_cmp(&other.as_float()))
+            }
             (Str(a), Str(b)) => Ok(a.cmp(b)),
             (Bool(a), Bool(b)) => Ok(a.cmp(b)),
+            (Date(a), Date(b)) => Ok(a.cmp(b)),
+            (Date(_) | Timestamp(_), Date(_) | Timestamp(_)) => {
+                Ok(as_seconds(this)?.cmp(&as_seconds(other)?))
+            }
+            (Null, Null) => Ok(std::cmp::Ordering::Equal),
+            (Null, _) => Ok(std::cmp::Ordering::Less),
+            (_, Null) => Ok(std::cmp::Ordering::Greater),
             _ => errored!(
                 Syntax,
                 "Cannot compare different types: {:?} and {:?}",
@@ -111,12 +243,143 @@ impl ExpressionComparator {
     }
 }
 
+/// Elemento de un patrón de `LIKE` ya interpretado.
+#[derive(Debug, Clone, PartialEq)]
+enum PatternItem {
+    /// Un caracter que debe aparecer tal cual.
+    Literal(char),
+    /// El comodín `_`, que coincide con exactamente un caracter.
+    AnyChar,
+    /// El comodín `%`, que coincide con cualquier secuencia de caracteres, incluso vacía.
+    AnySequence,
+}
+
+/// Patrón de `LIKE` (o `ILIKE`) ya interpretado, listo para compararse contra varios textos.
+///
+/// En el patrón, `%` representa cualquier secuencia de caracteres y `_` exactamente un caracter.
+/// Si se indica un caracter de escape (`ESCAPE`), el caracter que le sigue se toma de forma
+/// literal, lo que permite buscar `%`, `_` o el propio caracter de escape.
+///
+/// Interpretar el patrón una única vez evita repetir ese trabajo en cada fila cuando el patrón
+/// es constante (ver `ExpressionNode::like`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    items: Vec<PatternItem>,
+    case_insensitive: bool,
+}
+
+impl Pattern {
+    /// Interpreta un patrón de `LIKE`, resolviendo los comodines y el caracter de escape.
+    ///
+    /// # Parámetros
+    /// - `pattern`: El texto del patrón.
+    /// - `escape`: El caracter de escape del patrón, si tiene.
+    /// - `case_insensitive`: Si la comparación no distingue mayúsculas (`ILIKE`).
+    ///
+    /// # Errores
+    /// Retorna un error si el patrón termina con el caracter de escape.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::comparator::Pattern;
+    ///
+    /// let pattern = Pattern::compile("50!%", Some('!'), false).unwrap();
+    /// assert!(pattern.matches("50%"));
+    /// assert!(!pattern.matches("500"));
+    /// ```
+    pub fn compile(
+        pattern: &str,
+        escape: Option<char>,
+        case_insensitive: bool,
+    ) -> Result<Pattern, Errored> {
+        let (pattern, escape) = match case_insensitive {
+            true => (
+                pattern.to_lowercase(),
+                escape.map(|e| e.to_lowercase().next().unwrap_or(e)),
+            ),
+            false => (pattern.to_string(), escape),
+        };
+        let mut items = vec![];
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let item = match c {
+                c if Some(c) == escape => match chars.next() {
+                    Some(escaped) => PatternItem::Literal(escaped),
+                    None => errored!(
+                        Syntax,
+                        "LIKE pattern must not end with the escape character: {}",
+                        pattern
+                    ),
+                },
+                '%' => PatternItem::AnySequence,
+                '_' => PatternItem::AnyChar,
+                c => PatternItem::Literal(c),
+            };
+            items.push(item);
+        }
+        Ok(Pattern {
+            items,
+            case_insensitive,
+        })
+    }
+
+    /// Determina si un texto coincide con el patrón.
+    pub fn matches(&self, text: &str) -> bool {
+        let text: Vec<char> = match self.case_insensitive {
+            true => text.to_lowercase().chars().collect(),
+            false => text.chars().collect(),
+        };
+        let pattern = &self.items;
+        let (mut t, mut p) = (0, 0);
+        let mut backtrack: Option<(usize, usize)> = None;
+        while t < text.len() {
+            match pattern.get(p) {
+                Some(PatternItem::AnySequence) => {
+                    backtrack = Some((p, t));
+                    p += 1;
+                    continue;
+                }
+                Some(PatternItem::AnyChar) => {
+                    t += 1;
+                    p += 1;
+                    continue;
+                }
+                Some(PatternItem::Literal(c)) if *c == text[t] => {
+                    t += 1;
+                    p += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            match backtrack {
+                Some((star, start)) => {
+                    backtrack = Some((star, start + 1));
+                    p = star + 1;
+                    t = start + 1;
+                }
+                None => return false,
+            }
+        }
+        pattern[p..].iter().all(|i| *i == PatternItem::AnySequence)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::query::structs::expression::ExpressionOperator::*;
     use std::cmp::Ordering::*;
 
+    fn matches_pattern(
+        text: &str,
+        pattern: &str,
+        escape: Option<char>,
+        case_insensitive: bool,
+    ) -> Result<bool, Errored> {
+        Ok(Pattern::compile(pattern, escape, case_insensitive)?.matches(text))
+    }
+
     #[test]
     fn test_compare_ints() {
         assert_eq!(
@@ -145,6 +408,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_matches_pattern() {
+        assert!(
+            matches_pattern("john.doe@example.com", "%@example.com", Option::None, false).unwrap()
+        );
+        assert!(matches_pattern("Mouse", "M_use", Option::None, false).unwrap());
+        assert!(matches_pattern("Mouse", "%", Option::None, false).unwrap());
+        assert!(matches_pattern("", "%%", Option::None, false).unwrap());
+        assert!(matches_pattern("aXbXc", "a%b%c", Option::None, false).unwrap());
+        assert!(!matches_pattern("Mouse", "m%", Option::None, false).unwrap());
+        assert!(!matches_pattern("Mouse", "M_", Option::None, false).unwrap());
+        assert!(!matches_pattern("abc", "a%d", Option::None, false).unwrap());
+    }
+
+    #[test]
+    fn test_matches_pattern_case_insensitive_and_escape() {
+        assert!(matches_pattern("MOUSE", "m%e", Option::None, true).unwrap());
+        assert!(matches_pattern("50%", "50!%", Some('!'), false).unwrap());
+        assert!(!matches_pattern("500", "50!%", Some('!'), false).unwrap());
+        assert!(matches_pattern("A_B", "a!_b", Some('!'), true).unwrap());
+        assert!(matches_pattern("a!b", "a!!b", Some('!'), false).unwrap());
+        assert!(matches_pattern("50%", "50!", Some('!'), false).is_err());
+    }
+
+    #[test]
+    fn test_compare_str_like() {
+        assert_eq!(
+            ExpressionComparator::compare_str("Laptop", "lap%", &ILike(Option::None)).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_str("Laptop", "lap%", &Like(Option::None)).unwrap(),
+            Bool(false)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_str("Laptop", "lap%", &NotLike(Option::None)).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_str("Laptop", "lap%", &NotILike(Option::None)).unwrap(),
+            Bool(false)
+        );
+    }
+
     #[test]
     fn test_compare_ints_invalid() {
         assert!(ExpressionComparator::compare_ints(5, 5, &And).is_err());
@@ -186,22 +493,46 @@ mod tests {
     #[test]
     fn test_compare_bools() {
         assert_eq!(
-            ExpressionComparator::compare_bools(true, false, &And).unwrap(),
+            ExpressionComparator::compare_bools(Some(true), Some(false), &And).unwrap(),
             Bool(false)
         );
         assert_eq!(
-            ExpressionComparator::compare_bools(true, false, &Or).unwrap(),
+            ExpressionComparator::compare_bools(Some(true), Some(false), &Or).unwrap(),
             Bool(true)
         );
         assert_eq!(
-            ExpressionComparator::compare_bools(true, false, &Not).unwrap(),
+            ExpressionComparator::compare_bools(Some(true), Some(false), &Not).unwrap(),
             Bool(false)
         );
     }
 
+    #[test]
+    fn test_compare_bools_unknown() {
+        assert_eq!(
+            ExpressionComparator::compare_bools(Option::None, Some(false), &And).unwrap(),
+            Bool(false)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_bools(Option::None, Some(true), &And).unwrap(),
+            Null
+        );
+        assert_eq!(
+            ExpressionComparator::compare_bools(Some(true), Option::None, &Or).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_bools(Some(false), Option::None, &Or).unwrap(),
+            Null
+        );
+        assert_eq!(
+            ExpressionComparator::compare_bools(Option::None, Some(true), &Not).unwrap(),
+            Null
+        );
+    }
+
     #[test]
     fn test_compare_bools_invalid() {
-        assert!(ExpressionComparator::compare_bools(true, false, &Equals).is_err());
+        assert!(ExpressionComparator::compare_bools(Some(true), Some(false), &Equals).is_err());
     }
 
     #[test]
@@ -220,6 +551,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_compare_floats() {
+        assert_eq!(
+            ExpressionComparator::compare_floats(19.99, 20.0, &LessThan).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_floats(2.0, 2.0, &Equals).unwrap(),
+            Bool(true)
+        );
+        assert!(ExpressionComparator::compare_floats(2.0, 2.0, &And).is_err());
+    }
+
+    #[test]
+    fn test_compare_ordering_mixed_numbers() {
+        assert_eq!(
+            ExpressionComparator::compare_ordering(&Float(9.99), &Int(25)).unwrap(),
+            Less
+        );
+        assert_eq!(
+            ExpressionComparator::compare_ordering(&Int(3), &Float(2.5)).unwrap(),
+            Greater
+        );
+        assert_eq!(
+            ExpressionComparator::compare_ordering(&Int(2), &Float(2.0)).unwrap(),
+            Equal
+        );
+    }
+
     #[test]
     fn test_compare_ordering_strs() {
         assert_eq!(
@@ -243,4 +603,32 @@ mod tests {
     fn test_compare_ordering_invalid() {
         assert!(ExpressionComparator::compare_ordering(&Int(5), &Str("a".to_string())).is_err());
     }
+
+    #[test]
+    fn test_compare_dates() {
+        let day = Date(19_753);
+        let text = Str("2024-01-31".to_string());
+        let noon = Timestamp(19_753 * 86_400 + 43_200);
+        assert_eq!(
+            ExpressionComparator::compare_dates(&day, &text, &Equals).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            ExpressionComparator::compare_dates(&day, &noon, &LessThan).unwrap(),
+            Bool(true)
+        );
+        let pattern = Str("2024-01-%".to_string());
+        assert_eq!(
+            ExpressionComparator::compare_dates(&day, &pattern, &Like(Option::None)).unwrap(),
+            Bool(true)
+        );
+        assert!(ExpressionComparator::compare_dates(&day, &Int(19_753), &Equals).is_err());
+        assert!(
+            ExpressionComparator::compare_dates(&day, &Str("ayer".to_string()), &Equals).is_err()
+        );
+        assert_eq!(
+            ExpressionComparator::compare_ordering(&noon, &Date(19_754)).unwrap(),
+            Less
+        );
+    }
 }
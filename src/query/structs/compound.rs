@@ -0,0 +1,211 @@
+use crate::query::structs::compound::CompoundKind::{Except, Intersect, Union, UnionAll};
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::{Float, Int};
+use crate::query::structs::query::Query;
+use std::collections::HashSet;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Valores de una fila resultante, en el orden de las columnas proyectadas.
+type Values = Vec<ExpressionResult>;
+
+/// Estructura que representa una consulta SELECT combinada con el resultado de la consulta
+/// que la precede mediante una operación de conjuntos (`UNION`, `INTERSECT`, `EXCEPT`).
+///
+/// # Campos
+///
+/// * `kind` - La operación de conjuntos con la que se combinan los resultados.
+/// * `query` - La consulta SELECT cuyos resultados se combinan.
+#[derive(PartialEq)]
+pub struct Compound {
+    pub kind: CompoundKind,
+    pub query: Query,
+}
+
+/// Enum que representa las operaciones de conjuntos posibles entre consultas.
+///
+/// - `Union`: Las filas de ambas consultas, descartando las repetidas.
+/// - `UnionAll`: Las filas de ambas consultas, conservando las repetidas.
+/// - `Intersect`: Las filas distintas de la izquierda que también están en la derecha.
+/// - `Except`: Las filas distintas de la izquierda que no están en la derecha.
+#[derive(Debug, PartialEq)]
+pub enum CompoundKind {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+impl CompoundKind {
+    /// Obtiene la operación de conjuntos correspondiente a una palabra clave.
+    ///
+    /// # Retorna
+    ///
+    /// La operación, o `None` si la palabra clave no es una operación de conjuntos.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "UNION" => Some(Union),
+            "UNION ALL" => Some(UnionAll),
+            "INTERSECT" => Some(Intersect),
+            "EXCEPT" => Some(Except),
+            _ => None,
+        }
+    }
+
+    /// Combina las filas resultantes de dos consultas según la operación.
+    ///
+    /// Dos filas se consideran iguales si todos sus valores lo son, incluyendo los `NULL`.
+    /// Se conserva el orden en que aparecen las filas, primero las de la izquierda.
+    ///
+    /// # Parámetros
+    ///
+    /// - `left`: Las filas acumuladas hasta el momento.
+    /// - `right`: Las filas de la consulta que se combina.
+    ///
+    /// # Retorna
+    ///
+    /// Las filas resultantes de la operación.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::compound::CompoundKind;
+    /// use rustic_sql::query::structs::expression::ExpressionResult::Int;
+    /// let left = vec![vec![Int(1)], vec![Int(2)], vec![Int(2)]];
+    /// let right = vec![vec![Int(2)], vec![Int(3)]];
+    /// let rows = CompoundKind::Union.combine(left, right);
+    /// assert_eq!(rows, vec![vec![Int(1)], vec![Int(2)], vec![Int(3)]]);
+    /// ```
+    pub fn combine(&self, mut left: Vec<Values>, right: Vec<Values>) -> Vec<Values> {
+        match self {
+            UnionAll => {
+                left.extend(right);
+                left
+            }
+            Union => distinct(left.into_iter().chain(right)),
+            Intersect => {
+                let right: HashSet<Values> = right.iter().map(row_key).collect();
+                distinct(
+                    left.into_iter()
+                        .filter(|values| right.contains(&row_key(values))),
+                )
+            }
+            Except => {
+                let right: HashSet<Values> = right.iter().map(row_key).collect();
+                distinct(
+                    left.into_iter()
+                        .filter(|values| !right.contains(&row_key(values))),
+                )
+            }
+        }
+    }
+}
+
+/// Descarta las filas repetidas, conservando la primera aparición de cada una.
+fn distinct(rows: impl Iterator<Item = Values>) -> Vec<Values> {
+    let mut seen = HashSet::new();
+    rows.filter(|values| seen.insert(row_key(values))).collect()
+}
+
+/// Obtiene la clave con la que se compara una fila al combinar resultados.
+///
+/// Los flotantes sin parte decimal se convierten en enteros, ya que al compararse un entero
+/// con un flotante se consideran iguales si tienen el mismo valor numérico (`2 = 2.0`).
+fn row_key(values: &Values) -> Values {
+    values
+        .iter()
+        .map(|value| match value {
+            Float(x) if x.fract() == 0.0 && x.abs() < i64::MAX as f64 => Int(*x as i64),
+            value => value.clone(),
+        })
+        .collect()
+}
+
+impl Display for CompoundKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let keyword = match self {
+            Union => "UNION",
+            UnionAll => "UNION ALL",
+            Intersect => "INTERSECT",
+            Except => "EXCEPT",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+impl Debug for Compound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}:{})", &self.kind, &self.query.table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::structs::expression::ExpressionResult::{Null, Str};
+
+    fn rows() -> (Vec<Values>, Vec<Values>) {
+        let left = vec![
+            vec![Int(1), Str("a".to_string())],
+            vec![Int(2), Null],
+            vec![Int(1), Str("a".to_string())],
+            vec![Int(3), Str("c".to_string())],
+        ];
+        let right = vec![vec![Int(2), Null], vec![Int(4), Str("d".to_string())]];
+        (left, right)
+    }
+
+    #[test]
+    fn test_union_and_union_all() {
+        let (left, right) = rows();
+        assert_eq!(UnionAll.combine(left, right).len(), 6);
+
+        let (left, right) = rows();
+        assert_eq!(
+            Union.combine(left, right),
+            vec![
+                vec![Int(1), Str("a".to_string())],
+                vec![Int(2), Null],
+                vec![Int(3), Str("c".to_string())],
+                vec![Int(4), Str("d".to_string())],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_intersect_and_except() {
+        let (left, right) = rows();
+        assert_eq!(Intersect.combine(left, right), vec![vec![Int(2), Null]]);
+
+        let (left, right) = rows();
+        assert_eq!(
+            Except.combine(left, right),
+            vec![
+                vec![Int(1), Str("a".to_string())],
+                vec![Int(3), Str("c".to_string())],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mixed_numerics_are_equal() {
+        let left = vec![vec![Float(2.0)], vec![Int(2)], vec![Float(2.5)]];
+        let right = vec![vec![Int(2)], vec![Float(3.0)]];
+        assert_eq!(
+            Union.combine(left.clone(), right.clone()),
+            vec![vec![Float(2.0)], vec![Float(2.5)], vec![Float(3.0)]]
+        );
+        assert_eq!(
+            Intersect.combine(left.clone(), right.clone()),
+            vec![vec![Float(2.0)]]
+        );
+        assert_eq!(Except.combine(left, right), vec![vec![Float(2.5)]]);
+    }
+
+    #[test]
+    fn test_from_keyword() {
+        assert_eq!(CompoundKind::from_keyword("UNION ALL"), Some(UnionAll));
+        assert_eq!(CompoundKind::from_keyword("EXCEPT"), Some(Except));
+        assert_eq!(CompoundKind::from_keyword("SELECT"), None);
+        assert_eq!(UnionAll.to_string(), "UNION ALL");
+    }
+}
@@ -0,0 +1,478 @@
+use crate::errored;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::{Date, Int, Str, Timestamp};
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::{Default, Syntax};
+use std::fmt::{Display, Formatter};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Años representables por una fecha, que siempre se escribe con cuatro dígitos.
+const YEARS: std::ops::RangeInclusive<i64> = 0..=9999;
+
+/// Enum que representa las partes de una fecha u hora, usadas para extraer un campo
+/// (`EXTRACT`), sumar un intervalo (`DATE_ADD`) o truncar un valor (`DATE_TRUNC`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DatePart {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl DatePart {
+    /// Obtiene la parte correspondiente a un nombre, sin distinguir mayúsculas.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::date::DatePart;
+    /// assert_eq!(DatePart::from_name("month"), Some(DatePart::Month));
+    /// assert_eq!(DatePart::from_name("week"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<DatePart> {
+        match name.to_uppercase().as_str() {
+            "YEAR" => Some(DatePart::Year),
+            "MONTH" => Some(DatePart::Month),
+            "DAY" => Some(DatePart::Day),
+            "HOUR" => Some(DatePart::Hour),
+            "MINUTE" => Some(DatePart::Minute),
+            "SECOND" => Some(DatePart::Second),
+            _ => None,
+        }
+    }
+
+    /// Cantidad de segundos de la parte, para las partes de duración fija.
+    fn seconds(&self) -> Option<i64> {
+        match self {
+            DatePart::Day => Some(SECONDS_PER_DAY),
+            DatePart::Hour => Some(3600),
+            DatePart::Minute => Some(60),
+            DatePart::Second => Some(1),
+            DatePart::Year | DatePart::Month => None,
+        }
+    }
+}
+
+impl Display for DatePart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_uppercase())
+    }
+}
+
+/// Interpreta una fecha en formato ISO (`AAAA-MM-DD`).
+///
+/// # Retorna
+///
+/// La cantidad de días desde el 1970-01-01, o `None` si el texto no es una fecha válida.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::query::structs::date::parse_date;
+/// assert_eq!(parse_date("1970-01-02"), Some(1));
+/// assert_eq!(parse_date("2023-02-29"), None);
+/// ```
+pub fn parse_date(raw: &str) -> Option<i64> {
+    let bytes = raw.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = parse_digits(&raw[0..4])?;
+    let month = parse_digits(&raw[5..7])?;
+    let day = parse_digits(&raw[8..10])?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Interpreta una fecha y hora en formato ISO (`AAAA-MM-DD HH:MM:SS`, o con `T` como separador).
+/// Una fecha sin hora se interpreta como el comienzo del día.
+///
+/// # Retorna
+///
+/// La cantidad de segundos desde el 1970-01-01 00:00:00, o `None` si el texto no es válido.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::query::structs::date::parse_timestamp;
+/// assert_eq!(parse_timestamp("1970-01-01 01:00:30"), Some(3630));
+/// assert_eq!(parse_timestamp("1970-01-02"), Some(86400));
+/// assert_eq!(parse_timestamp("1970-01-01 24:00:00"), None);
+/// ```
+pub fn parse_timestamp(raw: &str) -> Option<i64> {
+    if raw.len() == 10 {
+        return parse_date(raw).map(|days| days * SECONDS_PER_DAY);
+    }
+    let bytes = raw.as_bytes();
+    if bytes.len() != 19 || !matches!(bytes[10], b' ' | b'T') {
+        return None;
+    }
+    if bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let days = parse_date(&raw[0..10])?;
+    let hour = parse_digits(&raw[11..13])?;
+    let minute = parse_digits(&raw[14..16])?;
+    let second = parse_digits(&raw[17..19])?;
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(days * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+/// Escribe una fecha, expresada en días desde el 1970-01-01, en formato `AAAA-MM-DD`.
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Escribe una fecha y hora, expresada en segundos desde el 1970-01-01, en formato
+/// `AAAA-MM-DD HH:MM:SS`.
+pub fn format_timestamp(seconds: i64) -> String {
+    let (days, time) = split_timestamp(seconds);
+    format!(
+        "{} {:02}:{:02}:{:02}",
+        format_date(days),
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Obtiene la fecha y hora actual (en UTC), como valor de `NOW()`.
+pub fn now() -> ExpressionResult {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    Timestamp(seconds)
+}
+
+/// Convierte un valor en una fecha o una fecha y hora.
+///
+/// Los textos con formato de fecha (`AAAA-MM-DD`) o de fecha y hora se interpretan como tales,
+/// de manera que puedan usarse directamente como argumento de las funciones de fecha.
+///
+/// # Errores
+///
+/// Retorna un error si el valor no representa una fecha.
+pub fn as_temporal(value: &ExpressionResult) -> Result<ExpressionResult, Errored> {
+    match value {
+        Date(_) | Timestamp(_) => Ok(value.clone()),
+        Str(s) => match (parse_date(s), parse_timestamp(s)) {
+            (Some(days), _) => Ok(Date(days)),
+            (_, Some(seconds)) => Ok(Timestamp(seconds)),
+            _ => errored!(Syntax, "invalid date: '{}'", s),
+        },
+        _ => errored!(Syntax, "expected a date, got: {:?}", value),
+    }
+}
+
+/// Convierte un valor en la cantidad de segundos desde el 1970-01-01, para comparar fechas
+/// con fechas y horas. Una fecha se considera al comienzo de su día.
+///
+/// # Errores
+///
+/// Retorna un error si el valor no representa una fecha.
+pub fn as_seconds(value: &ExpressionResult) -> Result<i64, Errored> {
+    match as_temporal(value)? {
+        Date(days) => Ok(days * SECONDS_PER_DAY),
+        Timestamp(seconds) => Ok(seconds),
+        _ => errored!(Syntax, "expected a date, got: {:?}", value),
+    }
+}
+
+/// Suma a una fecha una cantidad de la parte indicada, como en `DATE_ADD(fecha, INTERVAL 1 MONTH)`.
+///
+/// Al sumar meses o años, si el día no existe en el mes resultante se usa el último día del
+/// mes (`2024-01-31` más un mes es `2024-02-29`). Sumar horas, minutos o segundos a una
+/// fecha da como resultado una fecha y hora.
+///
+/// # Errores
+///
+/// Retorna un error si el valor no es una fecha o si el resultado está fuera de rango.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::query::structs::date::{add, parse_date, DatePart};
+/// use rustic_sql::query::structs::expression::ExpressionResult::Date;
+/// let date = Date(parse_date("2024-01-31").unwrap());
+/// let added = add(&date, 1, &DatePart::Month).unwrap();
+/// assert_eq!(added.to_string(), "2024-02-29");
+/// ```
+pub fn add(
+    value: &ExpressionResult,
+    amount: i64,
+    part: &DatePart,
+) -> Result<ExpressionResult, Errored> {
+    let months = match part {
+        DatePart::Year => amount.checked_mul(12),
+        DatePart::Month => Some(amount),
+        _ => None,
+    };
+    let result = match (as_temporal(value)?, months) {
+        (Date(days), Some(months)) => add_months(days, months).map(Date),
+        (Timestamp(seconds), Some(months)) => {
+            let (days, time) = split_timestamp(seconds);
+            add_months(days, months).map(|days| Timestamp(days * SECONDS_PER_DAY + time))
+        }
+        (Date(days), None) if *part == DatePart::Day => days.checked_add(amount).map(Date),
+        (temporal, None) => part
+            .seconds()
+            .and_then(|seconds| amount.checked_mul(seconds))
+            .and_then(|offset| as_seconds(&temporal).ok()?.checked_add(offset))
+            .map(Timestamp),
+        _ => None,
+    };
+    match result {
+        Some(result) if in_range(&result) => Ok(result),
+        _ => errored!(
+            Default,
+            "date out of range adding {} {} to {}",
+            amount,
+            part,
+            value
+        ),
+    }
+}
+
+/// Extrae una parte de una fecha, como en `EXTRACT(YEAR FROM fecha)`.
+///
+/// Las partes de la hora de una fecha sin hora son cero.
+///
+/// # Errores
+///
+/// Retorna un error si el valor no es una fecha.
+pub fn extract(value: &ExpressionResult, part: &DatePart) -> Result<ExpressionResult, Errored> {
+    let (days, time) = split_timestamp(as_seconds(value)?);
+    let (year, month, day) = civil_from_days(days);
+    let field = match part {
+        DatePart::Year => year,
+        DatePart::Month => month,
+        DatePart::Day => day,
+        DatePart::Hour => time / 3600,
+        DatePart::Minute => time % 3600 / 60,
+        DatePart::Second => time % 60,
+    };
+    Ok(Int(field))
+}
+
+/// Trunca una fecha a la precisión indicada, como en `DATE_TRUNC('month', fecha)`, conservando
+/// su tipo: una fecha sigue siendo una fecha, y una fecha y hora sigue siéndolo.
+///
+/// # Errores
+///
+/// Retorna un error si el valor no es una fecha.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::query::structs::date::{parse_timestamp, truncate, DatePart};
+/// use rustic_sql::query::structs::expression::ExpressionResult::Timestamp;
+/// let timestamp = Timestamp(parse_timestamp("2024-05-17 10:45:12").unwrap());
+/// let truncated = truncate(&timestamp, &DatePart::Hour).unwrap();
+/// assert_eq!(truncated.to_string(), "2024-05-17 10:00:00");
+/// ```
+pub fn truncate(value: &ExpressionResult, part: &DatePart) -> Result<ExpressionResult, Errored> {
+    let temporal = as_temporal(value)?;
+    let (days, time) = split_timestamp(as_seconds(&temporal)?);
+    let (year, month, _) = civil_from_days(days);
+    let days = match part {
+        DatePart::Year => days_from_civil(year, 1, 1),
+        DatePart::Month => days_from_civil(year, month, 1),
+        _ => days,
+    };
+    let time = match part.seconds() {
+        Some(seconds) if seconds < SECONDS_PER_DAY => time - time % seconds,
+        _ => 0,
+    };
+    match temporal {
+        Date(_) => Ok(Date(days)),
+        _ => Ok(Timestamp(days * SECONDS_PER_DAY + time)),
+    }
+}
+
+/// Separa una fecha y hora en días desde el 1970-01-01 y segundos desde el comienzo del día.
+fn split_timestamp(seconds: i64) -> (i64, i64) {
+    (
+        seconds.div_euclid(SECONDS_PER_DAY),
+        seconds.rem_euclid(SECONDS_PER_DAY),
+    )
+}
+
+/// Suma meses a una fecha, usando el último día del mes si el día no existe en el mes resultante.
+fn add_months(days: i64, months: i64) -> Option<i64> {
+    let (year, month, day) = civil_from_days(days);
+    let total = (year * 12 + month - 1).checked_add(months)?;
+    let (year, month) = (total.div_euclid(12), total.rem_euclid(12) + 1);
+    if !YEARS.contains(&year) {
+        return None;
+    }
+    Some(days_from_civil(
+        year,
+        month,
+        day.min(days_in_month(year, month)),
+    ))
+}
+
+/// Indica si el año de una fecha puede representarse con cuatro dígitos.
+fn in_range(value: &ExpressionResult) -> bool {
+    let days = match value {
+        Date(days) => *days,
+        Timestamp(seconds) => split_timestamp(*seconds).0,
+        _ => return false,
+    };
+    YEARS.contains(&civil_from_days(days).0)
+}
+
+/// Interpreta un número formado solo por dígitos decimales.
+fn parse_digits(raw: &str) -> Option<i64> {
+    if raw.chars().all(|c| c.is_ascii_digit()) {
+        raw.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Calcula los días desde el 1970-01-01 de una fecha del calendario gregoriano.
+///
+/// Los años se agrupan en eras de 400 años, tras las cuales el calendario se repite, y
+/// cada año se cuenta desde marzo para que el día bisiesto quede al final.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Calcula el año, mes y día del calendario gregoriano correspondiente a una cantidad de días
+/// desde el 1970-01-01. Es la inversa de `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = (month_from_march + 2) % 12 + 1;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(raw: &str) -> ExpressionResult {
+        Date(parse_date(raw).unwrap())
+    }
+
+    fn timestamp(raw: &str) -> ExpressionResult {
+        Timestamp(parse_timestamp(raw).unwrap())
+    }
+
+    #[test]
+    fn test_parse_and_format_dates() {
+        for raw in [
+            "1970-01-01",
+            "2000-02-29",
+            "2024-12-31",
+            "0001-01-01",
+            "9999-12-31",
+        ] {
+            assert_eq!(format_date(parse_date(raw).unwrap()), raw);
+        }
+        assert_eq!(parse_date("1969-12-31"), Some(-1));
+        assert_eq!(
+            format_timestamp(parse_timestamp("2024-01-31T23:59:59").unwrap()),
+            "2024-01-31 23:59:59"
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_dates() {
+        for raw in [
+            "2024-1-31",
+            "2024-13-01",
+            "2024-00-10",
+            "1900-02-29",
+            "24-01-01",
+            "+024-01-01",
+        ] {
+            assert_eq!(parse_date(raw), None, "{}", raw);
+        }
+        assert_eq!(parse_timestamp("2024-01-01 10:60:00"), None);
+        assert_eq!(parse_timestamp("2024-01-01 10:00"), None);
+    }
+
+    #[test]
+    fn test_add_interval() {
+        let added = add(&date("2024-01-31"), 1, &DatePart::Month).unwrap();
+        assert_eq!(added, date("2024-02-29"));
+        let added = add(&date("2024-02-29"), -1, &DatePart::Year).unwrap();
+        assert_eq!(added, date("2023-02-28"));
+        let added = add(&date("2024-12-31"), 1, &DatePart::Day).unwrap();
+        assert_eq!(added, date("2025-01-01"));
+        let added = add(&date("2024-12-31"), 90, &DatePart::Minute).unwrap();
+        assert_eq!(added, timestamp("2024-12-31 01:30:00"));
+        let added = add(&timestamp("2024-03-31 08:00:00"), 1, &DatePart::Month).unwrap();
+        assert_eq!(added, timestamp("2024-04-30 08:00:00"));
+        assert!(add(&date("9999-12-31"), 1, &DatePart::Day).is_err());
+        assert!(add(&Int(3), 1, &DatePart::Day).is_err());
+    }
+
+    #[test]
+    fn test_extract() {
+        let value = timestamp("2024-05-17 10:45:12");
+        assert_eq!(extract(&value, &DatePart::Year).unwrap(), Int(2024));
+        assert_eq!(extract(&value, &DatePart::Month).unwrap(), Int(5));
+        assert_eq!(extract(&value, &DatePart::Minute).unwrap(), Int(45));
+        assert_eq!(
+            extract(&date("2024-05-17"), &DatePart::Hour).unwrap(),
+            Int(0)
+        );
+        let text = Str("2024-05-17".to_string());
+        assert_eq!(extract(&text, &DatePart::Day).unwrap(), Int(17));
+    }
+
+    #[test]
+    fn test_truncate() {
+        let value = timestamp("2024-05-17 10:45:12");
+        assert_eq!(
+            truncate(&value, &DatePart::Year).unwrap(),
+            timestamp("2024-01-01 00:00:00")
+        );
+        assert_eq!(
+            truncate(&value, &DatePart::Minute).unwrap(),
+            timestamp("2024-05-17 10:45:00")
+        );
+        assert_eq!(
+            truncate(&date("2024-05-17"), &DatePart::Month).unwrap(),
+            date("2024-05-01")
+        );
+    }
+}
@@ -1,32 +1,111 @@
 use crate::errored;
-use crate::query::structs::comparator::ExpressionComparator;
-use crate::query::structs::expression::ExpressionResult::{Bool, Int, Str};
+use crate::query::structs::aggregate::Aggregate;
+use crate::query::structs::arithmetic::ExpressionCalculator;
+use crate::query::structs::comparator::{ExpressionComparator, Pattern};
+use crate::query::structs::date::{format_date, format_timestamp};
+use crate::query::structs::expression::ExpressionResult::{
+    Bool, Date, Float, Int, Null, Str, Timestamp,
+};
+use crate::query::structs::function::Function;
+use crate::query::structs::query::Query;
+use crate::query::structs::row::resolve_column;
+use crate::query::structs::token::TokenKind::Identifier;
 use crate::query::structs::token::{Token, TokenKind};
+use crate::query::structs::window::Window;
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::{Column, Default, Syntax};
-use std::collections::HashMap;
-use std::fmt::{Debug, Formatter};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::mem::discriminant;
+
+/// Cantidad mínima de valores constantes de una lista `IN` a partir de la cual se
+/// construye un conjunto para buscarlos, en lugar de compararlos uno por uno.
+const IN_LOOKUP_THRESHOLD: usize = 8;
 
 /// Enum que representa a una expresión.
 ///
 /// Usando una estructura recursiva de nodos, el mismo puede ser un nodo vacío, una hoja
-/// con un token, o una declaración con un operador y dos sub-nodos (izquierdo y derecho).
+/// con un token, una agregación sobre un grupo de filas, o una declaración con un operador
+/// y dos sub-nodos (izquierdo y derecho).
+///
+/// Las llamadas a funciones escalares (`UPPER(nombre)`) se representan con un nodo `Function`,
+/// que se evalúa en cada fila a partir de los valores de sus argumentos.
+///
+/// Las expresiones condicionales (`CASE WHEN edad > 60 THEN 'senior' ELSE 'adulto' END`) se
+/// representan con un nodo `Case`, que tiene un operando opcional (en la forma `CASE x WHEN 1
+/// THEN ...`), las ramas `WHEN ... THEN ...` en orden y un valor por defecto opcional (`ELSE`).
+///
+/// Las funciones de ventana (`RANK() OVER (ORDER BY edad)`) se representan con un nodo `Window`.
+/// Al igual que las agregaciones, su valor se calcula antes de evaluar la expresión y se
+/// obtiene de la fila por su nombre SQL.
+///
+/// Además existen nodos para los predicados con más de dos miembros:
+///
+/// - `InList`: Un valor buscado dentro de una lista (`x IN (1, 2, 3)`), que puede estar negado
+///   (`NOT IN`). Si la lista es grande y constante, incluye un conjunto (`lookup`) con sus valores.
+/// - `Between`: Un valor dentro de un rango inclusivo (`x BETWEEN 1 AND 10`), que puede estar
+///   negado (`NOT BETWEEN`).
+///
+/// Las comparaciones contra un patrón (`nombre LIKE 'J%'`) se representan con un nodo `Like`,
+/// cuyo operador es `LIKE`, `ILIKE` o alguna de sus negaciones. Si el patrón es constante,
+/// incluye el patrón ya interpretado (`compiled`), de manera que no se interpreta en cada fila.
+///
+/// Las sub-consultas (`SELECT` anidados) se representan con:
+///
+/// - `Subquery`: Una sub-consulta escalar (`edad > (SELECT AVG(edad) FROM usuarios)`). Si es el
+///   único elemento de la lista de un `InList`, la lista está formada por todos sus resultados.
+/// - `Exists`: Un predicado `EXISTS (SELECT ...)`, verdadero si la sub-consulta tiene resultados.
+///
+/// Ambos nodos deben ser reemplazados por su resultado (un nodo `Constant`) antes de evaluar la
+/// expresión, ya que ejecutarlos requiere leer otra tabla (ver `Executor::resolve_subqueries`).
 #[derive(Default, PartialEq)]
 pub enum ExpressionNode {
     #[default]
     Empty,
     Leaf(Token),
+    Constant(ExpressionResult),
+    Aggregate(Aggregate),
+    Window(Window),
+    Function(Function),
     Statement {
         operator: ExpressionOperator,
         left: Box<ExpressionNode>,
         right: Box<ExpressionNode>,
     },
+    Like {
+        expression: Box<ExpressionNode>,
+        operator: ExpressionOperator,
+        pattern: Box<ExpressionNode>,
+        compiled: Option<Pattern>,
+    },
+    InList {
+        expression: Box<ExpressionNode>,
+        list: Vec<ExpressionNode>,
+        lookup: Option<HashSet<ExpressionResult>>,
+        negated: bool,
+    },
+    Between {
+        expression: Box<ExpressionNode>,
+        low: Box<ExpressionNode>,
+        high: Box<ExpressionNode>,
+        negated: bool,
+    },
+    Case {
+        operand: Option<Box<ExpressionNode>>,
+        branches: Vec<(ExpressionNode, ExpressionNode)>,
+        default: Option<Box<ExpressionNode>>,
+    },
+    Subquery(Box<Query>),
+    Exists(Box<Query>),
 }
 
 /// Enum que define los operadores posibles en una expresión.
 ///
-/// Los operadores incluyen comparación (igual, mayor.. etc.) y operadores
-/// lógicos (AND, OR, NOT).
+/// Los operadores incluyen comparación (igual, mayor.. etc.), comparación de patrones
+/// (LIKE, ILIKE y sus negaciones, con un caracter de escape opcional), operadores
+/// lógicos (AND, OR, NOT), predicados de nulidad (IS NULL, IS NOT NULL) y
+/// operadores aritméticos (+, -, *, /, %).
 #[derive(Debug, Default, PartialEq)]
 pub enum ExpressionOperator {
     #[default]
@@ -37,22 +116,167 @@ pub enum ExpressionOperator {
     LessThan,
     GreaterOrEqual,
     LessOrEqual,
+    Like(Option<char>),
+    NotLike(Option<char>),
+    ILike(Option<char>),
+    NotILike(Option<char>),
     And,
     Or,
     Not,
+    IsNull,
+    IsNotNull,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
 }
 
 /// Enum que representa los posibles resultados de una expresión.
 ///
-/// Los resultados pueden ser un entero, un string  o un valor booleano.
-#[derive(Debug, PartialEq)]
+/// Los resultados pueden ser un entero, un número de punto flotante, un string, un valor
+/// booleano, una fecha o `Null`, que representa la ausencia de valor (y también el valor
+/// lógico "desconocido").
+///
+/// Los enteros y los flotantes son comparables y operables entre sí: al mezclarlos, el
+/// entero se convierte a flotante.
+///
+/// Las fechas (`Date`) se representan con la cantidad de días desde el 1970-01-01, y las fechas
+/// con hora (`Timestamp`) con la cantidad de segundos desde el 1970-01-01 00:00:00. Ambas son
+/// comparables entre sí y con textos que tengan formato de fecha, como `'2024-01-31'`
+/// (ver `ExpressionComparator::compare_dates`).
+#[derive(Debug, Clone, PartialEq)]
 pub enum ExpressionResult {
     Int(i64),
+    Float(f64),
     Str(String),
     Bool(bool),
+    Date(i64),
+    Timestamp(i64),
+    Null,
 }
 
 impl ExpressionNode {
+    /// Crea un nodo `InList` que busca el valor de `expression` dentro de `list`.
+    ///
+    /// Si la lista tiene al menos `IN_LOOKUP_THRESHOLD` elementos y todos son literales
+    /// (no nulos) de un mismo tipo, sus valores se evalúan una única vez y se guardan en un
+    /// conjunto, de manera que cada búsqueda no depende del largo de la lista.
+    ///
+    /// # Parámetros
+    ///
+    /// * `expression` - La expresión cuyo valor se busca.
+    /// * `list` - Las expresiones de la lista.
+    /// * `negated` - Si el predicado es `NOT IN`.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionNode;
+    /// use rustic_sql::query::structs::token::{Token, TokenKind};
+    /// use std::collections::HashMap;
+    ///
+    /// let leaf = |value: &str, kind| ExpressionNode::Leaf(Token { value: value.to_string(), kind });
+    /// let list = vec![leaf("1", TokenKind::Number), leaf("2", TokenKind::Number)];
+    /// let node = ExpressionNode::in_list(leaf("2", TokenKind::Number), list, false);
+    /// assert_eq!(node.to_string(), "2 IN (1, 2)");
+    /// assert!(node.evaluate(&HashMap::new()).unwrap().as_logical().unwrap().unwrap());
+    /// ```
+    pub fn in_list(expression: ExpressionNode, list: Vec<ExpressionNode>, negated: bool) -> Self {
+        let lookup = if list.len() >= IN_LOOKUP_THRESHOLD {
+            ExpressionNode::constant_set(&list)
+        } else {
+            Option::None
+        };
+        ExpressionNode::InList {
+            expression: Box::new(expression),
+            list,
+            lookup,
+            negated,
+        }
+    }
+
+    /// Crea un nodo `Like` que compara el valor de `expression` contra `pattern`.
+    ///
+    /// Si el patrón es un texto constante, se interpreta una única vez al crear el nodo. Si no
+    /// (por ejemplo, si es una columna), o si el patrón es inválido, se interpreta al evaluar
+    /// cada fila, donde se informa el error.
+    ///
+    /// # Parámetros
+    ///
+    /// * `expression` - La expresión cuyo valor se compara.
+    /// * `operator` - El operador de patrones (`LIKE`, `ILIKE` o sus negaciones).
+    /// * `pattern` - La expresión del patrón.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::{ExpressionNode, ExpressionOperator};
+    /// use rustic_sql::query::structs::token::{Token, TokenKind};
+    /// use std::collections::HashMap;
+    ///
+    /// let leaf = |value: &str| ExpressionNode::Leaf(Token { value: value.to_string(), kind: TokenKind::String });
+    /// let node = ExpressionNode::like(leaf("Mouse"), ExpressionOperator::ILike(None), leaf("m%"));
+    /// assert_eq!(node.to_string(), "'Mouse' ILIKE 'm%'");
+    /// assert!(node.evaluate(&HashMap::new()).unwrap().as_logical().unwrap().unwrap());
+    /// ```
+    pub fn like(
+        expression: ExpressionNode,
+        operator: ExpressionOperator,
+        pattern: ExpressionNode,
+    ) -> Self {
+        let text = match &pattern {
+            ExpressionNode::Leaf(t) if t.kind == TokenKind::String => Some(t.value.as_str()),
+            ExpressionNode::Constant(Str(s)) => Some(s.as_str()),
+            _ => Option::None,
+        };
+        let compiled = match (text, &operator) {
+            (
+                Some(text),
+                ExpressionOperator::Like(escape) | ExpressionOperator::NotLike(escape),
+            ) => Pattern::compile(text, *escape, false).ok(),
+            (
+                Some(text),
+                ExpressionOperator::ILike(escape) | ExpressionOperator::NotILike(escape),
+            ) => Pattern::compile(text, *escape, true).ok(),
+            _ => Option::None,
+        };
+        ExpressionNode::Like {
+            expression: Box::new(expression),
+            operator,
+            pattern: Box::new(pattern),
+            compiled,
+        }
+    }
+
+    /// Evalúa los elementos de una lista como un conjunto de constantes.
+    ///
+    /// # Retorna
+    ///
+    /// El conjunto de valores, o `None` si algún elemento no es un literal, es `NULL`,
+    /// o si los elementos no son todos del mismo tipo.
+    fn constant_set(list: &[ExpressionNode]) -> Option<HashSet<ExpressionResult>> {
+        let mut set = HashSet::new();
+        for node in list {
+            let value = match node {
+                ExpressionNode::Leaf(t)
+                    if t.kind == TokenKind::Number || t.kind == TokenKind::String =>
+                {
+                    node.evaluate(&HashMap::new()).ok()?
+                }
+                ExpressionNode::Constant(value) if *value != Null => value.clone(),
+                _ => return Option::None,
+            };
+            if let Some(first) = set.iter().next() {
+                if discriminant(first) != discriminant(&value) {
+                    return Option::None;
+                }
+            }
+            set.insert(value);
+        }
+        Some(set)
+    }
+
     /// Evalúa el nodo de expresión usando los valores proporcionados.
     /// Dichos valores estan contenidos dentro de un mapa que representa el contexto actual
     /// de la ejecución.
@@ -65,15 +289,35 @@ impl ExpressionNode {
     ///
     /// Un `Result` que contiene el resultado de la evaluación de la expresión o un error en caso de
     /// que ocurra algún problema.
-    pub fn evaluate(&self, values: &HashMap<String, String>) -> Result<ExpressionResult, Errored> {
+    pub fn evaluate(
+        &self,
+        values: &HashMap<String, ExpressionResult>,
+    ) -> Result<ExpressionResult, Errored> {
         match self {
             ExpressionNode::Empty => Ok(Bool(true)),
             ExpressionNode::Leaf(t) => match t.kind {
                 TokenKind::Identifier => ExpressionNode::get_variable_value(values, t),
                 TokenKind::String => Ok(Str(t.value.to_string())),
-                TokenKind::Number => Ok(Int(t.value.parse::<i64>()?)),
+                TokenKind::Number => ExpressionResult::parse_number(&t.value),
+                TokenKind::Keyword if t.value == "NULL" => Ok(Null),
                 _ => Ok(Bool(false)),
             },
+            ExpressionNode::Constant(value) => Ok(value.clone()),
+            ExpressionNode::Aggregate(aggregate) => {
+                let column = Token {
+                    value: aggregate.to_string(),
+                    kind: Identifier,
+                };
+                ExpressionNode::get_variable_value(values, &column)
+            }
+            ExpressionNode::Window(window) => {
+                let column = Token {
+                    value: window.to_string(),
+                    kind: Identifier,
+                };
+                ExpressionNode::get_variable_value(values, &column)
+            }
+            ExpressionNode::Function(function) => function.evaluate(values),
             ExpressionNode::Statement {
                 operator,
                 left,
@@ -83,6 +327,158 @@ impl ExpressionNode {
                 let r = right.evaluate(values)?;
                 ExpressionNode::get_statement_value(operator, l, r)
             }
+            ExpressionNode::Like {
+                expression,
+                operator,
+                pattern,
+                compiled,
+            } => {
+                let value = expression.evaluate(values)?;
+                match compiled {
+                    Some(compiled) => ExpressionNode::get_pattern_value(operator, value, compiled),
+                    Option::None => {
+                        let pattern = pattern.evaluate(values)?;
+                        ExpressionNode::get_statement_value(operator, value, pattern)
+                    }
+                }
+            }
+            ExpressionNode::InList {
+                expression,
+                list,
+                lookup,
+                negated,
+            } => {
+                let value = expression.evaluate(values)?;
+                let found = ExpressionNode::find_in_list(value, list, lookup, values)?;
+                ExpressionNode::negate_if(found, *negated)
+            }
+            ExpressionNode::Between {
+                expression,
+                low,
+                high,
+                negated,
+            } => {
+                let value = expression.evaluate(values)?;
+                let above = ExpressionNode::get_statement_value(
+                    &ExpressionOperator::GreaterOrEqual,
+                    value.clone(),
+                    low.evaluate(values)?,
+                )?;
+                let below = ExpressionNode::get_statement_value(
+                    &ExpressionOperator::LessOrEqual,
+                    value,
+                    high.evaluate(values)?,
+                )?;
+                let within =
+                    ExpressionNode::get_statement_value(&ExpressionOperator::And, above, below)?;
+                ExpressionNode::negate_if(within, *negated)
+            }
+            ExpressionNode::Case {
+                operand,
+                branches,
+                default,
+            } => {
+                let operand = match operand {
+                    Some(operand) => Some(operand.evaluate(values)?),
+                    Option::None => Option::None,
+                };
+                for (condition, result) in branches {
+                    let condition = match &operand {
+                        Some(operand) => ExpressionNode::get_statement_value(
+                            &ExpressionOperator::Equals,
+                            operand.clone(),
+                            condition.evaluate(values)?,
+                        )?,
+                        Option::None => condition.evaluate(values)?,
+                    };
+                    if condition.as_logical()? == Some(true) {
+                        return result.evaluate(values);
+                    }
+                }
+                match default {
+                    Some(default) => default.evaluate(values),
+                    Option::None => Ok(Null),
+                }
+            }
+            ExpressionNode::Subquery(_) | ExpressionNode::Exists(_) => errored!(
+                Default,
+                "subquery must be executed before evaluating: {}",
+                self
+            ),
+        }
+    }
+
+    /// Busca un valor dentro de la lista de un predicado `IN`.
+    ///
+    /// Si la lista tiene un conjunto de constantes del mismo tipo que el valor, la búsqueda
+    /// se resuelve en el conjunto. Si no, el valor se compara con cada elemento usando `=`,
+    /// siguiendo la lógica de tres valores: si no se encuentra y algún elemento es `NULL`,
+    /// el resultado es `Null`.
+    ///
+    /// # Parámetros
+    ///
+    /// * `value` - El valor buscado.
+    /// * `list` - Las expresiones de la lista.
+    /// * `lookup` - El conjunto de constantes de la lista, si existe.
+    /// * `values` - Un `HashMap` que contiene los pares clave, valor del contexto actual.
+    ///
+    /// # Retorna
+    ///
+    /// Un `Result` con `Bool` indicando si se encontró el valor, o `Null` si es desconocido.
+    fn find_in_list(
+        value: ExpressionResult,
+        list: &[ExpressionNode],
+        lookup: &Option<HashSet<ExpressionResult>>,
+        values: &HashMap<String, ExpressionResult>,
+    ) -> Result<ExpressionResult, Errored> {
+        if value == Null {
+            return Ok(Null);
+        }
+        if let Some(set) = lookup {
+            if set.contains(&value) {
+                return Ok(Bool(true));
+            }
+            if set.iter().all(|v| discriminant(v) == discriminant(&value)) {
+                return Ok(Bool(false));
+            }
+        }
+        let mut found = Bool(false);
+        for node in list {
+            let equals = ExpressionNode::get_statement_value(
+                &ExpressionOperator::Equals,
+                value.clone(),
+                node.evaluate(values)?,
+            )?;
+            found = ExpressionNode::get_statement_value(&ExpressionOperator::Or, found, equals)?;
+            if found == Bool(true) {
+                break;
+            }
+        }
+        Ok(found)
+    }
+
+    /// Niega el resultado lógico de un predicado si corresponde, conservando `Null`.
+    fn negate_if(result: ExpressionResult, negated: bool) -> Result<ExpressionResult, Errored> {
+        if !negated {
+            return Ok(result);
+        }
+        ExpressionNode::get_statement_value(&ExpressionOperator::Not, result, Null)
+    }
+
+    /// Compara un valor contra un patrón ya interpretado, con las mismas reglas que
+    /// `get_statement_value`: `Null` se propaga y las fechas se comparan escritas como texto.
+    fn get_pattern_value(
+        operator: &ExpressionOperator,
+        value: ExpressionResult,
+        pattern: &Pattern,
+    ) -> Result<ExpressionResult, Errored> {
+        match value {
+            Null => Ok(Null),
+            Str(text) => ExpressionComparator::compare_pattern(&text, pattern, operator),
+            value if value.is_temporal() => {
+                ExpressionComparator::compare_pattern(&value.to_string(), pattern, operator)
+            }
+            _ => errored!(Syntax, "expression members must match in type."),
         }
     }
 
@@ -90,6 +486,10 @@ impl ExpressionNode {
     /// El método comparativo a ser ejecutado depende de los tipos de datos contenidos
     /// en las hojas de la expresión.
     ///
+    /// Los valores `Null` se propagan: toda comparación u operación aritmética con un `Null`
+    /// resulta en `Null`, y los operadores lógicos siguen la lógica de tres valores de SQL.
+    /// Solo los predicados `IS NULL` e `IS NOT NULL` devuelven siempre un booleano.
+    ///
     /// # Parámetros
     ///
     /// * `operator` - El operador de la expresión.
@@ -105,10 +505,42 @@ impl ExpressionNode {
         left: ExpressionResult,
         right: ExpressionResult,
     ) -> Result<ExpressionResult, Errored> {
+        match operator {
+            ExpressionOperator::IsNull => return Ok(Bool(left == Null)),
+            ExpressionOperator::IsNotNull => return Ok(Bool(left != Null)),
+            ExpressionOperator::And | ExpressionOperator::Or | ExpressionOperator::Not => {
+                let (l, r) = (left.as_logical()?, right.as_logical()?);
+                return ExpressionComparator::compare_bools(l, r, operator);
+            }
+            _ => {}
+        }
+        if left == Null || right == Null {
+            return Ok(Null);
+        }
+        if operator.is_arithmetic() {
+            return match (left, right) {
+                (Int(l), Int(r)) => ExpressionCalculator::calculate_ints(l, r, operator),
+                (l, r) if l.is_numeric() && r.is_numeric() => {
+                    ExpressionCalculator::calculate_floats(l.as_float(), r.as_float(), operator)
+                }
+                (l, r) => errored!(
+                    Syntax,
+                    "arithmetic operators need numeric members, got: {:?} and {:?}",
+                    l,
+                    r
+                ),
+            };
+        }
         match (left, right) {
             (Int(l), Int(r)) => ExpressionComparator::compare_ints(l, r, operator),
+            (l, r) if l.is_numeric() && r.is_numeric() => {
+                ExpressionComparator::compare_floats(l.as_float(), r.as_float(), operator)
+            }
             (Str(l), Str(r)) => ExpressionComparator::compare_str(&l, &r, operator),
-            (Bool(l), Bool(r)) => ExpressionComparator::compare_bools(l, r, operator),
+            (Bool(l), Bool(r)) => ExpressionComparator::compare_bools(Some(l), Some(r), operator),
+            (l, r) if l.is_temporal() || r.is_temporal() => {
+                ExpressionComparator::compare_dates(&l, &r, operator)
+            }
             _ => errored!(Syntax, "expression members must match in type."),
         }
     }
@@ -117,6 +549,12 @@ impl ExpressionNode {
     /// Dicho `HashMap`vendría a ser el contexto en donde se esta interprentando la
     /// expresión.
     ///
+    /// Los valores del contexto ya fueron interpretados según el tipo de su columna al leer
+    /// la fila (ver `Row::set`), por lo que se devuelven tal cual.
+    ///
+    /// Si la variable no se encuentra tal cual, se intenta resolver como columna calificada
+    /// (`tabla.columna`) mediante `resolve_column`.
+    ///
     /// # Parámetros
     ///
     /// * `values` - Un `HashMap` que contiene los pares clave, valor del contexto actual.
@@ -126,22 +564,104 @@ impl ExpressionNode {
     ///
     /// Un `Result` que contiene el valor de la variable o un error si la variable no existe.
     pub fn get_variable_value(
-        values: &HashMap<String, String>,
+        values: &HashMap<String, ExpressionResult>,
         t: &Token,
     ) -> Result<ExpressionResult, Errored> {
-        let value = values.get(&t.value);
+        let value = match values.get(&t.value) {
+            Some(v) => Some(v),
+            None => resolve_column(values.keys(), &t.value)?.and_then(|k| values.get(k)),
+        };
         match value {
-            Some(v) => {
-                if v.parse::<i64>().is_ok() {
-                    Ok(Int(v.parse::<i64>()?))
-                } else {
-                    Ok(Str(v.to_string()))
-                }
-            }
+            Some(v) => Ok(v.clone()),
             None => errored!(Column, "column {} does not exist", t.value),
         }
     }
 
+    /// Obtiene todas las columnas referenciadas dentro de la expresión.
+    ///
+    /// # Retorna
+    ///
+    /// Un vector con referencias a cada token identificador encontrado al recorrer el árbol.
+    pub fn identifiers(&self) -> Vec<&Token> {
+        match self {
+            ExpressionNode::Leaf(t) if t.kind == Identifier => vec![t],
+            _ => self
+                .children()
+                .into_iter()
+                .flat_map(|child| child.identifiers())
+                .collect(),
+        }
+    }
+
+    /// Obtiene todas las agregaciones contenidas dentro de la expresión.
+    ///
+    /// # Retorna
+    ///
+    /// Un vector con referencias a cada agregación encontrada al recorrer el árbol.
+    pub fn aggregates(&self) -> Vec<&Aggregate> {
+        match self {
+            ExpressionNode::Aggregate(aggregate) => vec![aggregate],
+            _ => self
+                .children()
+                .into_iter()
+                .flat_map(|child| child.aggregates())
+                .collect(),
+        }
+    }
+
+    /// Obtiene todas las funciones de ventana contenidas dentro de la expresión.
+    ///
+    /// # Retorna
+    ///
+    /// Un vector con referencias a cada función de ventana encontrada al recorrer el árbol.
+    pub fn windows(&self) -> Vec<&Window> {
+        match self {
+            ExpressionNode::Window(window) => vec![window],
+            _ => self
+                .children()
+                .into_iter()
+                .flat_map(|child| child.windows())
+                .collect(),
+        }
+    }
+
+    /// Obtiene los sub-nodos directos de la expresión.
+    fn children(&self) -> Vec<&ExpressionNode> {
+        match self {
+            ExpressionNode::Statement { left, right, .. } => vec![left, right],
+            ExpressionNode::Like {
+                expression,
+                pattern,
+                ..
+            } => vec![expression, pattern],
+            ExpressionNode::Function(function) => function.arguments.iter().collect(),
+            ExpressionNode::InList {
+                expression, list, ..
+            } => {
+                let mut children = vec![&**expression];
+                children.extend(list);
+                children
+            }
+            ExpressionNode::Between {
+                expression,
+                low,
+                high,
+                ..
+            } => vec![expression, low, high],
+            ExpressionNode::Case {
+                operand,
+                branches,
+                default,
+            } => operand
+                .iter()
+                .map(|operand| &**operand)
+                .chain(branches.iter().flat_map(|(when, then)| [when, then]))
+                .chain(default.iter().map(|default| &**default))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     /// Obtiene una tupla de los tokens de una declaración que son hojas.
     /// Este método es usado para representar las actualizaciones de una consulta.
     /// Ya que una actualización tiene una llave y un valor, nos conviene devolver en un par.
@@ -166,6 +686,13 @@ impl Debug for ExpressionNode {
         match self {
             ExpressionNode::Empty => write!(f, "()"),
             ExpressionNode::Leaf(t) => write!(f, "{}", t.value),
+            ExpressionNode::Constant(_)
+            | ExpressionNode::Case { .. }
+            | ExpressionNode::Subquery(_)
+            | ExpressionNode::Exists(_) => write!(f, "{}", self),
+            ExpressionNode::Aggregate(aggregate) => write!(f, "{}", aggregate),
+            ExpressionNode::Window(window) => write!(f, "{}", window),
+            ExpressionNode::Function(function) => write!(f, "{}", function),
             ExpressionNode::Statement {
                 operator,
                 left,
@@ -173,13 +700,354 @@ impl Debug for ExpressionNode {
             } => {
                 write!(f, "{:?}[{:?},{:?}]", operator, left, right)
             }
+            ExpressionNode::Like {
+                expression,
+                operator,
+                pattern,
+                ..
+            } => {
+                write!(f, "{:?}[{:?},{:?}]", operator, expression, pattern)
+            }
+            ExpressionNode::InList {
+                expression,
+                list,
+                negated,
+                ..
+            } => {
+                let name = if *negated { "NotIn" } else { "In" };
+                write!(f, "{}[{:?},{:?}]", name, expression, list)
+            }
+            ExpressionNode::Between {
+                expression,
+                low,
+                high,
+                negated,
+            } => {
+                let name = if *negated { "NotBetween" } else { "Between" };
+                write!(f, "{}[{:?},{:?},{:?}]", name, expression, low, high)
+            }
         }
     }
 }
 
+impl Display for ExpressionNode {
+    /// Representa la expresión con sintaxis SQL, agregando paréntesis alrededor de
+    /// cada sub-expresión compuesta. Es el nombre con el que se proyecta una expresión.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpressionNode::Empty => Ok(()),
+            ExpressionNode::Leaf(t) if t.kind == TokenKind::String => write!(f, "'{}'", t.value),
+            ExpressionNode::Leaf(t) => write!(f, "{}", t.value),
+            ExpressionNode::Constant(Str(s)) => write!(f, "'{}'", s),
+            ExpressionNode::Constant(Null) => write!(f, "NULL"),
+            ExpressionNode::Constant(Date(days)) => write!(f, "DATE '{}'", format_date(*days)),
+            ExpressionNode::Constant(Timestamp(seconds)) => {
+                write!(f, "TIMESTAMP '{}'", format_timestamp(*seconds))
+            }
+            ExpressionNode::Constant(value) => write!(f, "{}", value),
+            ExpressionNode::Aggregate(aggregate) => write!(f, "{}", aggregate),
+            ExpressionNode::Window(window) => write!(f, "{}", window),
+            ExpressionNode::Function(function) => write!(f, "{}", function),
+            ExpressionNode::Subquery(query) => write!(f, "({})", SubquerySql(query)),
+            ExpressionNode::Exists(query) => write!(f, "EXISTS ({})", SubquerySql(query)),
+            ExpressionNode::Statement {
+                operator: ExpressionOperator::Not,
+                left,
+                ..
+            } => write!(f, "NOT {}", Nested(left)),
+            ExpressionNode::Statement {
+                operator: operator @ (ExpressionOperator::IsNull | ExpressionOperator::IsNotNull),
+                left,
+                ..
+            } => write!(f, "{} {}", Nested(left), operator),
+            ExpressionNode::Statement {
+                operator,
+                left,
+                right,
+            } => write!(f, "{} {} {}", Nested(left), operator, Nested(right)),
+            ExpressionNode::Like {
+                expression,
+                operator,
+                pattern,
+                ..
+            } => {
+                write!(f, "{} {} {}", Nested(expression), operator, Nested(pattern))?;
+                match operator {
+                    ExpressionOperator::Like(Some(escape))
+                    | ExpressionOperator::NotLike(Some(escape))
+                    | ExpressionOperator::ILike(Some(escape))
+                    | ExpressionOperator::NotILike(Some(escape)) => {
+                        write!(f, " ESCAPE '{}'", escape)
+                    }
+                    _ => Ok(()),
+                }
+            }
+            ExpressionNode::InList {
+                expression,
+                list,
+                negated,
+                ..
+            } => {
+                let keyword = if *negated { "NOT IN" } else { "IN" };
+                if let [ExpressionNode::Subquery(query)] = list.as_slice() {
+                    return write!(
+                        f,
+                        "{} {} ({})",
+                        Nested(expression),
+                        keyword,
+                        SubquerySql(query)
+                    );
+                }
+                let list: Vec<String> = list.iter().map(|n| n.to_string()).collect();
+                write!(
+                    f,
+                    "{} {} ({})",
+                    Nested(expression),
+                    keyword,
+                    list.join(", ")
+                )
+            }
+            ExpressionNode::Case {
+                operand,
+                branches,
+                default,
+            } => {
+                write!(f, "CASE")?;
+                if let Some(operand) = operand {
+                    write!(f, " {}", Nested(operand))?;
+                }
+                for (when, then) in branches {
+                    write!(f, " WHEN {} THEN {}", when, then)?;
+                }
+                if let Some(default) = default {
+                    write!(f, " ELSE {}", default)?;
+                }
+                write!(f, " END")
+            }
+            ExpressionNode::Between {
+                expression,
+                low,
+                high,
+                negated,
+            } => {
+                let keyword = if *negated { "NOT BETWEEN" } else { "BETWEEN" };
+                write!(
+                    f,
+                    "{} {} {} AND {}",
+                    Nested(expression),
+                    keyword,
+                    Nested(low),
+                    Nested(high)
+                )
+            }
+        }
+    }
+}
+
+/// Envoltorio para mostrar una sub-expresión, encerrándola entre paréntesis si es compuesta.
+struct Nested<'a>(&'a ExpressionNode);
+
+impl Display for Nested<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            ExpressionNode::Statement { .. }
+            | ExpressionNode::Like { .. }
+            | ExpressionNode::InList { .. }
+            | ExpressionNode::Between { .. } => write!(f, "({})", self.0),
+            node => write!(f, "{}", node),
+        }
+    }
+}
+
+/// Envoltorio para mostrar una sub-consulta de forma abreviada, con su proyección, su tabla
+/// y sus condiciones.
+struct SubquerySql<'a>(&'a Query);
+
+impl Display for SubquerySql<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let query = self.0;
+        let columns: Vec<&str> = query.columns.iter().map(|c| c.value.as_str()).collect();
+        let columns = if columns.is_empty() {
+            "*".to_string()
+        } else {
+            columns.join(", ")
+        };
+        write!(f, "SELECT {} FROM {}", columns, query.table)?;
+        if query.conditions != ExpressionNode::Empty {
+            write!(f, " WHERE {}", query.conditions)?;
+        }
+        Ok(())
+    }
+}
+
+impl ExpressionOperator {
+    /// Indica si el operador es aritmético (`+`, `-`, `*`, `/`, `%`).
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(
+            self,
+            ExpressionOperator::Add
+                | ExpressionOperator::Subtract
+                | ExpressionOperator::Multiply
+                | ExpressionOperator::Divide
+                | ExpressionOperator::Modulo
+        )
+    }
+}
+
+impl Display for ExpressionOperator {
+    /// Representa el operador con su símbolo SQL.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let symbol = match self {
+            ExpressionOperator::None => "",
+            ExpressionOperator::Equals => "=",
+            ExpressionOperator::NotEquals => "!=",
+            ExpressionOperator::GreaterThan => ">",
+            ExpressionOperator::LessThan => "<",
+            ExpressionOperator::GreaterOrEqual => ">=",
+            ExpressionOperator::LessOrEqual => "<=",
+            ExpressionOperator::Like(_) => "LIKE",
+            ExpressionOperator::NotLike(_) => "NOT LIKE",
+            ExpressionOperator::ILike(_) => "ILIKE",
+            ExpressionOperator::NotILike(_) => "NOT ILIKE",
+            ExpressionOperator::And => "AND",
+            ExpressionOperator::Or => "OR",
+            ExpressionOperator::Not => "NOT",
+            ExpressionOperator::IsNull => "IS NULL",
+            ExpressionOperator::IsNotNull => "IS NOT NULL",
+            ExpressionOperator::Add => "+",
+            ExpressionOperator::Subtract => "-",
+            ExpressionOperator::Multiply => "*",
+            ExpressionOperator::Divide => "/",
+            ExpressionOperator::Modulo => "%",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+impl ExpressionResult {
+    /// Interpreta un literal numérico, que puede ser un entero o un número con parte decimal.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el literal no es un número válido.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult;
+    /// use rustic_sql::query::structs::expression::ExpressionResult::{Float, Int};
+    /// assert_eq!(ExpressionResult::parse_number("-5").unwrap(), Int(-5));
+    /// assert_eq!(ExpressionResult::parse_number("19.99").unwrap(), Float(19.99));
+    /// assert!(ExpressionResult::parse_number("inf").is_err());
+    /// ```
+    pub fn parse_number(raw: &str) -> Result<ExpressionResult, Errored> {
+        if let Ok(i) = raw.parse::<i64>() {
+            return Ok(Int(i));
+        }
+        match parse_decimal(raw) {
+            Some(f) => Ok(Float(f)),
+            None => errored!(Syntax, "invalid number: {}", raw),
+        }
+    }
+
+    /// Indica si el resultado es numérico (entero o flotante).
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Int(_) | Float(_))
+    }
+
+    /// Indica si el resultado es una fecha (con o sin hora).
+    pub fn is_temporal(&self) -> bool {
+        matches!(self, Date(_) | Timestamp(_))
+    }
+
+    /// Convierte un resultado numérico a flotante, o `NaN` si no es numérico.
+    pub fn as_float(&self) -> f64 {
+        match self {
+            Int(i) => *i as f64,
+            Float(f) => *f,
+            _ => f64::NAN,
+        }
+    }
+
+    /// Interpreta el resultado como un valor lógico de tres estados.
+    ///
+    /// # Retorna
+    ///
+    /// `Some` con el valor de un booleano, o `None` si el resultado es `Null` (desconocido).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el resultado no es booleano ni `Null`.
+    pub fn as_logical(&self) -> Result<Option<bool>, Errored> {
+        match self {
+            Bool(b) => Ok(Some(*b)),
+            Null => Ok(Option::None),
+            _ => errored!(
+                Syntax,
+                "logical operators need boolean members, got: {:?}",
+                self
+            ),
+        }
+    }
+}
+
+impl Display for ExpressionResult {
+    /// Representa el resultado tal cual se escribiría dentro de una tabla.
+    ///
+    /// El valor `Null` se representa con una celda vacía, que es la representación por
+    /// defecto de las tablas (ver `Schema::null_marker`).
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Int(i) => write!(f, "{}", i),
+            Float(x) => write!(f, "{}", x),
+            Str(s) => write!(f, "{}", s),
+            Bool(b) => write!(f, "{}", b),
+            Date(days) => write!(f, "{}", format_date(*days)),
+            Timestamp(seconds) => write!(f, "{}", format_timestamp(*seconds)),
+            Null => Ok(()),
+        }
+    }
+}
+
+/// Los resultados de una expresión se usan como claves de conjuntos (ver `ExpressionNode::in_list`).
+/// Los flotantes nunca son `NaN`, ya que toda operación que no tiene un resultado finito falla,
+/// por lo que la igualdad es reflexiva.
+impl Eq for ExpressionResult {}
+
+impl Hash for ExpressionResult {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        discriminant(self).hash(state);
+        match self {
+            Int(i) => i.hash(state),
+            Float(x) if *x == 0.0 => 0.0f64.to_bits().hash(state),
+            Float(x) => x.to_bits().hash(state),
+            Str(s) => s.hash(state),
+            Bool(b) => b.hash(state),
+            Date(days) => days.hash(state),
+            Timestamp(seconds) => seconds.hash(state),
+            Null => {}
+        }
+    }
+}
+
+/// Interpreta un número con parte decimal opcional y signo opcional, como `-19.99`.
+///
+/// A diferencia de `str::parse::<f64>`, no acepta valores especiales como `inf` o `NaN`,
+/// ni notación científica, de manera que un texto nunca se confunda con un número.
+pub fn parse_decimal(raw: &str) -> Option<f64> {
+    let digits = raw.strip_prefix('-').unwrap_or(raw);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let all_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if integer.is_empty() || !all_digits(integer) || !all_digits(fraction) {
+        return None;
+    }
+    raw.parse::<f64>().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::query::structs::aggregate::AggregateKind::Count;
     use crate::query::structs::token::Token;
     use crate::query::structs::token::TokenKind::*;
     use std::collections::HashMap;
@@ -193,7 +1061,7 @@ mod tests {
     #[test]
     fn test_evaluate_leaf_identifier() {
         let mut values = HashMap::new();
-        values.insert("id_cliente".to_string(), "123".to_string());
+        values.insert("id_cliente".to_string(), Int(123));
         let node = ExpressionNode::Leaf(Token {
             kind: Identifier,
             value: "id_cliente".to_string(),
@@ -267,10 +1135,26 @@ mod tests {
         assert_eq!(node.evaluate(&HashMap::new()).unwrap(), Bool(true));
     }
 
+    #[test]
+    fn test_evaluate_aggregate_from_group_values() {
+        let mut values = HashMap::new();
+        values.insert("COUNT(*)".to_string(), Int(4));
+        let node = ExpressionNode::Aggregate(Aggregate {
+            kind: Count,
+            field: Token {
+                kind: Operator,
+                value: "*".to_string(),
+            },
+            distinct: false,
+        });
+        assert_eq!(node.evaluate(&values).unwrap(), Int(4));
+        assert!(node.evaluate(&HashMap::new()).is_err());
+    }
+
     #[test]
     fn test_get_variable_value_existing() {
         let mut values = HashMap::new();
-        values.insert("id_cliente".to_string(), "789".to_string());
+        values.insert("id_cliente".to_string(), Int(789));
         let token = Token {
             kind: Identifier,
             value: "id_cliente".to_string(),
@@ -329,4 +1213,242 @@ mod tests {
         };
         assert!(node.as_leaf_tuple().is_err());
     }
+
+    #[test]
+    fn test_evaluate_null_propagation() {
+        let mut values = HashMap::new();
+        values.insert("cantidad".to_string(), Null);
+        let cantidad = || {
+            Box::new(ExpressionNode::Leaf(Token {
+                kind: Identifier,
+                value: "cantidad".to_string(),
+            }))
+        };
+        let one = || {
+            Box::new(ExpressionNode::Leaf(Token {
+                kind: Number,
+                value: "1".to_string(),
+            }))
+        };
+        let sum = ExpressionNode::Statement {
+            operator: ExpressionOperator::Add,
+            left: cantidad(),
+            right: one(),
+        };
+        assert_eq!(sum.evaluate(&values).unwrap(), Null);
+        let comparison = ExpressionNode::Statement {
+            operator: ExpressionOperator::Equals,
+            left: cantidad(),
+            right: one(),
+        };
+        assert_eq!(comparison.evaluate(&values).unwrap(), Null);
+        let is_null = ExpressionNode::Statement {
+            operator: ExpressionOperator::IsNull,
+            left: cantidad(),
+            right: Box::new(ExpressionNode::Empty),
+        };
+        assert_eq!(is_null.evaluate(&values).unwrap(), Bool(true));
+        assert_eq!(is_null.to_string(), "cantidad IS NULL");
+    }
+
+    fn number(value: i64) -> ExpressionNode {
+        ExpressionNode::Leaf(Token {
+            kind: Number,
+            value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_evaluate_in_list() {
+        let mut values = HashMap::new();
+        values.insert("id".to_string(), Int(3));
+        let id = || {
+            ExpressionNode::Leaf(Token {
+                kind: Identifier,
+                value: "id".to_string(),
+            })
+        };
+        let small = ExpressionNode::in_list(id(), vec![number(1), number(3)], false);
+        assert_eq!(small.evaluate(&values).unwrap(), Bool(true));
+
+        let large = ExpressionNode::in_list(id(), (10..20).map(number).collect(), true);
+        assert!(matches!(
+            large,
+            ExpressionNode::InList {
+                lookup: Some(_),
+                ..
+            }
+        ));
+        assert_eq!(large.evaluate(&values).unwrap(), Bool(true));
+        values.insert("id".to_string(), Int(15));
+        assert_eq!(large.evaluate(&values).unwrap(), Bool(false));
+        values.insert("id".to_string(), Str("15".to_string()));
+        assert!(large.evaluate(&values).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_like_with_compiled_pattern() {
+        let mut values = HashMap::new();
+        values.insert("email".to_string(), Str("John@Example.com".to_string()));
+        values.insert("pattern".to_string(), Str("%@example.com".to_string()));
+        let leaf = |value: &str, kind| {
+            ExpressionNode::Leaf(Token {
+                kind,
+                value: value.to_string(),
+            })
+        };
+
+        let constant = ExpressionNode::like(
+            leaf("email", Identifier),
+            ExpressionOperator::ILike(Option::None),
+            leaf("%@example.com", String),
+        );
+        assert!(matches!(
+            constant,
+            ExpressionNode::Like {
+                compiled: Some(_),
+                ..
+            }
+        ));
+        assert_eq!(constant.evaluate(&values).unwrap(), Bool(true));
+        values.insert("email".to_string(), Null);
+        assert_eq!(constant.evaluate(&values).unwrap(), Null);
+        values.insert("email".to_string(), Int(1));
+        assert!(constant.evaluate(&values).is_err());
+
+        let column = ExpressionNode::like(
+            leaf("email", Identifier),
+            ExpressionOperator::NotLike(Option::None),
+            leaf("pattern", Identifier),
+        );
+        assert!(matches!(
+            column,
+            ExpressionNode::Like { compiled: None, .. }
+        ));
+        values.insert("email".to_string(), Str("john@example.com".to_string()));
+        assert_eq!(column.evaluate(&values).unwrap(), Bool(false));
+
+        let invalid = ExpressionNode::like(
+            leaf("email", Identifier),
+            ExpressionOperator::Like(Some('!')),
+            leaf("50!", String),
+        );
+        assert_eq!(invalid.to_string(), "email LIKE '50!' ESCAPE '!'");
+        assert!(invalid.evaluate(&values).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_in_list_with_null() {
+        let null = ExpressionNode::Leaf(Token {
+            kind: Keyword,
+            value: "NULL".to_string(),
+        });
+        let node = ExpressionNode::in_list(number(5), vec![number(1), null], false);
+        assert_eq!(node.evaluate(&HashMap::new()).unwrap(), Null);
+        let node = ExpressionNode::in_list(number(1), vec![number(1)], true);
+        assert_eq!(node.evaluate(&HashMap::new()).unwrap(), Bool(false));
+    }
+
+    #[test]
+    fn test_evaluate_between() {
+        let between = |value, negated| ExpressionNode::Between {
+            expression: Box::new(number(value)),
+            low: Box::new(number(1)),
+            high: Box::new(number(10)),
+            negated,
+        };
+        assert_eq!(
+            between(1, false).evaluate(&HashMap::new()).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            between(10, false).evaluate(&HashMap::new()).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(
+            between(11, false).evaluate(&HashMap::new()).unwrap(),
+            Bool(false)
+        );
+        assert_eq!(
+            between(11, true).evaluate(&HashMap::new()).unwrap(),
+            Bool(true)
+        );
+        assert_eq!(between(5, true).to_string(), "5 NOT BETWEEN 1 AND 10");
+    }
+
+    #[test]
+    fn test_evaluate_mixed_numbers() {
+        let leaf = |value: &str| {
+            Box::new(ExpressionNode::Leaf(Token {
+                kind: Number,
+                value: value.to_string(),
+            }))
+        };
+        let product = ExpressionNode::Statement {
+            operator: ExpressionOperator::Multiply,
+            left: leaf("2"),
+            right: leaf("19.99"),
+        };
+        assert_eq!(product.evaluate(&HashMap::new()).unwrap(), Float(39.98));
+        let comparison = ExpressionNode::Statement {
+            operator: ExpressionOperator::GreaterThan,
+            left: leaf("-1"),
+            right: leaf("-1.5"),
+        };
+        assert_eq!(comparison.evaluate(&HashMap::new()).unwrap(), Bool(true));
+
+        let mut values = HashMap::new();
+        values.insert("id".to_string(), Float(12.0));
+        let id = ExpressionNode::Leaf(Token {
+            kind: Identifier,
+            value: "id".to_string(),
+        });
+        let large = ExpressionNode::in_list(id, (10..20).map(number).collect(), false);
+        assert_eq!(large.evaluate(&values).unwrap(), Bool(true));
+    }
+
+    #[test]
+    fn test_evaluate_case() {
+        let edad = || {
+            Box::new(ExpressionNode::Leaf(Token {
+                kind: Identifier,
+                value: "edad".to_string(),
+            }))
+        };
+        let text = |value: &str| ExpressionNode::Constant(Str(value.to_string()));
+        let older_than = |limit| ExpressionNode::Statement {
+            operator: ExpressionOperator::GreaterThan,
+            left: edad(),
+            right: Box::new(number(limit)),
+        };
+        let searched = ExpressionNode::Case {
+            operand: Option::None,
+            branches: vec![
+                (older_than(60), text("senior")),
+                (older_than(17), text("adulto")),
+            ],
+            default: Option::None,
+        };
+        let simple = ExpressionNode::Case {
+            operand: Some(edad()),
+            branches: vec![(number(18), text("recién cumplidos"))],
+            default: Some(Box::new(text("otra"))),
+        };
+
+        let mut values = HashMap::new();
+        values.insert("edad".to_string(), Int(30));
+        assert_eq!(
+            searched.evaluate(&values).unwrap(),
+            Str("adulto".to_string())
+        );
+        assert_eq!(simple.evaluate(&values).unwrap(), Str("otra".to_string()));
+        values.insert("edad".to_string(), Int(18));
+        assert_eq!(
+            simple.evaluate(&values).unwrap(),
+            Str("recién cumplidos".to_string())
+        );
+        values.insert("edad".to_string(), Null);
+        assert_eq!(searched.evaluate(&values).unwrap(), Null);
+        assert_eq!(simple.evaluate(&values).unwrap(), Str("otra".to_string()));
+    }
 }
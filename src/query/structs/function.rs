@@ -0,0 +1,363 @@
+use crate::errored;
+use crate::query::structs::date;
+use crate::query::structs::date::DatePart;
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::expression::ExpressionResult::{Int, Null, Str};
+use crate::query::structs::function::FunctionKind::*;
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::Syntax;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Estructura que representa la llamada a una función escalar dentro de una expresión,
+/// como `UPPER(nombre)` o `SUBSTR(email, 1, 5)`.
+///
+/// A diferencia de una agregación, una función escalar se evalúa sobre cada fila y sus
+/// argumentos pueden ser expresiones cualesquiera.
+///
+/// # Campos
+///
+/// * `kind` - La función a aplicar.
+/// * `arguments` - Las expresiones cuyos valores recibe la función.
+#[derive(PartialEq)]
+pub struct Function {
+    pub kind: FunctionKind,
+    pub arguments: Vec<ExpressionNode>,
+}
+
+/// Enum que representa las funciones escalares disponibles.
+///
+/// - `Upper`: Convierte un texto a mayúsculas.
+/// - `Lower`: Convierte un texto a minúsculas.
+/// - `Length`: Obtiene la cantidad de caracteres de un texto.
+/// - `Substr`: Obtiene una porción de un texto, a partir de una posición (desde 1) y con un largo opcional.
+/// - `Trim`: Elimina los espacios al principio y al final de un texto.
+/// - `Concat`: Une varios textos en uno, ignorando los valores `NULL`.
+/// - `Replace`: Reemplaza todas las apariciones de un texto por otro.
+/// - `Now`: Obtiene la fecha y hora actual.
+/// - `DateAdd`: Suma un intervalo a una fecha (`DATE_ADD(fecha, INTERVAL 3 DAY)`).
+/// - `DateSub`: Resta un intervalo a una fecha (`DATE_SUB(fecha, INTERVAL 3 DAY)`).
+/// - `Extract`: Obtiene una parte de una fecha (`EXTRACT(YEAR FROM fecha)`).
+/// - `DateTrunc`: Trunca una fecha a una precisión (`DATE_TRUNC('month', fecha)`).
+///
+/// La parte de la fecha de `DATE_ADD`, `DATE_SUB` y `EXTRACT` se indica con un identificador,
+/// y se guarda como el primer argumento de la función, un texto constante.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FunctionKind {
+    Upper,
+    Lower,
+    Length,
+    Substr,
+    Trim,
+    Concat,
+    Replace,
+    Now,
+    DateAdd,
+    DateSub,
+    Extract,
+    DateTrunc,
+}
+
+impl FunctionKind {
+    /// Obtiene la función escalar correspondiente a un nombre, sin distinguir mayúsculas.
+    ///
+    /// Es el registro de las funciones disponibles: un nombre que no está aquí no es una función.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::function::FunctionKind;
+    /// assert_eq!(FunctionKind::from_name("upper"), Some(FunctionKind::Upper));
+    /// assert_eq!(FunctionKind::from_name("name"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<FunctionKind> {
+        match name.to_uppercase().as_str() {
+            "UPPER" => Some(Upper),
+            "LOWER" => Some(Lower),
+            "LENGTH" => Some(Length),
+            "SUBSTR" | "SUBSTRING" => Some(Substr),
+            "TRIM" => Some(Trim),
+            "CONCAT" => Some(Concat),
+            "REPLACE" => Some(Replace),
+            "NOW" => Some(Now),
+            "DATE_ADD" => Some(DateAdd),
+            "DATE_SUB" => Some(DateSub),
+            "EXTRACT" => Some(Extract),
+            "DATE_TRUNC" => Some(DateTrunc),
+            _ => None,
+        }
+    }
+
+    /// Nombre SQL de la función, en mayúsculas.
+    fn name(&self) -> &str {
+        match self {
+            Upper => "UPPER",
+            Lower => "LOWER",
+            Length => "LENGTH",
+            Substr => "SUBSTR",
+            Trim => "TRIM",
+            Concat => "CONCAT",
+            Replace => "REPLACE",
+            Now => "NOW",
+            DateAdd => "DATE_ADD",
+            DateSub => "DATE_SUB",
+            Extract => "EXTRACT",
+            DateTrunc => "DATE_TRUNC",
+        }
+    }
+
+    /// Obtiene la cantidad mínima y máxima de argumentos que recibe la función.
+    /// Un máximo `None` indica que la función admite cualquier cantidad de argumentos.
+    fn arity(&self) -> (usize, Option<usize>) {
+        match self {
+            Now => (0, Some(0)),
+            Upper | Lower | Length | Trim => (1, Some(1)),
+            Extract | DateTrunc => (2, Some(2)),
+            Substr => (2, Some(3)),
+            Concat => (1, None),
+            Replace | DateAdd | DateSub => (3, Some(3)),
+        }
+    }
+
+    /// Aplica la función sobre los valores de sus argumentos.
+    ///
+    /// En las funciones de texto, los argumentos que no son texto se convierten a su
+    /// representación textual. En las funciones de fecha, los textos con formato de fecha se
+    /// interpretan como fechas. Si algún argumento es `NULL` el resultado también lo es,
+    /// excepto en `CONCAT` que los ignora.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la posición o el largo de `SUBSTR` no son enteros, o si el largo es
+    /// negativo. En las funciones de fecha, si un argumento no es una fecha, si la parte de
+    /// la fecha no existe, si el intervalo no es un entero o si el resultado está fuera de rango.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::{Int, Str};
+    /// use rustic_sql::query::structs::function::FunctionKind;
+    /// let args = vec![Str("Laptop".to_string()), Int(2), Int(3)];
+    /// assert_eq!(FunctionKind::Substr.apply(&args).unwrap(), Str("apt".to_string()));
+    /// ```
+    pub fn apply(&self, args: &[ExpressionResult]) -> Result<ExpressionResult, Errored> {
+        match self {
+            Concat => {
+                let text: String = args
+                    .iter()
+                    .filter(|arg| **arg != Null)
+                    .map(|arg| arg.to_string())
+                    .collect();
+                return Ok(Str(text));
+            }
+            Now => return Ok(date::now()),
+            _ if args.contains(&Null) => return Ok(Null),
+            _ => {}
+        }
+        let text = args.first().map(|arg| arg.to_string()).unwrap_or_default();
+        let result = match self {
+            Upper => Str(text.to_uppercase()),
+            Lower => Str(text.to_lowercase()),
+            Length => Int(text.chars().count() as i64),
+            Trim => Str(text.trim().to_string()),
+            Substr => Str(substring(&text, &args[1], args.get(2))?),
+            Replace => {
+                let (from, to) = (args[1].to_string(), args[2].to_string());
+                if from.is_empty() {
+                    Str(text)
+                } else {
+                    Str(text.replace(&from, &to))
+                }
+            }
+            DateAdd => date::add(&args[0], interval(&args[1])?, &date_part(&args[2])?)?,
+            DateSub => {
+                let amount = interval(&args[1])?.checked_neg().unwrap_or(i64::MAX);
+                date::add(&args[0], amount, &date_part(&args[2])?)?
+            }
+            Extract => date::extract(&args[1], &date_part(&args[0])?)?,
+            DateTrunc => date::truncate(&args[1], &date_part(&args[0])?)?,
+            Concat | Now => Str(text),
+        };
+        Ok(result)
+    }
+}
+
+impl Function {
+    /// Crea la llamada a una función escalar, validando la cantidad de argumentos.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la función no recibe la cantidad de argumentos que espera.
+    pub fn new(kind: FunctionKind, arguments: Vec<ExpressionNode>) -> Result<Self, Errored> {
+        let (min, max) = kind.arity();
+        let count = arguments.len();
+        if count < min || max.is_some_and(|max| count > max) {
+            errored!(
+                Syntax,
+                "invalid number of arguments for {}: got ({}).",
+                kind.name(),
+                count
+            )
+        }
+        Ok(Function { kind, arguments })
+    }
+
+    /// Evalúa los argumentos de la función en el contexto de una fila y le aplica la función.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si falla la evaluación de algún argumento o la aplicación de la función.
+    pub fn evaluate(
+        &self,
+        values: &HashMap<String, ExpressionResult>,
+    ) -> Result<ExpressionResult, Errored> {
+        let args = self
+            .arguments
+            .iter()
+            .map(|arg| arg.evaluate(values))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.kind.apply(&args)
+    }
+}
+
+/// Obtiene la parte de una fecha indicada por un texto, como `'month'`.
+fn date_part(value: &ExpressionResult) -> Result<DatePart, Errored> {
+    match value {
+        Str(name) => {
+            DatePart::from_name(name).ok_or_else(|| Syntax(format!("unknown date part: {}", name)))
+        }
+        _ => errored!(Syntax, "date part must be a string, got: {}", value),
+    }
+}
+
+/// Obtiene la cantidad de un intervalo, que debe ser un entero.
+fn interval(value: &ExpressionResult) -> Result<i64, Errored> {
+    match value {
+        Int(amount) => Ok(*amount),
+        _ => errored!(Syntax, "interval must be an integer, got: {}", value),
+    }
+}
+
+/// Obtiene los caracteres de `text` a partir de la posición `start` (contando desde 1),
+/// tomando como máximo `length` caracteres si se indica.
+///
+/// Como en SQL, una posición menor a 1 consume parte del largo pedido sin agregar caracteres.
+fn substring(
+    text: &str,
+    start: &ExpressionResult,
+    length: Option<&ExpressionResult>,
+) -> Result<String, Errored> {
+    let start = match start {
+        Int(start) => *start,
+        _ => errored!(Syntax, "SUBSTR position must be an integer, got: {}", start),
+    };
+    let end = match length {
+        Some(Int(length)) if *length < 0 => {
+            errored!(Syntax, "SUBSTR length cannot be negative, got: {}", length)
+        }
+        Some(Int(length)) => Some(start.saturating_add(*length)),
+        Some(length) => errored!(Syntax, "SUBSTR length must be an integer, got: {}", length),
+        None => None,
+    };
+    let skip = (start.max(1) - 1) as usize;
+    let take = match end {
+        Some(end) => (end.max(1) - start.max(1)).max(0) as usize,
+        None => usize::MAX,
+    };
+    Ok(text.chars().skip(skip).take(take).collect())
+}
+
+impl Display for Function {
+    /// Representa la llamada tal cual se escribe en SQL, por ejemplo `SUBSTR(email, 1, 5)`
+    /// o `EXTRACT(YEAR FROM fecha)`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.kind, self.arguments.as_slice()) {
+            (Extract, [ExpressionNode::Constant(Str(part)), value]) => {
+                return write!(f, "EXTRACT({} FROM {})", part, value);
+            }
+            (DateAdd | DateSub, [value, amount, ExpressionNode::Constant(Str(part))]) => {
+                let name = self.kind.name();
+                return write!(f, "{}({}, INTERVAL {} {})", name, value, amount, part);
+            }
+            _ => {}
+        }
+        let arguments: Vec<String> = self.arguments.iter().map(|a| a.to_string()).collect();
+        write!(f, "{}({})", self.kind.name(), arguments.join(", "))
+    }
+}
+
+impl Debug for Function {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> ExpressionResult {
+        Str(value.to_string())
+    }
+
+    #[test]
+    fn test_case_functions() {
+        assert_eq!(Upper.apply(&[text("Laptop")]).unwrap(), text("LAPTOP"));
+        assert_eq!(Lower.apply(&[text("Laptop")]).unwrap(), text("laptop"));
+        assert_eq!(Upper.apply(&[Null]).unwrap(), Null);
+    }
+
+    #[test]
+    fn test_length_and_trim() {
+        assert_eq!(Length.apply(&[text("ñandú")]).unwrap(), Int(5));
+        assert_eq!(Length.apply(&[Int(1200)]).unwrap(), Int(4));
+        assert_eq!(Trim.apply(&[text("  Mouse ")]).unwrap(), text("Mouse"));
+    }
+
+    #[test]
+    fn test_substr() {
+        assert_eq!(
+            Substr.apply(&[text("Laptop"), Int(4)]).unwrap(),
+            text("top")
+        );
+        assert_eq!(
+            Substr.apply(&[text("Laptop"), Int(0), Int(3)]).unwrap(),
+            text("La")
+        );
+        assert_eq!(
+            Substr.apply(&[text("Laptop"), Int(10), Int(3)]).unwrap(),
+            text("")
+        );
+        assert!(Substr.apply(&[text("Laptop"), Int(1), Int(-1)]).is_err());
+        assert!(Substr.apply(&[text("Laptop"), text("1")]).is_err());
+    }
+
+    #[test]
+    fn test_concat_and_replace() {
+        assert_eq!(
+            Concat
+                .apply(&[text("Jane"), Null, text(" "), Int(3)])
+                .unwrap(),
+            text("Jane 3")
+        );
+        assert_eq!(
+            Replace
+                .apply(&[text("a@mail.com"), text("mail"), text("correo")])
+                .unwrap(),
+            text("a@correo.com")
+        );
+        assert_eq!(
+            Replace.apply(&[text("abc"), text(""), text("x")]).unwrap(),
+            text("abc")
+        );
+    }
+
+    #[test]
+    fn test_function_arity() {
+        assert!(Function::new(Upper, vec![]).is_err());
+        assert!(Function::new(Substr, vec![ExpressionNode::Empty]).is_err());
+        assert!(Function::new(Concat, vec![]).is_err());
+        assert!(Function::new(Trim, vec![ExpressionNode::Empty]).is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::join::JoinKind::Inner;
+use std::fmt::{Debug, Formatter};
+
+/// Estructura que representa la unión (JOIN) de una tabla adicional dentro de una consulta SELECT.
+///
+/// # Campos
+///
+/// * `kind` - El tipo de unión a realizar (interna o externa izquierda).
+/// * `table` - El nombre de la tabla que se une a la consulta.
+/// * `alias` - El alias con el que se refiere a la tabla (`JOIN ordenes o`), si lo tiene.
+/// * `conditions` - La condición (`ON`) que deben cumplir las filas para ser unidas.
+#[derive(PartialEq)]
+pub struct Join {
+    pub kind: JoinKind,
+    pub table: String,
+    pub alias: Option<String>,
+    pub conditions: ExpressionNode,
+}
+
+/// Enum que representa los tipos de unión posibles.
+///
+/// - `Inner`: Solo se conservan las filas que tienen al menos una coincidencia en ambas tablas.
+/// - `Left`: Se conservan todas las filas de la izquierda, completando con valores vacíos
+///   las columnas de la derecha en caso de no encontrar coincidencias.
+#[derive(Debug, PartialEq)]
+pub enum JoinKind {
+    Inner,
+    Left,
+}
+
+impl Join {
+    /// Obtiene el nombre con el que se califican las columnas de la tabla unida.
+    ///
+    /// # Retorna
+    ///
+    /// El alias de la tabla si la unión lo declara, o el nombre de la tabla en caso contrario.
+    pub fn qualifier(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.table)
+    }
+}
+
+impl Default for Join {
+    /// Devuelve un valor default para `Join`.
+    ///
+    /// El valor default es una unión interna sin tabla ni condiciones.
+    fn default() -> Self {
+        Self {
+            kind: Inner,
+            table: String::new(),
+            alias: None,
+            conditions: ExpressionNode::default(),
+        }
+    }
+}
+
+impl Debug for Join {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}:{}", &self.kind, &self.table)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " {}", alias)?;
+        }
+        write!(f, " ON {:?})", &self.conditions)
+    }
+}
@@ -1,7 +1,17 @@
+pub mod aggregate;
+pub mod alteration;
+pub mod arithmetic;
+pub mod common_table;
 pub mod comparator;
+pub mod compound;
+pub mod date;
 pub mod expression;
+pub mod function;
+pub mod join;
 pub mod operation;
 pub mod ordering;
 pub mod query;
 pub mod row;
+pub mod schema;
 pub mod token;
+pub mod window;
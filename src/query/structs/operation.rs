@@ -7,6 +7,9 @@
 /// - `Update`: Actualiza datos existentes en una tabla.
 /// - `Delete`: Elimina datos de una tabla.
 /// - `Insert`: Inserta nuevos datos en una tabla.
+/// - `Create`: Crea una nueva tabla.
+/// - `Drop`: Elimina una tabla existente.
+/// - `Alter`: Modifica las columnas de una tabla existente.
 #[derive(Debug, PartialEq)]
 pub enum Operation {
     Unknown,
@@ -14,4 +17,7 @@ pub enum Operation {
     Update,
     Delete,
     Insert,
+    Create,
+    Drop,
+    Alter,
 }
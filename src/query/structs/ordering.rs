@@ -10,7 +10,7 @@ use std::fmt::{Debug, Formatter};
 ///
 /// * `field` - El token que representa el campo por el cual se realizará el ordenamiento.
 /// * `kind` - El tipo de ordenamiento (ascendente o descendente).
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct Ordering {
     pub field: Token,
     pub kind: OrderKind,
@@ -22,7 +22,7 @@ pub struct Ordering {
 ///
 /// - `Asc`: Ordena los resultados de manera ascendente.
 /// - `Desc`: Ordena los resultados de manera descendente.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OrderKind {
     Asc,
     Desc,
@@ -1,47 +1,177 @@
 use crate::errored;
+use crate::query::builder::alter::AlterBuilder;
+use crate::query::builder::create::CreateBuilder;
 use crate::query::builder::delete::DeleteBuilder;
+use crate::query::builder::drop::DropBuilder;
+use crate::query::builder::expression::ExpressionBuilder;
 use crate::query::builder::insert::InsertBuilder;
 use crate::query::builder::select::SelectBuilder;
 use crate::query::builder::update::UpdateBuilder;
 use crate::query::builder::{get_kind, Builder};
+use crate::query::structs::aggregate::Aggregate;
+use crate::query::structs::alteration::Alteration;
+use crate::query::structs::common_table::CommonTable;
+use crate::query::structs::compound::{Compound, CompoundKind};
 use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::join::Join;
 use crate::query::structs::operation::Operation;
-use crate::query::structs::operation::Operation::{Delete, Insert, Select, Unknown, Update};
+use crate::query::structs::operation::Operation::{
+    Alter, Create, Delete, Drop, Insert, Select, Unknown, Update,
+};
 use crate::query::structs::ordering::Ordering;
+use crate::query::structs::schema::Schema;
 use crate::query::structs::token::Token;
+use crate::query::structs::token::TokenKind::{
+    Identifier, Keyword, ParenthesisClose, ParenthesisOpen,
+};
+use crate::query::structs::window::Window;
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Syntax;
 use std::collections::VecDeque;
 use std::fmt::{Debug, Display, Formatter};
+use std::mem;
 
 /// Estructura que representa una consulta dentro de RusticSQL.
 ///
 /// La consulta incluye la operación a realizar, la tabla, las columnas, los valores para insertar,
 /// las actualizaciones, las condiciones y el ordenamiento, algunos de estos campos pueden quedar
 /// con valores default en caso de no aplicar.
+#[derive(PartialEq)]
 pub struct Query {
     /// La operación que se debe realizar.
     pub operation: Operation,
+    /// Las tablas virtuales definidas con `WITH`, en el orden en que deben materializarse.
+    pub common_tables: Vec<CommonTable>,
     /// La tabla sobre la que se realiza la operación.
     pub table: String,
+    /// El alias de la tabla principal (`FROM usuarios u`), si la consulta lo declara.
+    pub table_alias: Option<String>,
+    /// Las tablas unidas a la tabla principal junto a sus condiciones de unión.
+    pub joins: Vec<Join>,
     /// Las columnas involucradas en la consulta.
     pub columns: Vec<Token>,
-    /// Los valores a insertar en caso de una operación de inserción.
-    pub inserts: Vec<Vec<Token>>,
+    /// Las expresiones calculadas de la proyección (como `precio * cantidad` o `'texto'`), cada una
+    /// proyectada como una columna nombrada con su representación SQL.
+    pub computed: Vec<ExpressionNode>,
+    /// Los alias de las columnas proyectadas (`SELECT nombre AS nombre_completo`), en el mismo
+    /// orden que `columns`. Las columnas sin alias se imprimen con su propio nombre.
+    pub aliases: Vec<Option<String>>,
+    /// Indica si deben descartarse los resultados repetidos de la proyección (`SELECT DISTINCT`).
+    pub distinct: bool,
+    /// Los valores a insertar en caso de una operación de inserción, una expresión constante
+    /// (o la palabra clave `DEFAULT`) por cada columna.
+    pub inserts: Vec<Vec<ExpressionNode>>,
+    /// La consulta SELECT cuyas filas se insertan (`INSERT INTO ... SELECT`), en lugar de
+    /// los valores de `inserts`.
+    pub source: Option<Box<Query>>,
     /// Las actualizaciones a realizar en caso de una operación de actualización.
     pub updates: Vec<ExpressionNode>,
     /// Las condiciones para filtrar los resultados de la consulta.
     pub conditions: ExpressionNode,
     /// El criterio de ordenamiento para los resultados.
     pub ordering: Vec<Ordering>,
+    /// Las consultas combinadas con los resultados de esta (`UNION`, `INTERSECT`, `EXCEPT`),
+    /// en el orden en que se aplican. Si hay alguna, el ordenamiento, `LIMIT` y `OFFSET` de
+    /// la consulta se aplican sobre el resultado combinado.
+    pub compounds: Vec<Compound>,
+    /// Las columnas por las que se agrupan los resultados.
+    pub group_by: Vec<Token>,
+    /// Las funciones de agregación que deben calcularse para cada grupo.
+    pub aggregates: Vec<Aggregate>,
+    /// Las funciones de ventana que deben calcularse para cada fila resultante.
+    pub windows: Vec<Window>,
+    /// Las condiciones para filtrar los grupos, evaluadas luego de calcular las agregaciones.
+    pub having: ExpressionNode,
+    /// La cantidad máxima de filas a devolver, si la consulta tiene `LIMIT`.
+    pub limit: Option<usize>,
+    /// La cantidad de filas a omitir antes de empezar a devolver resultados.
+    pub offset: usize,
+    /// Las columnas y sus tipos en caso de una operación de creación de tabla.
+    pub schema: Schema,
+    /// Indica si la operación no debe fallar cuando la tabla ya existe (`CREATE TABLE IF NOT EXISTS`)
+    /// o cuando no existe (`DROP TABLE IF EXISTS`).
+    pub if_exists: bool,
+    /// La modificación a realizar en caso de una operación de alteración de tabla.
+    pub alteration: Option<Alteration>,
 }
 
 impl Query {
+    /// Indica si los resultados de la consulta deben agruparse.
+    ///
+    /// Una consulta se agrupa si tiene una cláusula `GROUP BY`, una cláusula `HAVING`
+    /// o si utiliza funciones de agregación.
+    pub fn is_grouped(&self) -> bool {
+        !self.group_by.is_empty()
+            || !self.aggregates.is_empty()
+            || self.having != ExpressionNode::Empty
+    }
+
+    /// Obtiene el nombre con el que se califican las columnas de la tabla principal.
+    ///
+    /// # Retorna
+    ///
+    /// El alias de la tabla si la consulta lo declara, o el nombre de la tabla en caso contrario.
+    pub fn qualifier(&self) -> &str {
+        self.table_alias.as_deref().unwrap_or(&self.table)
+    }
+
+    /// Obtiene los nombres con los que pueden calificarse las columnas de la consulta: el de la
+    /// tabla principal y su alias, y el de cada tabla unida.
+    pub fn qualifiers(&self) -> Vec<&str> {
+        let mut qualifiers = vec![self.table.as_str()];
+        qualifiers.extend(self.table_alias.as_deref());
+        qualifiers.extend(self.joins.iter().map(|join| join.qualifier()));
+        qualifiers
+    }
+
+    /// Obtiene todas las columnas referenciadas por la consulta: las proyectadas y las de sus
+    /// expresiones calculadas, condiciones, uniones, agrupamiento, agregaciones, funciones de
+    /// ventana, ordenamiento y actualizaciones.
+    ///
+    /// Las columnas proyectadas que nombran a una agregación, función de ventana o expresión
+    /// calculada no se incluyen, sino las columnas sobre las que operan. Tampoco se incluyen las
+    /// columnas de las sub-consultas, que se resuelven contra sus propias tablas.
+    pub fn identifiers(&self) -> Vec<&Token> {
+        let derived: Vec<String> = self
+            .aggregates
+            .iter()
+            .map(|a| a.to_string())
+            .chain(self.windows.iter().map(|w| w.to_string()))
+            .chain(self.computed.iter().map(|e| e.to_string()))
+            .collect();
+        let expressions = self
+            .computed
+            .iter()
+            .chain(self.updates.iter())
+            .chain([&self.conditions, &self.having])
+            .chain(self.joins.iter().map(|join| &join.conditions));
+        self.columns
+            .iter()
+            .filter(|c| c.kind == Identifier && !derived.contains(&c.value))
+            .chain(expressions.flat_map(|e| e.identifiers()))
+            .chain(&self.group_by)
+            .chain(self.aggregates.iter().map(|a| &a.field))
+            .chain(self.windows.iter().flat_map(|w| w.columns()))
+            .chain(self.ordering.iter().map(|o| &o.field))
+            .filter(|c| c.value != "*")
+            .collect()
+    }
+
     /// Crea una nueva consulta a partir de una lista de tokens.
     ///
     /// La función intenta identificar el tipo de operación
     /// y construir la consulta correspondiente usando el builder adecuado.
     ///
+    /// Si la consulta combina varios SELECT con operaciones de conjuntos (`UNION`, `UNION ALL`,
+    /// `INTERSECT`, `EXCEPT`), cada uno se construye por separado y los siguientes al primero
+    /// se guardan en `compounds`. El `ORDER BY`, `LIMIT` y `OFFSET` del último SELECT pasan a
+    /// la primera consulta, ya que se aplican sobre el resultado combinado.
+    ///
+    /// Las operaciones de conjuntos de un `INSERT INTO ... SELECT` pertenecen al SELECT cuyas
+    /// filas se insertan, por lo que se separan al construir ese SELECT (ver `InsertBuilder`).
+    ///
+    /// Si la consulta comienza con `WITH`, sus tablas virtuales se guardan en `common_tables`.
+    ///
     /// # Parámetros
     ///
     /// * `tokens` - La lista de tokens obtenida de tokenizar un string que representaba la consulta.
@@ -51,6 +181,27 @@ impl Query {
     /// Un `Result` que contiene la consulta construida o un error en caso de que la consulta no sea
     /// válida.
     pub fn from(tokens: Vec<Token>) -> Result<Self, Errored> {
+        let mut tokens = VecDeque::from(tokens);
+        let common_tables = parse_common_tables(&mut tokens)?;
+        let (tokens, compounds) = match tokens.front() {
+            Some(t) if t.kind == Keyword && t.value == "INSERT INTO" => (tokens.into(), vec![]),
+            _ => split_compounds(tokens.into()),
+        };
+        let mut query = Query::build(tokens)?;
+        if !compounds.is_empty() {
+            query = query.with_compounds(compounds)?;
+        }
+        if !common_tables.is_empty() {
+            if query.operation != Select {
+                errored!(Syntax, "WITH can only be followed by a SELECT query.")
+            }
+            query.common_tables = common_tables;
+        }
+        Ok(query)
+    }
+
+    /// Construye una consulta simple a partir de sus tokens, con el builder de su operación.
+    fn build(tokens: Vec<Token>) -> Result<Self, Errored> {
         let mut tokens = VecDeque::from(tokens);
         let kind = get_kind(tokens.pop_front());
         match kind {
@@ -59,21 +210,178 @@ impl Query {
             Update => UpdateBuilder::new(tokens).build(),
             Delete => DeleteBuilder::new(tokens).build(),
             Insert => InsertBuilder::new(tokens).build(),
+            Create => CreateBuilder::new(tokens).build(),
+            Drop => DropBuilder::new(tokens).build(),
+            Alter => AlterBuilder::new(tokens).build(),
+        }
+    }
+
+    /// Construye las consultas que se combinan con esta y las agrega a `compounds`.
+    ///
+    /// El ordenamiento, `LIMIT` y `OFFSET` del último SELECT se mueven a esta consulta, ya que
+    /// se aplican sobre el resultado combinado. Los alias de columnas del ordenamiento se
+    /// conservan, porque el resultado combinado se nombra con los de la primera consulta.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna consulta no es un SELECT, si alguna consulta salvo la
+    /// última tiene ordenamiento, `LIMIT` u `OFFSET`, o si alguna no puede construirse.
+    fn with_compounds(
+        mut self,
+        compounds: Vec<(CompoundKind, Vec<Token>)>,
+    ) -> Result<Self, Errored> {
+        for (kind, tokens) in compounds {
+            let query = Query::build(tokens)?;
+            self.compounds.push(Compound { kind, query });
+        }
+        let queries = std::iter::once(&self).chain(self.compounds.iter().map(|c| &c.query));
+        let last = self.compounds.len();
+        for (i, query) in queries.enumerate() {
+            if query.operation != Select {
+                errored!(Syntax, "only SELECT queries can be combined.")
+            }
+            let paginated = query.limit.is_some() || query.offset > 0;
+            if i < last && (!query.ordering.is_empty() || paginated) {
+                errored!(
+                    Syntax,
+                    "ORDER BY, LIMIT and OFFSET are only allowed after the last combined query."
+                )
+            }
+        }
+        if let Some(compound) = self.compounds.last_mut() {
+            let last = &mut compound.query;
+            for order in &mut last.ordering {
+                let position = last.columns.iter().position(|c| c == &order.field);
+                if let Some(Some(alias)) = position.and_then(|i| last.aliases.get(i)) {
+                    order.field.value = alias.to_string();
+                }
+            }
+            self.ordering = mem::take(&mut last.ordering);
+            self.limit = last.limit.take();
+            self.offset = mem::take(&mut last.offset);
         }
+        Ok(self)
     }
 }
 
+/// Analiza las tablas virtuales de la cláusula `WITH` al comienzo de una consulta, como
+/// `WITH mayores AS (SELECT ...), compras AS (SELECT ...)`.
+///
+/// Cada tabla virtual se construye como una sub-consulta (ver `ExpressionBuilder::parse_subquery`),
+/// y los tokens de la consulta principal quedan en `tokens`.
+///
+/// # Retorna
+///
+/// Las tablas virtuales de la consulta, o un vector vacío si no comienza con `WITH`.
+///
+/// # Errores
+///
+/// Retorna un error si alguna tabla virtual no tiene nombre, `AS` o una consulta SELECT entre
+/// paréntesis, o si su nombre está repetido.
+fn parse_common_tables(tokens: &mut VecDeque<Token>) -> Result<Vec<CommonTable>, Errored> {
+    let mut common_tables: Vec<CommonTable> = vec![];
+    if !tokens
+        .front()
+        .is_some_and(|t| t.kind == Keyword && t.value == "WITH")
+    {
+        return Ok(common_tables);
+    }
+    tokens.pop_front();
+    while common_tables.is_empty() || tokens.front().is_some_and(|t| t.kind == Identifier) {
+        let name = match tokens.pop_front() {
+            Some(t) if t.kind == Identifier => t.value,
+            t => errored!(Syntax, "expected table name in WITH clause, got: {:?}", t),
+        };
+        match tokens.pop_front() {
+            Some(t) if t.kind == Keyword && t.value == "AS" => {}
+            t => errored!(
+                Syntax,
+                "expected AS after {} in WITH clause, got: {:?}",
+                name,
+                t
+            ),
+        }
+        let query = ExpressionBuilder::parse_subquery(tokens)?;
+        if query.operation != Select {
+            errored!(
+                Syntax,
+                "table {} in WITH clause must be a SELECT query.",
+                name
+            )
+        }
+        if common_tables.iter().any(|table| table.name == name) {
+            errored!(
+                Syntax,
+                "table {} is defined more than once in WITH clause.",
+                name
+            )
+        }
+        common_tables.push(CommonTable { name, query });
+    }
+    Ok(common_tables)
+}
+
+/// Separa los tokens de una consulta en cada uno de los SELECT que combina mediante
+/// operaciones de conjuntos.
+///
+/// Solo se consideran las operaciones fuera de paréntesis, las de las sub-consultas
+/// quedan dentro de los tokens de la consulta que las contiene.
+///
+/// # Retorna
+///
+/// Los tokens de la primera consulta, y la operación y los tokens de cada una de las siguientes.
+fn split_compounds(tokens: Vec<Token>) -> (Vec<Token>, Vec<(CompoundKind, Vec<Token>)>) {
+    let mut first = vec![];
+    let mut compounds: Vec<(CompoundKind, Vec<Token>)> = vec![];
+    let mut depth = 0;
+    for token in tokens {
+        match token.kind {
+            ParenthesisOpen => depth += 1,
+            ParenthesisClose => depth -= 1,
+            Keyword if depth == 0 => {
+                if let Some(kind) = CompoundKind::from_keyword(&token.value) {
+                    compounds.push((kind, vec![]));
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        match compounds.last_mut() {
+            Some((_, tokens)) => tokens.push(token),
+            None => first.push(token),
+        }
+    }
+    (first, compounds)
+}
+
 impl Default for Query {
     /// Devuelve un valor default para `Query`.
     fn default() -> Self {
         Self {
             operation: Unknown,
+            common_tables: vec![],
             table: "".to_string(),
+            table_alias: None,
+            joins: vec![],
             columns: vec![],
+            computed: vec![],
+            aliases: vec![],
             inserts: vec![],
+            source: None,
             updates: vec![],
             conditions: ExpressionNode::default(),
             ordering: vec![],
+            compounds: vec![],
+            group_by: vec![],
+            aggregates: vec![],
+            windows: vec![],
+            having: ExpressionNode::default(),
+            distinct: false,
+            limit: None,
+            offset: 0,
+            schema: Schema::default(),
+            if_exists: false,
+            alteration: None,
         }
     }
 }
@@ -82,24 +390,40 @@ impl Display for Query {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let fields: Vec<&str> = self.columns.iter().map(|f| f.value.as_str()).collect();
         writeln!(f, "Tipo de Consulta: [{:?}]", self.operation)?;
-        writeln!(f, "Tabla: {:?}", self.table)?;
+        writeln!(f, "Tablas Virtuales: {:?}", self.common_tables)?;
+        writeln!(f, "Tabla: {:?} (alias {:?})", self.table, self.table_alias)?;
+        writeln!(f, "Uniones: {:?}", self.joins)?;
         writeln!(f, "Columnas: {:?}", fields)?;
+        writeln!(f, "Columnas Calculadas: {:?}", self.computed)?;
+        writeln!(f, "Alias: {:?}", self.aliases)?;
         writeln!(f, "Inserts {{ ")?;
         for insert in &self.inserts {
-            let values: Vec<&String> = insert.iter().map(|t| &t.value).collect();
+            let values: Vec<String> = insert.iter().map(|v| v.to_string()).collect();
             writeln!(f, "   {:?}", values)?;
         }
         writeln!(f, "}} ")?;
+        if let Some(source) = &self.source {
+            writeln!(f, "Origen de Inserts: {:?}", source.table)?;
+        }
         writeln!(f, "Actualizaciones {{ ")?;
         for up in &self.updates {
-            if let Ok((l, r)) = up.as_leaf_tuple() {
-                writeln!(f, "   {} -> {}", l.value, r.value)?;
-            }
+            writeln!(f, "   {}", up)?;
         }
         writeln!(f, "}} ")?;
         writeln!(f, "Actualizaciones: {:?}", self.updates)?;
         writeln!(f, "Condiciones: {:?}", self.conditions)?;
-        writeln!(f, "Ordenamiento: {:?}", self.ordering)
+        let groups: Vec<&str> = self.group_by.iter().map(|g| g.value.as_str()).collect();
+        writeln!(f, "Agrupamiento: {:?}", groups)?;
+        writeln!(f, "Agregaciones: {:?}", self.aggregates)?;
+        writeln!(f, "Funciones de Ventana: {:?}", self.windows)?;
+        writeln!(f, "Condiciones de Grupo: {:?}", self.having)?;
+        writeln!(f, "Ordenamiento: {:?}", self.ordering)?;
+        writeln!(f, "Combinaciones: {:?}", self.compounds)?;
+        writeln!(f, "Límite: {:?} (desde {})", self.limit, self.offset)?;
+        writeln!(f, "Distintos: {}", self.distinct)?;
+        writeln!(f, "Esquema: {:?}", self.schema)?;
+        writeln!(f, "Condicional de Existencia: {}", self.if_exists)?;
+        writeln!(f, "Alteración: {:?}", self.alteration)
     }
 }
 
@@ -111,10 +435,16 @@ impl Debug for Query {
 
 #[cfg(test)]
 mod test {
+    use crate::query::structs::compound::CompoundKind::{Except, UnionAll};
     use crate::query::structs::query::Query;
     use crate::query::structs::token::Token;
+    use crate::query::tokenizer::Tokenizer;
     use crate::utils::errors::Errored;
 
+    fn query(sql: &str) -> Result<Query, Errored> {
+        Query::from(Tokenizer::new().tokenize(sql)?)
+    }
+
     #[test]
     fn test_invalid_query() {
         let tokens = vec![Token::default()];
@@ -128,4 +458,102 @@ mod test {
             _ => panic!("se esperaba un error de sintaxis para el primer token de la consulta."),
         }
     }
+
+    #[test]
+    fn test_compound_query() {
+        let query = query(
+            "SELECT id AS codigo FROM enero UNION ALL SELECT id FROM febrero \
+            WHERE id IN (SELECT id FROM marzo EXCEPT SELECT id FROM abril) \
+            EXCEPT SELECT id AS codigo FROM bajas ORDER BY codigo DESC LIMIT 3 OFFSET 1",
+        )
+        .unwrap();
+
+        assert_eq!(query.table, "enero");
+        assert_eq!(query.compounds.len(), 2);
+        assert_eq!(query.compounds[0].kind, UnionAll);
+        assert_eq!(query.compounds[0].query.table, "febrero");
+        assert_eq!(query.compounds[1].kind, Except);
+        assert_eq!(query.compounds[1].query.table, "bajas");
+        assert_eq!(query.ordering.len(), 1);
+        assert_eq!(query.ordering[0].field.value, "codigo");
+        assert_eq!((query.limit, query.offset), (Some(3), 1));
+        assert!(query.compounds[1].query.ordering.is_empty());
+        assert_eq!(query.compounds[1].query.limit, None);
+    }
+
+    #[test]
+    fn test_invalid_compound_query() {
+        let invalid = [
+            "SELECT id FROM enero ORDER BY id UNION SELECT id FROM febrero",
+            "SELECT id FROM enero LIMIT 1 INTERSECT SELECT id FROM febrero",
+            "SELECT id FROM enero UNION DELETE FROM febrero",
+            "SELECT id FROM enero UNION",
+        ];
+        for sql in invalid {
+            assert!(query(sql).is_err(), "debería fallar: {}", sql);
+        }
+    }
+
+    #[test]
+    fn test_query_with_common_tables() {
+        let query = query(
+            "WITH mayores AS (SELECT id FROM clientes WHERE edad > 60), \
+            compras AS (SELECT id_cliente FROM ordenes WHERE id_cliente IN (SELECT id FROM mayores)) \
+            SELECT * FROM compras UNION SELECT id FROM mayores",
+        )
+        .unwrap();
+
+        assert_eq!(query.common_tables.len(), 2);
+        assert_eq!(query.common_tables[0].name, "mayores");
+        assert_eq!(query.common_tables[0].query.table, "clientes");
+        assert_eq!(query.common_tables[1].name, "compras");
+        assert_eq!(query.common_tables[1].query.table, "ordenes");
+        assert_eq!(query.table, "compras");
+        assert_eq!(query.compounds.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_query_with_common_tables() {
+        let invalid = [
+            "WITH SELECT * FROM clientes",
+            "WITH mayores (SELECT id FROM clientes) SELECT * FROM mayores",
+            "WITH mayores AS SELECT id FROM clientes",
+            "WITH mayores AS (SELECT id FROM clientes SELECT * FROM mayores",
+            "WITH a AS (SELECT id FROM clientes) a AS (SELECT id FROM clientes) SELECT * FROM a",
+            "WITH a AS (SELECT id FROM clientes) DELETE FROM a",
+        ];
+        for sql in invalid {
+            assert!(query(sql).is_err(), "debería fallar: {}", sql);
+        }
+    }
+
+    #[test]
+    fn test_query_identifiers_and_qualifiers() {
+        let query = query(
+            "SELECT c.nombre, COUNT(o.id), edad * 2 FROM clientes c JOIN ordenes o ON c.id = o.id_cliente \
+            WHERE EXISTS (SELECT 1 FROM otras WHERE otras.id = 1) AND c.edad > 3 \
+            GROUP BY c.nombre ORDER BY c.nombre",
+        )
+        .unwrap();
+
+        let identifiers: Vec<&str> = query
+            .identifiers()
+            .iter()
+            .map(|t| t.value.as_str())
+            .collect();
+        assert_eq!(
+            identifiers,
+            vec![
+                "c.nombre",
+                "edad",
+                "c.edad",
+                "c.id",
+                "o.id_cliente",
+                "c.nombre",
+                "o.id",
+                "c.nombre"
+            ]
+        );
+        assert_eq!(query.qualifiers(), vec!["clientes", "c", "o"]);
+    }
 }
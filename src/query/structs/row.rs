@@ -1,15 +1,22 @@
 use crate::errored;
-use crate::query::structs::expression::{ExpressionNode, ExpressionResult};
+use crate::query::structs::expression::{ExpressionNode, ExpressionOperator, ExpressionResult};
 use crate::query::structs::query::Query;
+use crate::query::structs::schema::{ColumnType, Schema};
 use crate::query::structs::token::Token;
+use crate::query::structs::token::TokenKind::Identifier;
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::{Column, Default, Syntax, Table};
+use crate::utils::files::{escape_csv_value, CsvField};
 use std::collections::HashMap;
 
 /// Representa una fila en una tabla, con un encabezado y valores asociados.
+///
+/// Los valores se guardan ya interpretados según el tipo de su columna: si la tabla tiene
+/// un esquema se usa el tipo declarado, y si no, el tipo se infiere a partir de cada valor.
 pub struct Row<'a> {
     pub header: &'a Vec<String>,
-    pub values: HashMap<String, String>,
+    pub schema: Option<&'a Schema>,
+    pub values: HashMap<String, ExpressionResult>,
 }
 
 impl<'a> Row<'a> {
@@ -23,22 +30,46 @@ impl<'a> Row<'a> {
     /// let row = Row::new(&header);
     /// ```
     pub fn new(header: &'a Vec<String>) -> Self {
+        Self::with_schema(header, None)
+    }
+
+    /// Crea una nueva instancia de `Row` cuyos valores se interpretan según el esquema de la tabla.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::Str;
+    /// use rustic_sql::query::structs::row::Row;
+    /// use rustic_sql::query::structs::schema::Schema;
+    /// let header = vec!["id".to_string(), "zip".to_string()];
+    /// let schema = Schema::parse("zip TEXT").unwrap();
+    /// let mut row = Row::with_schema(&header, Some(&schema));
+    /// row.set("zip", "01234".to_string()).unwrap();
+    /// assert_eq!(row.values["zip"], Str("01234".to_string()));
+    /// ```
+    pub fn with_schema(header: &'a Vec<String>, schema: Option<&'a Schema>) -> Self {
         Self {
             header,
+            schema,
             values: HashMap::new(),
         }
     }
 
     /// Establece un valor para una columna en la fila.
     ///
+    /// El valor crudo se interpreta según el tipo declarado de la columna en el esquema,
+    /// o se infiere su tipo si la columna no lo declara. Si el valor coincide con la
+    /// representación de `NULL` de la tabla (ver `Schema::null_marker`), se guarda `Null`.
+    ///
     /// # Parámetros
     ///
     /// - `key`: El nombre de la columna.
-    /// - `value`: El valor a asignar.
+    /// - `value`: El valor crudo a asignar.
     ///
     /// # Errores
     ///
-    /// Devuelve un error si la columna no existe en el encabezado.
+    /// Devuelve un error si la columna no existe en el encabezado o si el valor no es válido
+    /// para el tipo declarado de la columna.
     ///
     /// # Ejemplo
     ///
@@ -49,6 +80,62 @@ impl<'a> Row<'a> {
     /// row.set("id", "123".to_string()).unwrap();
     /// ```
     pub fn set(&mut self, key: &str, value: String) -> Result<(), Errored> {
+        self.set_field(
+            key,
+            CsvField {
+                value,
+                quoted: false,
+            },
+        )
+    }
+
+    /// Establece el valor de una columna a partir de un campo leído de la tabla.
+    ///
+    /// Funciona igual que `set`, salvo que un campo entre comillas nunca se interpreta como
+    /// `NULL` (ver `CsvField`).
+    ///
+    /// # Errores
+    ///
+    /// Devuelve un error si la columna no existe en el encabezado o si el valor no es válido
+    /// para el tipo declarado de la columna.
+    pub fn set_field(&mut self, key: &str, field: CsvField) -> Result<(), Errored> {
+        let value = match self.schema {
+            _ if field.is_null(self.null_marker()) => ExpressionResult::Null,
+            Some(schema) => schema.parse_value(key, &field.value)?,
+            None => ColumnType::infer(&field.value),
+        };
+        self.set_value(key, value)
+    }
+
+    /// Establece el resultado de una expresión como valor de una columna en la fila.
+    ///
+    /// A diferencia de `set_value`, el resultado se convierte al tipo declarado de la
+    /// columna (ver `Schema::coerce_value`). Si la columna no declara un tipo, el resultado
+    /// se guarda tal cual, sin volver a inferir su tipo.
+    ///
+    /// # Errores
+    ///
+    /// Devuelve un error si la columna no existe en el encabezado o si el valor no es válido
+    /// para el tipo declarado de la columna.
+    pub fn set_result(&mut self, key: &str, value: ExpressionResult) -> Result<(), Errored> {
+        let value = match self.schema {
+            Some(schema) => schema.coerce_value(key, value)?,
+            None => value,
+        };
+        self.set_value(key, value)
+    }
+
+    /// Obtiene la representación de `NULL` en las celdas de la tabla de la fila.
+    pub fn null_marker(&self) -> &str {
+        self.schema.map(|s| s.null_marker()).unwrap_or_default()
+    }
+
+    /// Establece un valor ya interpretado para una columna en la fila.
+    ///
+    /// # Errores
+    ///
+    /// Devuelve un error si la columna no existe en el encabezado.
+    pub fn set_value(&mut self, key: &str, value: ExpressionResult) -> Result<(), Errored> {
         if self.header.contains(&key.to_string()) {
             self.values.insert(key.to_string(), value);
         } else {
@@ -62,7 +149,7 @@ impl<'a> Row<'a> {
         Ok(())
     }
 
-    /// Limpia los valores de la fila, estableciendo cada columna con un string vacío.
+    /// Limpia los valores de la fila, estableciendo cada columna en `NULL`.
     ///
     /// # Ejemplo
     ///
@@ -75,53 +162,82 @@ impl<'a> Row<'a> {
     /// ```
     pub fn clear(&mut self) -> Result<(), Errored> {
         for key in self.header {
-            self.set(key, "".to_string())?
+            self.set_value(key, ExpressionResult::Null)?
         }
         Ok(())
     }
 
     /// Aplica una lista de actualizaciones a la fila.
     ///
+    /// Cada actualización asigna a una columna el resultado de evaluar una expresión,
+    /// como en `cantidad = cantidad + 1`. Todas las expresiones se evalúan con los valores
+    /// que tenía la fila antes de aplicar cualquiera de las actualizaciones.
+    ///
     /// # Parámetros
     ///
     /// - `updates`: Lista de expresiones que representan las actualizaciones.
     ///
     /// # Errores
     ///
-    /// Devuelve un error si ocurre un problema al aplicar las actualizaciones.
+    /// Devuelve un error si alguna actualización no asigna a una columna, si falla la
+    /// evaluación de su expresión o si el resultado no es válido para la columna.
     ///
     /// # Ejemplo
     ///
     /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::Int;
     /// use rustic_sql::query::structs::expression::{ExpressionNode, ExpressionOperator};
     /// use rustic_sql::query::structs::row::Row;
     /// use rustic_sql::query::structs::token::Token;
-    /// use rustic_sql::query::structs::token::TokenKind::{Identifier, String};
-    /// let header = vec!["id".to_string(), "apellido".to_string()];
+    /// use rustic_sql::query::structs::token::TokenKind::{Identifier, Number};
+    /// let header = vec!["id".to_string(), "cantidad".to_string()];
     /// let mut row = Row::new(&header);
+    /// row.read_new_row(vec!["1".to_string(), "4".to_string()]).unwrap();
     /// let update = ExpressionNode::Statement {
     ///     operator: ExpressionOperator::Equals,
     ///     left: Box::new(ExpressionNode::Leaf(Token {
     ///         kind: Identifier,
-    ///         value: "id".to_string(),
-    ///     })),
-    ///     right: Box::new(ExpressionNode::Leaf(Token {
-    ///         kind: String,
-    ///         value: "360".to_string(),
+    ///         value: "cantidad".to_string(),
     ///     })),
+    ///     right: Box::new(ExpressionNode::Statement {
+    ///         operator: ExpressionOperator::Add,
+    ///         left: Box::new(ExpressionNode::Leaf(Token {
+    ///             kind: Identifier,
+    ///             value: "cantidad".to_string(),
+    ///         })),
+    ///         right: Box::new(ExpressionNode::Leaf(Token {
+    ///             kind: Number,
+    ///             value: "1".to_string(),
+    ///         })),
+    ///     }),
     /// };
     /// row.apply_updates(&vec![update]).unwrap();
+    /// assert_eq!(row.values["cantidad"], Int(5));
     /// ```
     pub fn apply_updates(&mut self, updates: &Vec<ExpressionNode>) -> Result<(), Errored> {
+        let mut assignments = vec![];
         for up in updates {
-            if let Ok((field, value)) = up.as_leaf_tuple() {
-                let k = &field.value;
-                let v = &value.value;
-                self.set(k, v.to_string())?
-            } else {
-                errored!(Default, "error while updating values.")
+            match up {
+                ExpressionNode::Statement {
+                    operator: ExpressionOperator::Equals,
+                    left,
+                    right,
+                } => match &**left {
+                    ExpressionNode::Leaf(field) if field.kind == Identifier => {
+                        assignments.push((&field.value, right.evaluate(&self.values)?))
+                    }
+                    _ => errored!(
+                        Default,
+                        "error while updating values, expected column but got: {:?}",
+                        left
+                    ),
+                },
+                _ => errored!(Default, "error while updating values."),
             }
         }
+        for (key, value) in assignments {
+            self.set_result(key, value)?
+        }
         Ok(())
     }
 
@@ -146,16 +262,33 @@ impl<'a> Row<'a> {
     /// row.read_new_row(values).unwrap();
     /// ```
     pub fn read_new_row(&mut self, values: Vec<String>) -> Result<(), Errored> {
-        if self.header.len() != values.len() {
+        let fields = values
+            .into_iter()
+            .map(|value| CsvField {
+                value,
+                quoted: false,
+            })
+            .collect();
+        self.read_fields(fields)
+    }
+
+    /// Lee una nueva fila a partir de los campos de un registro de la tabla (ver `split_csv_fields`).
+    ///
+    /// # Errores
+    ///
+    /// Devuelve un error si el número de campos no coincide con el número de columnas, o si
+    /// algún valor no es válido para su columna.
+    pub fn read_fields(&mut self, fields: Vec<CsvField>) -> Result<(), Errored> {
+        if self.header.len() != fields.len() {
             errored!(
                 Table,
                 "new row has ({}) fields but table needs ({}).",
-                values.len(),
+                fields.len(),
                 self.header.len()
             );
         }
-        for (key, value) in self.header.iter().zip(values) {
-            self.set(key, value)?;
+        for (key, field) in self.header.iter().zip(fields) {
+            self.set_field(key, field)?;
         }
         Ok(())
     }
@@ -174,6 +307,7 @@ impl<'a> Row<'a> {
     /// # Ejemplo
     ///
     /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::{Int, Str};
     /// use rustic_sql::query::structs::row::Row;
     /// use rustic_sql::query::structs::token::Token;
     /// use rustic_sql::query::structs::token::TokenKind::Identifier;
@@ -189,18 +323,27 @@ impl<'a> Row<'a> {
     ///         value: "apellido".to_string(),
     ///     },
     /// ];
-    /// let values = vec!["360".to_string(), "katta".to_string()];
+    /// let values = vec![Int(360), Str("katta".to_string())];
     /// row.insert_values(&columns, values).unwrap();
     /// ```
-    pub fn insert_values(&mut self, columns: &[Token], values: Vec<String>) -> Result<(), Errored> {
+    pub fn insert_values(
+        &mut self,
+        columns: &[Token],
+        values: Vec<ExpressionResult>,
+    ) -> Result<(), Errored> {
         for (col, value) in columns.iter().zip(values) {
-            self.set(&col.value, value)?
+            self.set_result(&col.value, value)?
         }
         Ok(())
     }
 
     /// Convierte la fila en un string CSV con campos específicos.
     ///
+    /// Los valores que lo necesiten se escriben entre comillas (ver `escape_csv`), por lo que
+    /// el resultado puede volver a leerse con `split_csv` sin perder información. Los valores
+    /// `NULL` se escriben con la representación de `NULL` de la tabla, y los demás valores
+    /// que coincidan con ella se escriben entre comillas (ver `escape_csv_value`).
+    ///
     /// # Parámetros
     ///
     /// - `fields`: Lista de campos a incluir en la proyección CSV.
@@ -217,10 +360,19 @@ impl<'a> Row<'a> {
     /// assert_eq!(csv_string, "360,katta");
     /// ```
     pub fn as_csv_projection(&self, fields: &Vec<String>) -> String {
-        let mut projection: Vec<&str> = Vec::new();
+        let mut projection: Vec<String> = Vec::new();
         for key in fields {
-            let value = self.values.get(key).map(|v| v.as_str()).unwrap_or("");
-            projection.push(value);
+            let value = match self.values.get(key) {
+                Some(v) => Some(v),
+                None => resolve_column(self.values.keys(), key)
+                    .ok()
+                    .flatten()
+                    .and_then(|k| self.values.get(k)),
+            };
+            match value {
+                Some(ExpressionResult::Null) | None => projection.push(self.null_marker().into()),
+                Some(v) => projection.push(escape_csv_value(&v.to_string(), self.null_marker())),
+            }
         }
         projection.join(",")
     }
@@ -314,19 +466,87 @@ impl<'a> Row<'a> {
     /// assert!(row.matches_condition(&query).unwrap());
     /// ```
     pub fn matches_condition(&self, query: &Query) -> Result<bool, Errored> {
-        match query.conditions.evaluate(&self.values)? {
+        self.matches_expression(&query.conditions)
+    }
+
+    /// Verifica si la fila cumple con una expresión booleana cualquiera.
+    ///
+    /// Es utilizado tanto para las condiciones de la consulta como para las condiciones
+    /// de unión (`ON`) entre tablas.
+    ///
+    /// Si la expresión resulta en `NULL` (desconocido), la fila no la cumple.
+    ///
+    /// # Errores
+    ///
+    /// Devuelve un error si la evaluación de la expresión no resulta en un valor booleano.
+    pub fn matches_expression(&self, expression: &ExpressionNode) -> Result<bool, Errored> {
+        match expression.evaluate(&self.values)? {
             ExpressionResult::Bool(b) => Ok(b),
+            ExpressionResult::Null => Ok(false),
             _ => errored!(Syntax, "query condition evaluates to non-boolean value."),
         }
     }
 }
 
+/// Resuelve el nombre de una columna contra un conjunto de columnas conocidas.
+///
+/// Las columnas pueden estar calificadas con el nombre de su tabla (`tabla.columna`),
+/// esto sucede cuando la consulta une varias tablas. La resolución sigue estas reglas:
+///
+/// - Si el nombre coincide exactamente con una columna, se usa esa columna.
+/// - Si el nombre no está calificado, se busca una única columna calificada que termine en él.
+/// - Si el nombre está calificado, se busca la columna sin calificar (consultas de una sola tabla).
+///   El calificador no se compara acá, ya que las columnas no lo incluyen: el ejecutor valida
+///   antes de recorrer la tabla que corresponda a la tabla de la consulta o a su alias.
+///
+/// # Retorna
+///
+/// La columna encontrada, o `None` si ninguna coincide.
+///
+/// # Errores
+///
+/// Devuelve un error si el nombre sin calificar es ambiguo entre varias tablas.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::query::structs::row::resolve_column;
+/// let header = vec!["users.id".to_string(), "ordenes.id_cliente".to_string()];
+/// let found = resolve_column(&header, "id_cliente").unwrap();
+/// assert_eq!(found, Some(&"ordenes.id_cliente".to_string()));
+/// ```
+pub fn resolve_column<'k, I>(columns: I, name: &str) -> Result<Option<&'k String>, Errored>
+where
+    I: IntoIterator<Item = &'k String>,
+{
+    let qualified = name.split_once('.');
+    let mut found: Option<&String> = None;
+    for column in columns {
+        if column == name {
+            return Ok(Some(column));
+        }
+        let matches = match (qualified, column.split_once('.')) {
+            (None, Some((_, field))) => field == name,
+            (Some((_, field)), None) => column == field,
+            _ => false,
+        };
+        if matches {
+            if found.is_some() {
+                errored!(Column, "column {} is ambiguous", name)
+            }
+            found = Some(column);
+        }
+    }
+    Ok(found)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::query::structs::expression::{ExpressionNode, ExpressionOperator};
     use crate::query::structs::token::Token;
     use crate::query::structs::token::TokenKind::*;
+    use crate::utils::files::split_csv_fields;
     use std::default::Default;
 
     #[test]
@@ -342,7 +562,7 @@ mod tests {
         let header = vec!["id".to_string(), "apellido".to_string()];
         let mut row = Row::new(&header);
         assert!(row.set("id", "123".to_string()).is_ok());
-        assert_eq!(row.values.get("id").unwrap(), "123");
+        assert_eq!(row.values.get("id").unwrap().to_string(), "123");
     }
 
     #[test]
@@ -358,8 +578,8 @@ mod tests {
         let mut row = Row::new(&header);
         row.set("id", "123".to_string()).unwrap();
         row.clear().unwrap();
-        assert_eq!(row.values.get("id").unwrap(), "");
-        assert_eq!(row.values.get("apellido").unwrap(), "");
+        assert_eq!(row.values.get("id").unwrap().to_string(), "");
+        assert_eq!(row.values.get("apellido").unwrap().to_string(), "");
     }
 
     #[test]
@@ -382,7 +602,7 @@ mod tests {
         };
 
         row.apply_updates(&vec![update]).unwrap();
-        assert_eq!(row.values.get("id").unwrap(), "360");
+        assert_eq!(row.values.get("id").unwrap().to_string(), "360");
     }
 
     #[test]
@@ -392,8 +612,8 @@ mod tests {
         let values = vec!["360".to_string(), "katta".to_string()];
 
         row.read_new_row(values).unwrap();
-        assert_eq!(row.values.get("id").unwrap(), "360");
-        assert_eq!(row.values.get("apellido").unwrap(), "katta");
+        assert_eq!(row.values.get("id").unwrap().to_string(), "360");
+        assert_eq!(row.values.get("apellido").unwrap().to_string(), "katta");
     }
 
     #[test]
@@ -418,11 +638,58 @@ mod tests {
                 value: "apellido".to_string(),
             },
         ];
-        let values = vec!["360".to_string(), "katta".to_string()];
+        let values = vec![
+            ExpressionResult::Int(360),
+            ExpressionResult::Str("katta".to_string()),
+        ];
 
         row.insert_values(&columns, values).unwrap();
-        assert_eq!(row.values.get("id").unwrap(), "360");
-        assert_eq!(row.values.get("apellido").unwrap(), "katta");
+        assert_eq!(row.values.get("id").unwrap().to_string(), "360");
+        assert_eq!(row.values.get("apellido").unwrap().to_string(), "katta");
+    }
+
+    #[test]
+    fn test_null_marker_round_trip() {
+        let header = vec!["id".to_string(), "zip".to_string()];
+        let schema = Schema::parse("zip TEXT\nNULL \\N").unwrap();
+        let mut row = Row::with_schema(&header, Some(&schema));
+        row.read_new_row(vec!["1".to_string(), "\\N".to_string()])
+            .unwrap();
+        assert_eq!(row.values["zip"], ExpressionResult::Null);
+        row.set("zip", "".to_string()).unwrap();
+        assert_eq!(row.values["zip"], ExpressionResult::Str("".to_string()));
+        row.clear().unwrap();
+        assert_eq!(row.as_csv_row(), "\\N,\\N");
+    }
+
+    #[test]
+    fn test_set_result_keeps_values_typed() {
+        let header = vec!["id".to_string(), "zip".to_string()];
+        let mut row = Row::new(&header);
+        row.set_result("zip", ExpressionResult::Str("".to_string()))
+            .unwrap();
+        assert_eq!(row.values["zip"], ExpressionResult::Str("".to_string()));
+        row.set_result("id", ExpressionResult::Str("007".to_string()))
+            .unwrap();
+        assert_eq!(row.values["id"], ExpressionResult::Str("007".to_string()));
+        assert_eq!(row.as_csv_row(), "007,\"\"");
+
+        let schema = Schema::parse("id INT\nzip TEXT\nNULL \\N").unwrap();
+        let mut row = Row::with_schema(&header, Some(&schema));
+        row.set_result("zip", ExpressionResult::Int(2110)).unwrap();
+        assert_eq!(row.values["zip"], ExpressionResult::Str("2110".to_string()));
+        assert!(row
+            .set_result("id", ExpressionResult::Str("uno".to_string()))
+            .is_err());
+        row.set_result("zip", ExpressionResult::Str("\\N".to_string()))
+            .unwrap();
+        row.set_result("id", ExpressionResult::Null).unwrap();
+        assert_eq!(row.as_csv_row(), "\\N,\"\\N\"");
+
+        let mut read = Row::with_schema(&header, Some(&schema));
+        read.read_fields(split_csv_fields(&row.as_csv_row()).unwrap())
+            .unwrap();
+        assert_eq!(read.values, row.values);
     }
 
     #[test]
@@ -436,6 +703,41 @@ mod tests {
         assert_eq!(csv_string, "360,katta");
     }
 
+    #[test]
+    fn test_as_csv_row_quotes_special_values() {
+        let header = vec!["id".to_string(), "nombre".to_string()];
+        let mut row = Row::new(&header);
+        row.set("id", "1".to_string()).unwrap();
+        row.set("nombre", "Katta, \"Gabo\"".to_string()).unwrap();
+
+        let csv_string = row.as_csv_row();
+        assert_eq!(csv_string, "1,\"Katta, \"\"Gabo\"\"\"");
+    }
+
+    #[test]
+    fn test_resolve_qualified_columns() {
+        let header = vec!["users.id".to_string(), "ordenes.id".to_string()];
+        let found = resolve_column(&header, "ordenes.id").unwrap();
+        assert_eq!(found, Some(&"ordenes.id".to_string()));
+        assert!(resolve_column(&header, "id").is_err());
+        assert_eq!(resolve_column(&header, "email").unwrap(), None);
+
+        let single = vec!["id".to_string(), "email".to_string()];
+        let found = resolve_column(&single, "users.email").unwrap();
+        assert_eq!(found, Some(&"email".to_string()));
+    }
+
+    #[test]
+    fn test_as_csv_projection_with_qualified_columns() {
+        let header = vec!["users.id".to_string(), "ordenes.producto".to_string()];
+        let mut row = Row::new(&header);
+        row.set("users.id", "1".to_string()).unwrap();
+        row.set("ordenes.producto", "Laptop".to_string()).unwrap();
+        let projection =
+            row.as_csv_projection(&vec!["producto".to_string(), "users.id".to_string()]);
+        assert_eq!(projection, "Laptop,1");
+    }
+
     #[test]
     fn test_matches_condition() {
         let header = vec!["id".to_string()];
@@ -0,0 +1,581 @@
+use crate::errored;
+use crate::query::structs::date::{parse_date, parse_timestamp};
+use crate::query::structs::expression::ExpressionResult::{Date, Float, Int, Null, Str, Timestamp};
+use crate::query::structs::expression::{parse_decimal, ExpressionResult};
+use crate::utils::errors::Errored;
+use crate::utils::errors::Errored::{Column, Table};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const SCHEMA_EXTENSION: &str = "schema";
+
+const NULL_KEYWORD: &str = "NULL";
+
+/// Estructura que representa el esquema de una tabla, es decir, el tipo declarado de sus columnas.
+///
+/// El esquema es opcional y se lee de un archivo con el mismo nombre que la tabla y extensión
+/// `.schema` (por ejemplo `users.schema` junto a `users.csv`). Cada línea del archivo declara
+/// una columna y su tipo, separados por espacios:
+///
+/// ```text
+/// user_id INT
+/// name TEXT
+/// zip_code TEXT
+/// ```
+///
+/// Las columnas que no figuran en el esquema conservan el comportamiento por defecto, donde
+/// el tipo se infiere a partir de cada valor.
+///
+/// El esquema también define cómo se representa `NULL` dentro de las celdas de la tabla.
+/// Por defecto una celda vacía es `NULL`, pero puede configurarse otra representación con
+/// una línea `NULL <representación>`, por ejemplo `NULL \N`. Esto permite distinguir
+/// un string vacío de la ausencia de valor.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Schema {
+    columns: Vec<(String, ColumnType)>,
+    null: String,
+}
+
+/// Enum que representa los tipos de datos que puede declarar una columna.
+///
+/// - `Int`: Números enteros.
+/// - `Float`: Números con parte decimal, como `19.99`.
+/// - `Text`: Cadenas de texto, aunque su contenido parezca un número.
+/// - `Date`: Fechas en formato ISO, como `2024-01-31`.
+/// - `Timestamp`: Fechas con hora en formato ISO, como `2024-01-31 18:30:00`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnType {
+    Int,
+    Float,
+    Text,
+    Date,
+    Timestamp,
+}
+
+impl ColumnType {
+    /// Obtiene el tipo correspondiente a un nombre, sin distinguir mayúsculas.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::schema::ColumnType;
+    /// assert_eq!(ColumnType::from_name("integer"), Some(ColumnType::Int));
+    /// assert_eq!(ColumnType::from_name("real"), Some(ColumnType::Float));
+    /// assert_eq!(ColumnType::from_name("VARCHAR"), Some(ColumnType::Text));
+    /// assert_eq!(ColumnType::from_name("BLOB"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<ColumnType> {
+        match name.to_uppercase().as_str() {
+            "INT" | "INTEGER" => Some(ColumnType::Int),
+            "FLOAT" | "REAL" | "DOUBLE" => Some(ColumnType::Float),
+            "TEXT" | "VARCHAR" | "STRING" => Some(ColumnType::Text),
+            "DATE" => Some(ColumnType::Date),
+            "TIMESTAMP" | "DATETIME" => Some(ColumnType::Timestamp),
+            _ => None,
+        }
+    }
+
+    /// Interpreta el valor crudo de una celda según el tipo de la columna.
+    ///
+    /// Un valor vacío representa la ausencia de valor, por lo que es aceptado por cualquier tipo.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el valor no es válido para el tipo de la columna.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::{Float, Int, Str};
+    /// use rustic_sql::query::structs::schema::ColumnType;
+    /// assert_eq!(ColumnType::Text.parse("01234").unwrap(), Str("01234".to_string()));
+    /// assert_eq!(ColumnType::Int.parse("42").unwrap(), Int(42));
+    /// assert_eq!(ColumnType::Float.parse("42").unwrap(), Float(42.0));
+    /// assert!(ColumnType::Int.parse("cuarenta").is_err());
+    /// ```
+    pub fn parse(&self, raw: &str) -> Result<ExpressionResult, Errored> {
+        if raw.is_empty() {
+            return Ok(Str(String::new()));
+        }
+        match self {
+            ColumnType::Int => match raw.parse::<i64>() {
+                Ok(i) => Ok(Int(i)),
+                Err(_) => errored!(Column, "value '{}' is not a valid {}.", raw, self),
+            },
+            ColumnType::Float => match parse_decimal(raw) {
+                Some(f) => Ok(Float(f)),
+                None => errored!(Column, "value '{}' is not a valid {}.", raw, self),
+            },
+            ColumnType::Text => Ok(Str(raw.to_string())),
+            ColumnType::Date => match parse_date(raw) {
+                Some(days) => Ok(Date(days)),
+                None => errored!(Column, "value '{}' is not a valid {}.", raw, self),
+            },
+            ColumnType::Timestamp => match parse_timestamp(raw) {
+                Some(seconds) => Ok(Timestamp(seconds)),
+                None => errored!(Column, "value '{}' is not a valid {}.", raw, self),
+            },
+        }
+    }
+
+    /// Convierte el resultado de una expresión al tipo de la columna.
+    ///
+    /// Los valores que ya son del tipo de la columna (o `NULL`) se conservan, los enteros se
+    /// aceptan en columnas de flotantes y cualquier valor se acepta como texto. El resto se
+    /// interpreta a partir de su representación como texto, como un valor leído de la tabla
+    /// (por ejemplo `'42'` en una columna de enteros).
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el valor no es válido para el tipo de la columna.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::{Float, Int, Str};
+    /// use rustic_sql::query::structs::schema::ColumnType;
+    /// assert_eq!(ColumnType::Text.coerce(Int(7)).unwrap(), Str("7".to_string()));
+    /// assert_eq!(ColumnType::Float.coerce(Int(7)).unwrap(), Float(7.0));
+    /// assert_eq!(ColumnType::Int.coerce(Str("42".to_string())).unwrap(), Int(42));
+    /// assert!(ColumnType::Int.coerce(Float(1.5)).is_err());
+    /// ```
+    pub fn coerce(&self, value: ExpressionResult) -> Result<ExpressionResult, Errored> {
+        match (self, value) {
+            (_, Null) => Ok(Null),
+            (ColumnType::Int, Int(i)) => Ok(Int(i)),
+            (ColumnType::Float, value) if value.is_numeric() => Ok(Float(value.as_float())),
+            (ColumnType::Text, Str(s)) => Ok(Str(s)),
+            (ColumnType::Text, value) => Ok(Str(value.to_string())),
+            (ColumnType::Date, Date(days)) => Ok(Date(days)),
+            (ColumnType::Timestamp, Timestamp(seconds)) => Ok(Timestamp(seconds)),
+            (kind, value) => kind.parse(&value.to_string()),
+        }
+    }
+
+    /// Obtiene el tipo de columna que corresponde a un valor, o `None` si ningún tipo lo
+    /// representa (como `NULL` o un valor lógico).
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::expression::ExpressionResult::{Bool, Str};
+    /// use rustic_sql::query::structs::schema::ColumnType;
+    /// assert_eq!(ColumnType::of(&Str("02110".to_string())), Some(ColumnType::Text));
+    /// assert_eq!(ColumnType::of(&Bool(true)), None);
+    /// ```
+    pub fn of(value: &ExpressionResult) -> Option<ColumnType> {
+        match value {
+            Int(_) => Some(ColumnType::Int),
+            Float(_) => Some(ColumnType::Float),
+            Str(_) => Some(ColumnType::Text),
+            Date(_) => Some(ColumnType::Date),
+            Timestamp(_) => Some(ColumnType::Timestamp),
+            _ => None,
+        }
+    }
+
+    /// Infiere el tipo de un valor crudo, para las columnas que no tienen un tipo declarado.
+    ///
+    /// Todo valor que pueda interpretarse como un entero se considera un entero, los
+    /// números con parte decimal se consideran flotantes, las fechas en formato ISO se
+    /// consideran fechas (con o sin hora), y el resto se considera texto.
+    pub fn infer(raw: &str) -> ExpressionResult {
+        if let Ok(i) = raw.parse::<i64>() {
+            return Int(i);
+        }
+        if let Some(f) = parse_decimal(raw) {
+            return Float(f);
+        }
+        if let Some(days) = parse_date(raw) {
+            return Date(days);
+        }
+        match parse_timestamp(raw) {
+            Some(seconds) => Timestamp(seconds),
+            None => Str(raw.to_string()),
+        }
+    }
+}
+
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnType::Int => write!(f, "INT"),
+            ColumnType::Float => write!(f, "FLOAT"),
+            ColumnType::Text => write!(f, "TEXT"),
+            ColumnType::Date => write!(f, "DATE"),
+            ColumnType::Timestamp => write!(f, "TIMESTAMP"),
+        }
+    }
+}
+
+impl Schema {
+    /// Carga el esquema de la tabla ubicada en `table_path`, si existe.
+    ///
+    /// # Retorna
+    ///
+    /// Un `Result` con el esquema de la tabla, o `None` si la tabla no tiene un archivo de esquema.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el archivo no puede leerse o si tiene un formato inválido.
+    pub fn load(table_path: &Path) -> Result<Option<Schema>, Errored> {
+        let schema_path = schema_path(table_path);
+        if !schema_path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&schema_path)?;
+        Ok(Some(Schema::parse(&content)?))
+    }
+
+    /// Crea un esquema sin columnas declaradas, con la representación de `NULL` indicada.
+    pub fn with_null_marker(null: &str) -> Schema {
+        Schema {
+            columns: vec![],
+            null: null.to_string(),
+        }
+    }
+
+    /// Interpreta el contenido de un archivo de esquema.
+    ///
+    /// Las líneas vacías son ignoradas, y la línea `NULL <representación>` define la
+    /// representación de los valores nulos en la tabla. Para no confundirla con una columna
+    /// llamada `null`, la palabra `NULL` debe estar en mayúsculas y la representación no puede
+    /// ser el nombre de un tipo: `null TEXT` (o `NULL TEXT`) declara una columna.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si alguna línea no tiene exactamente un nombre y un tipo, si el tipo
+    /// no existe o si una columna se declara más de una vez.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::schema::{ColumnType, Schema};
+    /// let schema = Schema::parse("id INT\nzip TEXT\n").unwrap();
+    /// assert_eq!(schema.column_type("zip"), Some(&ColumnType::Text));
+    /// assert_eq!(schema.column_type("email"), None);
+    ///
+    /// let schema = Schema::parse("NULL \\N\nid INT").unwrap();
+    /// assert_eq!(schema.null_marker(), "\\N");
+    /// ```
+    pub fn parse(content: &str) -> Result<Schema, Errored> {
+        let mut schema = Schema::default();
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let (name, kind) = match parts.as_slice() {
+                [NULL_KEYWORD, marker] if ColumnType::from_name(marker).is_none() => {
+                    schema.null = marker.to_string();
+                    continue;
+                }
+                [name, kind] => (name.to_string(), kind),
+                _ => errored!(
+                    Table,
+                    "invalid schema line, expected 'column TYPE': {}",
+                    line
+                ),
+            };
+            let kind = match ColumnType::from_name(kind) {
+                Some(kind) => kind,
+                None => errored!(Table, "unknown type {} for column {}.", kind, name),
+            };
+            schema.add_column(name, kind)?;
+        }
+        Ok(schema)
+    }
+
+    /// Guarda el esquema en el archivo `.schema` correspondiente a la tabla ubicada en `table_path`.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el archivo no puede escribirse.
+    pub fn save(&self, table_path: &Path) -> Result<(), Errored> {
+        let mut content: String = self
+            .columns
+            .iter()
+            .map(|(name, kind)| format!("{} {}\n", name, kind))
+            .collect();
+        if !self.null.is_empty() {
+            content.push_str(&format!("{} {}\n", NULL_KEYWORD, self.null));
+        }
+        fs::write(schema_path(table_path), content)?;
+        Ok(())
+    }
+
+    /// Elimina el archivo `.schema` de la tabla ubicada en `table_path`, si existe.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el archivo existe pero no puede eliminarse.
+    pub fn remove(table_path: &Path) -> Result<(), Errored> {
+        let schema_path = schema_path(table_path);
+        if schema_path.exists() {
+            fs::remove_file(schema_path)?;
+        }
+        Ok(())
+    }
+
+    /// Agrega una columna al final del esquema.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si la columna ya fue declarada.
+    pub fn add_column(&mut self, name: String, kind: ColumnType) -> Result<(), Errored> {
+        if self.column_type(&name).is_some() {
+            errored!(
+                Table,
+                "column {} is declared more than once in schema.",
+                name
+            )
+        }
+        self.columns.push((name, kind));
+        Ok(())
+    }
+
+    /// Crea una copia del esquema sin la columna indicada.
+    pub fn without_column(&self, column: &str) -> Schema {
+        Schema {
+            columns: self
+                .columns
+                .iter()
+                .filter(|(name, _)| name != column)
+                .cloned()
+                .collect(),
+            null: self.null.to_string(),
+        }
+    }
+
+    /// Crea una copia del esquema donde la columna `from` pasa a llamarse `to`.
+    pub fn with_renamed_column(&self, from: &str, to: &str) -> Schema {
+        Schema {
+            columns: self
+                .columns
+                .iter()
+                .map(|(name, kind)| match name == from {
+                    true => (to.to_string(), kind.clone()),
+                    false => (name.to_string(), kind.clone()),
+                })
+                .collect(),
+            null: self.null.to_string(),
+        }
+    }
+
+    /// Obtiene los nombres de las columnas declaradas, en el orden en que fueron declaradas.
+    pub fn column_names(&self) -> Vec<&String> {
+        self.columns.iter().map(|(name, _)| name).collect()
+    }
+
+    /// Obtiene la representación de `NULL` en las celdas de la tabla (por defecto, una celda vacía).
+    pub fn null_marker(&self) -> &str {
+        &self.null
+    }
+
+    /// Obtiene el tipo declarado de una columna, si lo tiene.
+    pub fn column_type(&self, column: &str) -> Option<&ColumnType> {
+        self.columns
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, kind)| kind)
+    }
+
+    /// Interpreta el valor crudo de una columna, usando su tipo declarado o infiriéndolo si no lo tiene.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el valor no es válido para el tipo declarado de la columna.
+    pub fn parse_value(&self, column: &str, raw: &str) -> Result<ExpressionResult, Errored> {
+        match self.column_type(column) {
+            Some(kind) => match kind.parse(raw) {
+                Ok(value) => Ok(value),
+                Err(_) => errored!(
+                    Column,
+                    "value '{}' is not a valid {} for column {}.",
+                    raw,
+                    kind,
+                    column
+                ),
+            },
+            None => Ok(ColumnType::infer(raw)),
+        }
+    }
+
+    /// Convierte el resultado de una expresión al tipo declarado de una columna (ver
+    /// `ColumnType::coerce`). Si la columna no declara un tipo, el valor se conserva tal cual.
+    ///
+    /// # Errores
+    ///
+    /// Retorna un error si el valor no es válido para el tipo declarado de la columna.
+    pub fn coerce_value(
+        &self,
+        column: &str,
+        value: ExpressionResult,
+    ) -> Result<ExpressionResult, Errored> {
+        match self.column_type(column) {
+            Some(kind) => {
+                let raw = value.to_string();
+                match kind.coerce(value) {
+                    Ok(value) => Ok(value),
+                    Err(_) => errored!(
+                        Column,
+                        "value '{}' is not a valid {} for column {}.",
+                        raw,
+                        kind,
+                        column
+                    ),
+                }
+            }
+            None => Ok(value),
+        }
+    }
+
+    /// Crea una copia del esquema con las columnas calificadas con el nombre de la tabla
+    /// (`tabla.columna`), para ser usado al unir varias tablas.
+    pub fn qualified(&self, table: &str) -> Schema {
+        Schema {
+            columns: self
+                .columns
+                .iter()
+                .map(|(name, kind)| (format!("{}.{}", table, name), kind.clone()))
+                .collect(),
+            null: self.null.to_string(),
+        }
+    }
+}
+
+/// Obtiene la ruta del archivo de esquema correspondiente a una tabla.
+fn schema_path(table_path: &Path) -> PathBuf {
+    table_path.with_extension(SCHEMA_EXTENSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema = Schema::parse("id INT\n\n  zip   varchar \n").unwrap();
+        assert_eq!(schema.column_type("id"), Some(&ColumnType::Int));
+        assert_eq!(schema.column_type("zip"), Some(&ColumnType::Text));
+    }
+
+    #[test]
+    fn test_parse_invalid_schema() {
+        assert!(Schema::parse("id").is_err());
+        assert!(Schema::parse("id FLOATY").is_err());
+        assert!(Schema::parse("id INT\nid TEXT").is_err());
+    }
+
+    #[test]
+    fn test_parse_value_with_declared_and_inferred_types() {
+        let schema = Schema::parse("zip TEXT\nage INT").unwrap();
+        assert_eq!(
+            schema.parse_value("zip", "01234").unwrap(),
+            Str("01234".to_string())
+        );
+        assert_eq!(schema.parse_value("age", "40").unwrap(), Int(40));
+        assert_eq!(schema.parse_value("age", "").unwrap(), Str(String::new()));
+        assert_eq!(schema.parse_value("id", "7").unwrap(), Int(7));
+        let error = schema.parse_value("age", "cuarenta").unwrap_err();
+        assert!(error.to_string().contains("age"));
+    }
+
+    #[test]
+    fn test_parse_float_values() {
+        let schema = Schema::parse("precio REAL").unwrap();
+        assert_eq!(schema.column_type("precio"), Some(&ColumnType::Float));
+        assert_eq!(schema.parse_value("precio", "19.99").unwrap(), Float(19.99));
+        assert_eq!(schema.parse_value("precio", "-5").unwrap(), Float(-5.0));
+        assert!(schema.parse_value("precio", "inf").is_err());
+        assert_eq!(schema.parse_value("peso", "0.5").unwrap(), Float(0.5));
+        assert_eq!(
+            schema.parse_value("nombre", "NaN").unwrap(),
+            Str("NaN".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_date_values() {
+        let schema = Schema::parse("alta DATE\nultimo_acceso DATETIME").unwrap();
+        assert_eq!(
+            schema.column_type("ultimo_acceso"),
+            Some(&ColumnType::Timestamp)
+        );
+        assert_eq!(
+            schema
+                .parse_value("alta", "2024-01-31")
+                .unwrap()
+                .to_string(),
+            "2024-01-31"
+        );
+        assert!(schema.parse_value("alta", "2024-01-31 10:00:00").is_err());
+        assert_eq!(
+            schema.parse_value("ultimo_acceso", "2024-01-31").unwrap(),
+            Timestamp(19_753 * 86_400)
+        );
+        assert_eq!(ColumnType::infer("2024-01-31"), Date(19_753));
+        assert_eq!(
+            ColumnType::infer("31/01/2024"),
+            Str("31/01/2024".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_null_marker() {
+        assert_eq!(Schema::parse("id INT").unwrap().null_marker(), "");
+        let schema = Schema::parse("id INT\nNULL \\N").unwrap();
+        assert_eq!(schema.null_marker(), "\\N");
+        assert_eq!(schema.column_names(), vec!["id"]);
+        assert_eq!(schema.without_column("id").null_marker(), "\\N");
+        assert_eq!(schema.qualified("users").null_marker(), "\\N");
+    }
+
+    #[test]
+    fn test_parse_column_named_null() {
+        let schema = Schema::parse("null TEXT\nid INT\nNULL \\N").unwrap();
+        assert_eq!(schema.column_names(), vec!["null", "id"]);
+        assert_eq!(schema.column_type("null"), Some(&ColumnType::Text));
+        assert_eq!(schema.null_marker(), "\\N");
+
+        let schema = Schema::parse("NULL INT").unwrap();
+        assert_eq!(schema.column_type("NULL"), Some(&ColumnType::Int));
+        assert_eq!(schema.null_marker(), "");
+        assert!(Schema::parse("null BLOB").is_err());
+        assert!(Schema::parse("NULL \\N extra").is_err());
+    }
+
+    #[test]
+    fn test_qualified_schema() {
+        let schema = Schema::parse("zip TEXT").unwrap().qualified("users");
+        assert_eq!(schema.column_type("users.zip"), Some(&ColumnType::Text));
+        assert_eq!(schema.column_type("zip"), None);
+    }
+
+    #[test]
+    fn test_save_and_remove_schema() {
+        let dir = std::env::temp_dir().join(format!("rustic_schema_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let table_path = dir.join("tiendas.csv");
+        let mut schema = Schema::default();
+        schema
+            .add_column("id".to_string(), ColumnType::Int)
+            .unwrap();
+        schema
+            .add_column("zip".to_string(), ColumnType::Text)
+            .unwrap();
+        assert!(schema
+            .add_column("id".to_string(), ColumnType::Text)
+            .is_err());
+
+        schema.save(&table_path).unwrap();
+        assert_eq!(Schema::load(&table_path).unwrap(), Some(schema));
+        Schema::remove(&table_path).unwrap();
+        assert_eq!(Schema::load(&table_path).unwrap(), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_schema() {
+        let schema = Schema::load(Path::new("tests/unit_tables/ordenes.csv")).unwrap();
+        assert_eq!(schema, None);
+    }
+}
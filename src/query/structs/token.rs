@@ -16,7 +16,7 @@ use crate::query::structs::token::TokenKind::Unknown;
 /// assert_eq!(token.value, "id_cliente");
 /// assert_eq!(token.kind, TokenKind::Identifier);
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Token {
     /// El valor del token como un string.
     pub value: String,
@@ -46,7 +46,7 @@ pub struct Token {
 /// let kind = TokenKind::String;
 /// assert_eq!(kind, TokenKind::String);
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TokenKind {
     Unknown,
     String,
@@ -0,0 +1,250 @@
+use crate::query::structs::aggregate::Aggregate;
+use crate::query::structs::expression::ExpressionNode;
+use crate::query::structs::expression::ExpressionResult;
+use crate::query::structs::ordering::OrderKind::Desc;
+use crate::query::structs::ordering::Ordering;
+use crate::query::structs::token::Token;
+use crate::query::structs::window::WindowKind::*;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Estructura que representa una función de ventana dentro de una consulta SELECT, como
+/// `ROW_NUMBER() OVER (PARTITION BY ciudad ORDER BY edad DESC)`.
+///
+/// A diferencia de una agregación, una función de ventana no reduce las filas: calcula un valor
+/// para cada fila a partir de las filas de su partición, recorridas según el ordenamiento.
+///
+/// # Campos
+///
+/// * `kind` - La función de ventana a aplicar.
+/// * `field` - La columna sobre la que opera la función, o `None` en las funciones de ranking.
+/// * `partition_by` - Las columnas que separan las filas en particiones independientes.
+/// * `ordering` - El orden en que se recorren las filas de cada partición.
+/// * `aggregates` - Las agregaciones de la partición o del ordenamiento, que se calculan por
+///   grupo y se nombran en ellos con su representación SQL.
+#[derive(Clone, PartialEq)]
+pub struct Window {
+    pub kind: WindowKind,
+    pub field: Option<Token>,
+    pub partition_by: Vec<Token>,
+    pub ordering: Vec<Ordering>,
+    pub aggregates: Vec<Aggregate>,
+}
+
+/// Enum que representa las funciones de ventana disponibles.
+///
+/// - `RowNumber`: El número de la fila dentro de su partición, empezando en uno.
+/// - `Rank`: La posición de la fila dentro de su partición, repetida entre las filas empatadas
+///   según el ordenamiento y dejando huecos luego de cada empate.
+/// - `Lag`: El valor de la columna `offset` filas antes de la actual, o `default` si no existe.
+/// - `Lead`: El valor de la columna `offset` filas después de la actual, o `default` si no existe.
+/// - `Sum`: La suma acumulada de la columna desde el inicio de la partición hasta la fila actual,
+///   incluyendo a las filas empatadas con ella.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowKind {
+    RowNumber,
+    Rank,
+    Lag {
+        offset: usize,
+        default: ExpressionResult,
+    },
+    Lead {
+        offset: usize,
+        default: ExpressionResult,
+    },
+    Sum,
+}
+
+impl WindowKind {
+    /// Obtiene la función de ventana correspondiente a un nombre, sin distinguir mayúsculas.
+    ///
+    /// `LAG` y `LEAD` se obtienen con sus argumentos por defecto: una fila de distancia y
+    /// `NULL` como valor cuando la fila no existe.
+    ///
+    /// # Ejemplo
+    ///
+    /// ```rust
+    /// use rustic_sql::query::structs::window::WindowKind;
+    /// assert_eq!(WindowKind::from_name("rank"), Some(WindowKind::Rank));
+    /// assert_eq!(WindowKind::from_name("count"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<WindowKind> {
+        match name.to_uppercase().as_str() {
+            "ROW_NUMBER" => Some(RowNumber),
+            "RANK" => Some(Rank),
+            "LAG" => Some(Lag {
+                offset: 1,
+                default: ExpressionResult::Null,
+            }),
+            "LEAD" => Some(Lead {
+                offset: 1,
+                default: ExpressionResult::Null,
+            }),
+            "SUM" => Some(Sum),
+            _ => None,
+        }
+    }
+
+    /// Obtiene el nombre SQL de la función.
+    fn name(&self) -> &str {
+        match self {
+            RowNumber => "ROW_NUMBER",
+            Rank => "RANK",
+            Lag { .. } => "LAG",
+            Lead { .. } => "LEAD",
+            Sum => "SUM",
+        }
+    }
+}
+
+impl Window {
+    /// Obtiene las columnas de la tabla que utiliza la función: la columna sobre la que opera,
+    /// las de la partición y las del ordenamiento.
+    pub fn columns(&self) -> Vec<&Token> {
+        self.field
+            .iter()
+            .chain(&self.partition_by)
+            .chain(self.ordering.iter().map(|o| &o.field))
+            .collect()
+    }
+}
+
+impl Display for Window {
+    /// Representa a la función tal cual se escribe en SQL, por ejemplo
+    /// `RANK() OVER (PARTITION BY ciudad ORDER BY edad DESC)` o `LAG(precio, 2, 0) OVER (ORDER BY id)`.
+    ///
+    /// Este nombre es el que se utiliza como columna del resultado.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut arguments: Vec<String> = self.field.iter().map(|t| t.value.to_string()).collect();
+        if let Lag { offset, default } | Lead { offset, default } = &self.kind {
+            if *default != ExpressionResult::Null {
+                arguments.push(offset.to_string());
+                arguments.push(ExpressionNode::Constant(default.clone()).to_string());
+            } else if *offset != 1 {
+                arguments.push(offset.to_string());
+            }
+        }
+        write!(f, "{}({}) OVER (", self.kind.name(), arguments.join(", "))?;
+        let mut clauses = vec![];
+        if !self.partition_by.is_empty() {
+            let columns: Vec<&str> = self.partition_by.iter().map(|t| t.value.as_str()).collect();
+            clauses.push(format!("PARTITION BY {}", columns.join(", ")));
+        }
+        if !self.ordering.is_empty() {
+            let ordering: Vec<String> = self
+                .ordering
+                .iter()
+                .map(|o| match o.kind {
+                    Desc => format!("{} DESC", o.field.value),
+                    _ => o.field.value.to_string(),
+                })
+                .collect();
+            clauses.push(format!("ORDER BY {}", ordering.join(", ")));
+        }
+        write!(f, "{})", clauses.join(" "))
+    }
+}
+
+impl Debug for Window {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::structs::expression::ExpressionResult::{Int, Str};
+    use crate::query::structs::ordering::OrderKind::Asc;
+    use crate::query::structs::token::TokenKind::Identifier;
+
+    fn column(value: &str) -> Token {
+        Token {
+            value: value.to_string(),
+            kind: Identifier,
+        }
+    }
+
+    fn window(kind: WindowKind, field: Option<&str>) -> Window {
+        Window {
+            kind,
+            field: field.map(column),
+            partition_by: vec![],
+            ordering: vec![],
+            aggregates: vec![],
+        }
+    }
+
+    #[test]
+    fn test_window_name() {
+        let mut rank = window(Rank, None);
+        rank.partition_by = vec![column("ciudad"), column("pais")];
+        rank.ordering = vec![
+            Ordering {
+                field: column("edad"),
+                kind: Desc,
+            },
+            Ordering {
+                field: column("id"),
+                kind: Asc,
+            },
+        ];
+        assert_eq!(
+            rank.to_string(),
+            "RANK() OVER (PARTITION BY ciudad, pais ORDER BY edad DESC, id)"
+        );
+        assert_eq!(window(RowNumber, None).to_string(), "ROW_NUMBER() OVER ()");
+        assert_eq!(
+            window(Sum, Some("precio")).to_string(),
+            "SUM(precio) OVER ()"
+        );
+    }
+
+    #[test]
+    fn test_lag_and_lead_arguments_in_name() {
+        let lag = WindowKind::from_name("lag").unwrap();
+        assert_eq!(
+            window(lag, Some("precio")).to_string(),
+            "LAG(precio) OVER ()"
+        );
+
+        let lead = Lead {
+            offset: 2,
+            default: ExpressionResult::Null,
+        };
+        assert_eq!(
+            window(lead, Some("precio")).to_string(),
+            "LEAD(precio, 2) OVER ()"
+        );
+
+        let lag = Lag {
+            offset: 1,
+            default: Str("nada".to_string()),
+        };
+        assert_eq!(
+            window(lag, Some("producto")).to_string(),
+            "LAG(producto, 1, 'nada') OVER ()"
+        );
+
+        let lead = Lead {
+            offset: 3,
+            default: Int(0),
+        };
+        assert_eq!(
+            window(lead, Some("precio")).to_string(),
+            "LEAD(precio, 3, 0) OVER ()"
+        );
+    }
+
+    #[test]
+    fn test_window_columns() {
+        let mut sum = window(Sum, Some("precio"));
+        sum.partition_by = vec![column("usuario")];
+        sum.ordering = vec![Ordering {
+            field: column("id"),
+            kind: Asc,
+        }];
+        let columns: Vec<&str> = sum.columns().iter().map(|t| t.value.as_str()).collect();
+        assert_eq!(columns, vec!["precio", "usuario", "id"]);
+        assert!(window(RowNumber, None).columns().is_empty());
+    }
+}
@@ -7,25 +7,73 @@ use crate::query::tokenizer::TokenizerState::*;
 use crate::utils::errors::Errored;
 use crate::utils::errors::Errored::Syntax;
 
-const VALID_OPERATORS: &[&str] = &["*", "=", "<", ">", "!", ">=", "<=", "!="];
+const VALID_OPERATORS: &[&str] = &[
+    "*", "=", "<", ">", "!", ">=", "<=", "!=", "+", "-", "/", "%",
+];
 
 const IGNORABLE_CHARS: &[char] = &[' ', ',', ';', '\0', '\n'];
 
 const RESERVED_KEYWORDS: &[&str] = &[
+    "WITH",
     "SELECT",
+    "DISTINCT",
     "UPDATE",
     "DELETE",
     "INSERT INTO",
+    "CREATE TABLE",
+    "DROP TABLE",
+    "ALTER TABLE",
+    "ADD COLUMN",
+    "DROP COLUMN",
+    "RENAME COLUMN",
+    "TO",
+    "DEFAULT",
+    "IF NOT EXISTS",
+    "IF EXISTS",
     "SET",
     "VALUES",
     "ORDER BY",
+    "GROUP BY",
+    "PARTITION BY",
+    "HAVING",
+    "LIMIT",
+    "OFFSET",
+    "UNION ALL",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+    "INNER JOIN",
+    "LEFT OUTER JOIN",
+    "LEFT JOIN",
+    "JOIN",
+    "ON",
+    "OVER",
     "DESC",
     "ASC",
+    "AS",
     "FROM",
     "WHERE",
     "AND",
     "OR",
+    "NOT LIKE",
+    "NOT ILIKE",
+    "NOT IN",
+    "NOT BETWEEN",
     "NOT",
+    "LIKE",
+    "ILIKE",
+    "IN",
+    "BETWEEN",
+    "EXISTS",
+    "ESCAPE",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "ELSE",
+    "END",
+    "IS NOT NULL",
+    "IS NULL",
+    "NULL",
 ];
 
 /// `Tokenizer` es una estructura que se encarga de analizar y tokenizar un string SQL.
@@ -33,6 +81,11 @@ const RESERVED_KEYWORDS: &[&str] = &[
 /// Esta estructura divide un string SQL en tokens basados en los componentes del SQL, como palabras clave,
 /// identificadores, operadores, literales numéricos y de cadena, y paréntesis.
 ///
+/// Un identificador entre comillas dobles (`"end"`) se tokeniza siempre como `Identifier`, tal cual
+/// está escrito, aunque coincida con una palabra clave. Esto permite consultar columnas cuyo
+/// nombre es una palabra reservada (`SELECT "end" FROM eventos`). Para calificar una columna así
+/// con el nombre de su tabla, se encierra el nombre completo (`"eventos.end"`).
+///
 /// # Ejemplo
 ///
 /// ```rust
@@ -47,6 +100,7 @@ pub struct Tokenizer {
     i: usize,
     state: TokenizerState,
     parenthesis_count: i8,
+    separated: bool,
 }
 
 /// `TokenizerState` representa los posibles estados del `Tokenizer` durante el proceso de tokenización.
@@ -58,6 +112,7 @@ pub struct Tokenizer {
 /// - `Operator`: Estado cuando se está analizando un operador.
 /// - `NumberLiteral`: Estado cuando se está analizando un literal numérico.
 /// - `StringLiteral`: Estado cuando se está analizando una cadena de texto.
+/// - `QuotedIdentifier`: Estado cuando se está analizando un identificador entre comillas dobles.
 /// - `OpenParenthesis`: Estado cuando se está analizando un paréntesis de apertura.
 /// - `CloseParenthesis`: Estado cuando se está analizando un paréntesis de cierre.
 /// - `Complete`: Estado cuando el token actual ha sido completado.
@@ -69,6 +124,7 @@ enum TokenizerState {
     Operator,
     NumberLiteral,
     StringLiteral,
+    QuotedIdentifier,
     OpenParenthesis,
     CloseParenthesis,
     Complete,
@@ -88,6 +144,7 @@ impl Tokenizer {
             i: 0,
             state: Begin,
             parenthesis_count: 0,
+            separated: false,
         }
     }
 
@@ -112,14 +169,18 @@ impl Tokenizer {
         while self.i < sql.len() {
             let c = char_at(self.i, sql);
             match self.state {
-                Begin => self.next_state(c)?,
+                Begin => self.next_state(c, sql, out.last())?,
                 IdentifierOrKeyword => token = self.tokenize_identifier_or_keyword(sql)?,
                 Operator => token = self.tokenize_operator(sql)?,
                 NumberLiteral => token = self.tokenize_number(sql)?,
                 OpenParenthesis | CloseParenthesis => token = self.tokenize_parenthesis(sql)?,
                 StringLiteral => {
                     self.i += c.len_utf8();
-                    token = self.tokenize_quoted(sql)?;
+                    token = self.tokenize_quoted(sql, '\'', TokenKind::String)?;
+                }
+                QuotedIdentifier => {
+                    self.i += c.len_utf8();
+                    token = self.tokenize_quoted(sql, '"', Identifier)?;
                 }
                 Complete => {
                     out.push(token);
@@ -139,15 +200,29 @@ impl Tokenizer {
 
     /// Cambia el estado del `Tokenizer` basado en el carácter actual.
     ///
+    /// Un signo `-` seguido de un dígito es el comienzo de un número negativo, salvo que
+    /// el token anterior sea un valor (como en `cantidad -1`), en cuyo caso es una resta.
+    /// Una coma entre ambos separa dos valores de una lista (`cantidad, -1`), por lo que el
+    /// signo vuelve a ser parte del número.
+    ///
     /// # Errores
     ///
     /// Retorna un error si el carácter no se puede tokenizar.
-    fn next_state(&mut self, c: char) -> Result<(), Errored> {
+    fn next_state(&mut self, c: char, sql: &str, previous: Option<&Token>) -> Result<(), Errored> {
         match c {
-            c if can_be_skipped(c) => self.i += c.len_utf8(),
+            c if can_be_skipped(c) => {
+                self.separated |= c == ',';
+                self.i += c.len_utf8()
+            }
             c if c.is_ascii_digit() => self.state = NumberLiteral,
+            '-' if char_at(self.i + 1, sql).is_ascii_digit()
+                && (self.separated || !previous.is_some_and(ends_value)) =>
+            {
+                self.state = NumberLiteral
+            }
             c if is_identifier_char(c) => self.state = IdentifierOrKeyword,
             '\'' => self.state = StringLiteral,
+            '"' => self.state = QuotedIdentifier,
             '(' => self.state = OpenParenthesis,
             ')' => self.state = CloseParenthesis,
             c if is_operator_char(c) => self.state = Operator,
@@ -209,13 +284,32 @@ impl Tokenizer {
 
     /// Tokeniza un literal numérico.
     ///
-    /// Se tokeniza el texto como `Number` si corresponde a un literal numérico.
+    /// Se tokeniza el texto como `Number` si corresponde a un literal numérico, que puede
+    /// comenzar con un signo `-` y tener una parte decimal separada por un punto (`-19.99`).
     ///
     /// # Errores
     ///
     /// Retorna un error si el texto no puede ser tokenizado.
     fn tokenize_number(&mut self, sql: &str) -> Result<Token, Errored> {
-        self.tokenize_kind(sql, Number, |c| c.is_ascii_digit())
+        let start = self.i;
+        if char_at(self.i, sql) == '-' {
+            self.i += 1;
+        }
+        let mut decimal = false;
+        loop {
+            let c = char_at(self.i, sql);
+            if c == '.' && !decimal && char_at(self.i + 1, sql).is_ascii_digit() {
+                decimal = true;
+            } else if !c.is_ascii_digit() {
+                break;
+            }
+            self.i += 1;
+        }
+        self.state = Complete;
+        Ok(Token {
+            value: String::from(&sql[start..self.i]),
+            kind: Number,
+        })
     }
 
     /// Tokeniza un operador.
@@ -243,24 +337,33 @@ impl Tokenizer {
         }
     }
 
-    /// Tokeniza una cadena de texto entre comillas simples.
+    /// Tokeniza un texto entre comillas.
     ///
-    /// Extrae el contenido entre las comillas simples y lo tokeniza como `String`.
+    /// Extrae el contenido entre las comillas y lo tokeniza con el tipo indicado: `String` para
+    /// las comillas simples, o `Identifier` para las comillas dobles.
     ///
     /// # Errores
     ///
-    /// Retorna un error si las comillas no están balanceadas.
-    fn tokenize_quoted(&mut self, sql: &str) -> Result<Token, Errored> {
+    /// Retorna un error si las comillas no están balanceadas, o si un identificador está vacío.
+    fn tokenize_quoted(
+        &mut self,
+        sql: &str,
+        quote: char,
+        kind: TokenKind,
+    ) -> Result<Token, Errored> {
         let start = self.i;
         for (index, char) in sql[start..].char_indices() {
-            if char == '\'' {
+            if char == quote {
                 let end = start + index;
                 self.i = end + 1;
                 let quoted = &sql[start..end];
+                if quoted.is_empty() && kind == Identifier {
+                    errored!(Syntax, "empty quoted identifier at index: {start}");
+                }
                 self.state = Complete;
                 return Ok(Token {
                     value: String::from(quoted),
-                    kind: TokenKind::String,
+                    kind,
                 });
             }
         }
@@ -277,10 +380,16 @@ impl Tokenizer {
 
     /// Busca un operador en la cadena SQL.
     ///
-    /// Verifica si el texto actual coincide con algún operador válido.
+    /// Verifica si el texto actual comienza con algún operador válido, eligiendo el más
+    /// largo posible. De esta manera `>=` es un único operador, pero en `x>-1` el operador
+    /// `>` puede estar seguido de un número negativo.
     ///
     fn matches_operator(&self, sql: &str) -> Option<String> {
-        self.matches_special_tokens(sql, VALID_OPERATORS, is_operator_char)
+        VALID_OPERATORS
+            .iter()
+            .filter(|op| sql[self.i..].starts_with(**op))
+            .max_by_key(|op| op.len())
+            .map(|op| op.to_string())
     }
 
     /// Busca tokens especiales en la consulta SQL.
@@ -347,7 +456,8 @@ impl Tokenizer {
     ///
     /// Esto se usa para preparar el `Tokenizer` para el próximo token después de completar el actual.
     fn reset(&mut self) {
-        self.state = Begin
+        self.state = Begin;
+        self.separated = false;
     }
 }
 
@@ -367,9 +477,20 @@ fn can_be_skipped(c: char) -> bool {
 /// Determina si un carácter es válido para un identificador o variable.
 ///
 /// Los identificadores pueden comenzar con letras o guiones bajos, seguidos
-/// de letras, dígitos o guiones bajos.
+/// de letras, dígitos o guiones bajos. El punto se admite para poder calificar
+/// una columna con el nombre de su tabla (`tabla.columna`).
 fn is_identifier_char(c: char) -> bool {
-    c == '_' || (c.is_alphanumeric() && !can_be_skipped(c))
+    c == '_' || c == '.' || (c.is_alphanumeric() && !can_be_skipped(c))
+}
+
+/// Determina si un token termina un valor, es decir, si puede ser el operando izquierdo
+/// de una operación como la resta.
+fn ends_value(token: &Token) -> bool {
+    match token.kind {
+        Identifier | Number | TokenKind::String | ParenthesisClose => true,
+        Keyword => token.value == "NULL" || token.value == "END",
+        _ => false,
+    }
 }
 
 /// Determina si un carácter es un operador válido.
@@ -473,6 +594,27 @@ mod tests {
         assert_eq!(tokens[7].kind, TokenString);
     }
 
+    #[test]
+    fn test_tokenize_quoted_identifiers() {
+        let sql = "SELECT \"end\", \"eventos.in\" FROM eventos WHERE \"END\" > 1";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+        assert_eq!(tokens.len(), 9);
+        assert_eq!(tokens[1].value, "end");
+        assert_eq!(tokens[1].kind, Identifier);
+        assert_eq!(tokens[2].value, "eventos.in");
+        assert_eq!(tokens[2].kind, Identifier);
+        assert_eq!(tokens[6].value, "END");
+        assert_eq!(tokens[6].kind, Identifier);
+
+        assert!(Tokenizer::new()
+            .tokenize("SELECT \"end FROM eventos")
+            .is_err());
+        assert!(Tokenizer::new()
+            .tokenize("SELECT \"\" FROM eventos")
+            .is_err());
+    }
+
     #[test]
     fn test_unclosed_parenthesis_error() {
         let sql = "SELECT id FROM ordenes WHERE (producto = 'Laptop'";
@@ -508,4 +650,138 @@ mod tests {
         assert_eq!(tokens[6].value, ">=");
         assert_eq!(tokens[6].kind, Operator);
     }
+
+    #[test]
+    fn test_tokenize_arithmetic_operators() {
+        let sql = "SELECT precio * cantidad FROM ordenes WHERE (cantidad + 1) % 2 = 0";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+
+        assert_eq!(tokens[2].value, "*");
+        assert_eq!(tokens[2].kind, Operator);
+        assert_eq!(tokens[9].value, "+");
+        assert_eq!(tokens[9].kind, Operator);
+        assert_eq!(tokens[12].value, "%");
+        assert_eq!(tokens[12].kind, Operator);
+    }
+
+    #[test]
+    fn test_tokenize_null_keywords() {
+        let sql =
+            "SELECT * FROM users WHERE age is not null OR email IS NULL AND null_count = NULL";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+
+        assert_eq!(
+            tokens[6],
+            Token {
+                value: "IS NOT NULL".to_string(),
+                kind: Keyword
+            }
+        );
+        assert_eq!(
+            tokens[9],
+            Token {
+                value: "IS NULL".to_string(),
+                kind: Keyword
+            }
+        );
+        assert_eq!(tokens[11].kind, Identifier);
+        assert_eq!(
+            tokens[13],
+            Token {
+                value: "NULL".to_string(),
+                kind: Keyword
+            }
+        );
+    }
+
+    #[test]
+    fn test_tokenize_like_keywords() {
+        let sql = "SELECT * FROM users WHERE email not like '%!_%' escape '!' OR name ILIKE 'j%'";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+        let keywords: Vec<&str> = tokens
+            .iter()
+            .filter(|t| t.kind == Keyword)
+            .map(|t| t.value.as_str())
+            .collect();
+
+        assert_eq!(
+            keywords,
+            vec!["SELECT", "FROM", "WHERE", "NOT LIKE", "ESCAPE", "OR", "ILIKE"]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_decimal_and_negative_numbers() {
+        let sql = "SELECT * FROM orders WHERE price>-1.5 AND quantity -1 < 2*-3.25";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+        let values: Vec<&str> = tokens[5..].iter().map(|t| t.value.as_str()).collect();
+
+        assert_eq!(
+            values,
+            vec!["price", ">", "-1.5", "AND", "quantity", "-", "1", "<", "2", "*", "-3.25"]
+        );
+        assert_eq!(tokens[7].kind, Number);
+        assert_eq!(tokens[10].kind, TokenKind::Operator);
+    }
+
+    #[test]
+    fn test_tokenize_negative_number_after_comma() {
+        let sql = "SELECT quantity, -1, quantity -1 FROM orders";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+        let values: Vec<&str> = tokens.iter().map(|t| t.value.as_str()).collect();
+
+        assert_eq!(
+            values,
+            vec!["SELECT", "quantity", "-1", "quantity", "-", "1", "FROM", "orders"]
+        );
+        assert_eq!(tokens[2].kind, Number);
+        assert_eq!(tokens[4].kind, TokenKind::Operator);
+    }
+
+    #[test]
+    fn test_tokenize_case_keywords() {
+        let sql = "SELECT CASE WHEN level > 20 THEN 'high' ELSE 'low' END FROM pokemon WHERE CASE type WHEN 'Fire' THEN 1 END -1 = 0";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+        let keywords: Vec<&str> = tokens
+            .iter()
+            .filter(|t| t.kind == Keyword)
+            .map(|t| t.value.as_str())
+            .collect();
+
+        assert_eq!(
+            keywords,
+            vec![
+                "SELECT", "CASE", "WHEN", "THEN", "ELSE", "END", "FROM", "WHERE", "CASE", "WHEN",
+                "THEN", "END"
+            ]
+        );
+        assert_eq!(tokens[tokens.len() - 4].value, "-");
+        assert_eq!(tokens[tokens.len() - 4].kind, Operator);
+    }
+
+    #[test]
+    fn test_tokenize_window_keywords() {
+        let sql = "SELECT RANK() OVER (PARTITION BY city ORDER BY age DESC), overtime FROM users";
+        let mut tokenizer = Tokenizer::new();
+        let tokens = tokenizer.tokenize(sql).unwrap();
+        let keywords: Vec<&str> = tokens
+            .iter()
+            .filter(|t| t.kind == Keyword)
+            .map(|t| t.value.as_str())
+            .collect();
+
+        assert_eq!(
+            keywords,
+            vec!["SELECT", "OVER", "PARTITION BY", "ORDER BY", "DESC", "FROM"]
+        );
+        assert_eq!(tokens[1].value, "RANK");
+        assert_eq!(tokens[1].kind, Identifier);
+        assert_eq!(tokens[tokens.len() - 3].value, "overtime");
+    }
 }
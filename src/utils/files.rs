@@ -1,16 +1,47 @@
 use crate::errored;
 use crate::utils::errors::Errored;
-use crate::utils::errors::Errored::Default;
+use crate::utils::errors::Errored::{Default, Table};
 use std::fs::File;
 use std::hash::{DefaultHasher, Hash, Hasher};
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, BufWriter};
 use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
-use std::{fs, thread};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::{fs, mem, process, thread};
 
 const TEMP_EXTENSION: &str = "tmp";
 const CSV_EXTENSION: &str = "csv";
-const CSV_SEPARATOR: &str = ",";
+const CSV_SEPARATOR: char = ',';
+const CSV_QUOTE: char = '"';
+
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Campo de un registro CSV, tal como se leyó del archivo.
+///
+/// Se distingue si el campo estaba entre comillas, ya que un campo entre comillas nunca
+/// representa `NULL`, aunque su contenido coincida con la representación de `NULL` de la tabla.
+/// Por ejemplo, con la representación por defecto, `""` es un texto vacío y un campo vacío es `NULL`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvField {
+    pub value: String,
+    pub quoted: bool,
+}
+
+impl CsvField {
+    /// Indica si el campo representa `NULL`, según la representación de `NULL` de la tabla.
+    pub fn is_null(&self, null_marker: &str) -> bool {
+        !self.quoted && self.value == null_marker
+    }
+
+    /// Escribe el campo nuevamente en formato CSV, de manera que se lea con el mismo valor.
+    pub fn to_csv(&self, null_marker: &str) -> String {
+        if self.is_null(null_marker) {
+            null_marker.to_string()
+        } else {
+            escape_csv_value(&self.value, null_marker)
+        }
+    }
+}
 
 /// Extrae el encabezado de un archivo CSV.
 ///
@@ -22,33 +53,255 @@ const CSV_SEPARATOR: &str = ",";
 ///
 /// Devuelve un `Result` que contiene un `Vec<String>` con los nombres de las columnas si tiene éxito, o un `Errored` en caso de error.
 pub fn extract_header(reader: &mut BufReader<&File>) -> Result<Vec<String>, Errored> {
-    let mut header = String::new();
-    reader.read_line(&mut header)?;
-    Ok(split_csv(&header))
+    let header = read_csv_record(reader)?.unwrap_or_default();
+    split_csv(&header)
+}
+
+/// Lee el siguiente registro de un archivo CSV.
+///
+/// Un registro normalmente ocupa una única línea, pero si contiene un campo entre comillas
+/// con saltos de línea, se siguen leyendo líneas hasta que ese campo se cierre (ver `split_csv`).
+///
+/// # Parámetros
+///
+/// - `reader`: El lector desde el cual leer el registro.
+///
+/// # Retorna
+///
+/// Devuelve un `Result` con el registro completo sin el salto de línea final, o `None` si se llegó al final del archivo.
+///
+/// # Errores
+///
+/// Retorna un error si falla la lectura, si algún campo está mal formado o si el archivo termina
+/// dentro de un campo entre comillas.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::utils::files::read_csv_record;
+/// let mut reader = "1,\"linea\nsiguiente\"\n2,otra\n".as_bytes();
+/// let first = read_csv_record(&mut reader).unwrap();
+/// assert_eq!(first, Some("1,\"linea\nsiguiente\"".to_string()));
+/// let second = read_csv_record(&mut reader).unwrap();
+/// assert_eq!(second, Some("2,otra".to_string()));
+/// assert_eq!(read_csv_record(&mut reader).unwrap(), None);
+/// ```
+pub fn read_csv_record<R: BufRead>(reader: &mut R) -> Result<Option<String>, Errored> {
+    let mut record = String::new();
+    loop {
+        if reader.read_line(&mut record)? == 0 {
+            if record.is_empty() {
+                return Ok(None);
+            }
+            errored!(Table, "unterminated quoted field in record: {}", record)
+        }
+        if scan_csv(&record)?.is_some() {
+            break;
+        }
+    }
+    let end = record.trim_end_matches(['\n', '\r']).len();
+    record.truncate(end);
+    Ok(Some(record))
+}
+
+/// Iterador sobre los registros de un archivo CSV, leídos con `read_csv_record`.
+///
+/// Es el equivalente a `BufRead::lines` para archivos que pueden contener campos
+/// con saltos de línea.
+pub struct CsvRecords<R: BufRead> {
+    reader: R,
+}
+
+/// Crea un iterador sobre los registros restantes de un lector CSV.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::utils::files::csv_records;
+/// let records: Vec<String> = csv_records("a,b\n\"c\nd\",e\n".as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(records, vec!["a,b", "\"c\nd\",e"]);
+/// ```
+pub fn csv_records<R: BufRead>(reader: R) -> CsvRecords<R> {
+    CsvRecords { reader }
 }
 
-/// Divide una línea CSV en un vector de strings.
+impl<R: BufRead> Iterator for CsvRecords<R> {
+    type Item = Result<String, Errored>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        read_csv_record(&mut self.reader).transpose()
+    }
+}
+
+/// Divide un registro CSV en un vector de strings.
+///
+/// Sigue el formato de la RFC 4180: los campos pueden estar entre comillas dobles, en cuyo
+/// caso pueden contener comas, saltos de línea y comillas escapadas como `""`. Los espacios
+/// alrededor de un campo sin comillas se descartan, mientras que los que están dentro de las
+/// comillas se conservan.
 ///
 /// # Parámetros
 ///
-/// - `line`: La línea CSV que se desea dividir.
+/// - `line`: El registro CSV que se desea dividir.
 ///
 /// # Retorna
 ///
 /// Devuelve un `Vec<String>` con los valores separados.
 ///
+/// # Errores
+///
+/// Retorna un error si algún campo está mal formado: si un campo sin comillas contiene una
+/// comilla, si después de cerrar las comillas de un campo hay algo más que espacios, o si
+/// las comillas de un campo no se cierran.
+///
 /// # Ejemplo
 ///
 /// ```rust
 /// use rustic_sql::utils::files::split_csv;
 /// let line = "id, id_cliente ,      email ";
-/// let result = split_csv(line);
+/// let result = split_csv(line).unwrap();
 /// println!("{:?}", result); // Imprime ["id", "id_cliente", "email"]
+///
+/// let quoted = split_csv("1,\" Doe, John \",\"dice \"\"hola\"\"\"").unwrap();
+/// assert_eq!(quoted, vec!["1", " Doe, John ", "dice \"hola\""]);
+/// assert!(split_csv("\"ab\"c").is_err());
+/// ```
+pub fn split_csv(line: &str) -> Result<Vec<String>, Errored> {
+    let fields = split_csv_fields(line)?;
+    Ok(fields.into_iter().map(|field| field.value).collect())
+}
+
+/// Divide un registro CSV en sus campos, indicando cuáles estaban entre comillas (ver `split_csv`).
+///
+/// # Errores
+///
+/// Retorna un error si algún campo está mal formado.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::utils::files::split_csv_fields;
+/// let fields = split_csv_fields("1,,\"\"").unwrap();
+/// assert!(!fields[1].quoted && fields[1].value.is_empty());
+/// assert!(fields[2].quoted && fields[2].value.is_empty());
 /// ```
-pub fn split_csv(line: &str) -> Vec<String> {
-    line.split(CSV_SEPARATOR)
-        .map(|s| s.trim().to_string())
-        .collect::<Vec<String>>()
+pub fn split_csv_fields(line: &str) -> Result<Vec<CsvField>, Errored> {
+    match scan_csv(line.trim_end_matches(['\n', '\r']))? {
+        Some(fields) => Ok(fields),
+        None => errored!(Table, "unterminated quoted field in record: {}", line),
+    }
+}
+
+/// Recorre un registro CSV campo por campo, siguiendo la RFC 4180.
+///
+/// Un campo está entre comillas solo si la comilla es su primer caracter (sin contar los
+/// espacios). Dentro de un campo sin comillas no puede haber comillas, y después de cerrar
+/// las comillas de un campo solo puede haber espacios hasta el próximo separador.
+///
+/// # Retorna
+///
+/// Los campos del registro, o `None` si el registro termina dentro de un campo entre comillas
+/// (es decir, el campo continúa en la línea siguiente).
+///
+/// # Errores
+///
+/// Retorna un error si algún campo está mal formado.
+fn scan_csv(line: &str) -> Result<Option<Vec<CsvField>>, Errored> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            CSV_QUOTE if in_quotes && chars.peek() == Some(&CSV_QUOTE) => {
+                chars.next();
+                field.push(CSV_QUOTE);
+            }
+            CSV_QUOTE if in_quotes => in_quotes = false,
+            _ if in_quotes => field.push(c),
+            CSV_SEPARATOR => {
+                fields.push(finish_field(mem::take(&mut field), quoted));
+                quoted = false;
+            }
+            CSV_QUOTE if !quoted && field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                in_quotes = true;
+            }
+            c if quoted && c.is_whitespace() => {}
+            _ if quoted || c == CSV_QUOTE => {
+                errored!(Table, "malformed quoted field in record: {}", line)
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Ok(None);
+    }
+    fields.push(finish_field(field, quoted));
+    Ok(Some(fields))
+}
+
+/// Termina de leer un campo, descartando los espacios si no estaba entre comillas.
+fn finish_field(field: String, quoted: bool) -> CsvField {
+    let value = if quoted {
+        field
+    } else {
+        field.trim().to_string()
+    };
+    CsvField { value, quoted }
+}
+
+/// Escapa un valor para escribirlo como campo de un registro CSV.
+///
+/// El valor se encierra entre comillas dobles si contiene comas, comillas, saltos de línea
+/// o espacios al principio o al final, duplicando las comillas que contenga.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::utils::files::escape_csv;
+/// assert_eq!(escape_csv("Laptop"), "Laptop");
+/// assert_eq!(escape_csv("Doe, John"), "\"Doe, John\"");
+/// assert_eq!(escape_csv("dice \"hola\""), "\"dice \"\"hola\"\"\"");
+/// ```
+pub fn escape_csv(value: &str) -> String {
+    let needs_quotes =
+        value.contains([CSV_SEPARATOR, CSV_QUOTE, '\n', '\r']) || value.trim() != value;
+    if needs_quotes {
+        quote_csv(value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escapa un valor no nulo de una tabla para escribirlo como campo de un registro CSV.
+///
+/// Además de los casos de `escape_csv`, el valor se encierra entre comillas si coincide con
+/// la representación de `NULL` de la tabla, para que no se lea como `NULL`.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use rustic_sql::utils::files::escape_csv_value;
+/// assert_eq!(escape_csv_value("", ""), "\"\"");
+/// assert_eq!(escape_csv_value("\\N", "\\N"), "\"\\N\"");
+/// assert_eq!(escape_csv_value("Laptop", "\\N"), "Laptop");
+/// ```
+pub fn escape_csv_value(value: &str, null_marker: &str) -> String {
+    if value == null_marker {
+        quote_csv(value)
+    } else {
+        escape_csv(value)
+    }
+}
+
+/// Encierra un valor entre comillas dobles, duplicando las comillas que contenga.
+fn quote_csv(value: &str) -> String {
+    format!("\"{}\"", value.replace(CSV_QUOTE, "\"\""))
 }
 
 /// Obtiene la ruta completa del archivo CSV para una tabla dada.
@@ -61,8 +314,13 @@ pub fn split_csv(line: &str) -> Vec<String> {
 /// # Retorna
 ///
 /// Devuelve un `Result` que contiene un `PathBuf` con la ruta al archivo de la tabla si tiene éxito, o un `Errored` en caso de error.
+///
+/// # Errores
+///
+/// Retorna un error si el nombre de la tabla es inválido (ver `build_table_path`) o si la
+/// tabla no existe en el directorio.
 pub fn get_table_path(dir_path: &Path, table_name: &str) -> Result<PathBuf, Errored> {
-    let table_path = dir_path.join(table_name).with_extension(CSV_EXTENSION);
+    let table_path = build_table_path(dir_path, table_name)?;
     if !table_path.is_file() {
         errored!(
             Default,
@@ -74,8 +332,38 @@ pub fn get_table_path(dir_path: &Path, table_name: &str) -> Result<PathBuf, Erro
     Ok(table_path)
 }
 
+/// Construye la ruta del archivo CSV para una tabla dada, sin verificar que exista.
+///
+/// Es utilizada al crear o eliminar tablas, donde la existencia del archivo depende de la consulta.
+///
+/// # Errores
+///
+/// Retorna un error si el nombre de la tabla es vacío o contiene puntos o separadores de ruta,
+/// ya que no correspondería a un archivo `tabla.csv` dentro del directorio.
+///
+/// # Ejemplo
+///
+/// ```rust
+/// use std::path::Path;
+/// use rustic_sql::utils::files::build_table_path;
+/// let path = build_table_path(Path::new("tablas"), "ordenes").unwrap();
+/// assert_eq!(path, Path::new("tablas/ordenes.csv"));
+/// assert!(build_table_path(Path::new("tablas"), "ordenes.backup").is_err());
+/// ```
+pub fn build_table_path(dir_path: &Path, table_name: &str) -> Result<PathBuf, Errored> {
+    let invalid = table_name.is_empty() || table_name.contains(['.', '/', '\\']);
+    if invalid {
+        errored!(Table, "invalid table name: {}", table_name)
+    }
+    Ok(dir_path.join(format!("{}.{}", table_name, CSV_EXTENSION)))
+}
+
 /// Genera un identificador único para un archivo temporal.
 ///
+/// El identificador combina el proceso, el hilo y un contador, por lo que dos llamadas nunca
+/// devuelven el mismo identificador, aunque se hagan desde el mismo hilo (por ejemplo, al
+/// materializar tablas virtuales anidadas con el mismo nombre).
+///
 /// # Retorna
 ///
 /// Devuelve un `u64` que representa el identificador único.
@@ -88,9 +376,12 @@ pub fn get_table_path(dir_path: &Path, table_name: &str) -> Result<PathBuf, Erro
 /// println!("{}", id);
 /// ```
 pub fn get_temp_id() -> u64 {
-    let id = thread::current().id();
     let mut hasher = DefaultHasher::new();
-    id.hash(&mut hasher);
+    process::id().hash(&mut hasher);
+    thread::current().id().hash(&mut hasher);
+    TEMP_COUNTER
+        .fetch_add(1, Ordering::Relaxed)
+        .hash(&mut hasher);
     hasher.finish()
 }
 
@@ -151,6 +442,38 @@ pub fn delete_temp_file(table_path: &Path, temp_path: &Path) -> Result<(), Error
     Ok(())
 }
 
+/// Reescribe una tabla a través de un archivo temporal.
+///
+/// Crea el archivo temporal de la tabla, escribe en él mediante `write` y, si la escritura fue
+/// exitosa, lo renombra para reemplazar a la tabla. Si ocurre cualquier error, el archivo
+/// temporal se elimina y la tabla queda sin cambios, por lo que puede seguir modificándose
+/// en consultas posteriores.
+///
+/// # Parámetros
+///
+/// - `table_name`: El nombre de la tabla.
+/// - `table_path`: La ruta al archivo de la tabla.
+/// - `write`: La función que escribe el contenido completo de la tabla en el archivo temporal.
+///
+/// # Errores
+///
+/// Retorna el error de `write`, o uno propio si falla la creación, la escritura o el renombrado
+/// del archivo temporal.
+pub fn rewrite_table<F>(table_name: &str, table_path: &Path, write: F) -> Result<(), Errored>
+where
+    F: FnOnce(&mut BufWriter<File>) -> Result<(), Errored>,
+{
+    let (temp_table, temp_path) = get_temp_file(table_name, table_path)?;
+    let mut writer = BufWriter::new(temp_table);
+    let written = write(&mut writer).and_then(|_| Ok(writer.flush()?));
+    drop(writer);
+    let result = written.and_then(|_| delete_temp_file(table_path, &temp_path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
 /// Asegura que un archivo termine en una nueva línea.
 ///
 /// # Parámetros
@@ -226,10 +549,60 @@ mod tests {
     #[test]
     fn test_split_csv() {
         let line = "id, id_cliente ,      email ";
-        let result = split_csv(line);
+        let result = split_csv(line).unwrap();
         assert_eq!(result, vec!["id", "id_cliente", "email"]);
     }
 
+    #[test]
+    fn test_split_csv_quoted_fields() {
+        let line = "1, \"  con espacios \" ,\"a,b\",\"\"\"citado\"\"\",\"\"";
+        let result = split_csv(line).unwrap();
+        assert_eq!(
+            result,
+            vec!["1", "  con espacios ", "a,b", "\"citado\"", ""]
+        );
+    }
+
+    #[test]
+    fn test_split_csv_embedded_newline() {
+        let result = split_csv("1,\"primera\nsegunda\"\r\n").unwrap();
+        assert_eq!(result, vec!["1", "primera\nsegunda"]);
+    }
+
+    #[test]
+    fn test_escape_csv_round_trip() {
+        let values = [
+            "simple",
+            " espacios ",
+            "a,b",
+            "\"citado\"",
+            "linea\nnueva",
+            "",
+        ];
+        let line = values.map(escape_csv).join(",");
+        assert_eq!(split_csv(&line).unwrap(), values);
+    }
+
+    #[test]
+    fn test_split_csv_malformed_fields() {
+        assert!(split_csv("1,\"ab\"c,2").is_err());
+        assert!(split_csv("1,5'10\",2").is_err());
+        assert!(split_csv("1,\"sin cerrar").is_err());
+        assert_eq!(split_csv("1,\"ab\"  ,2").unwrap(), vec!["1", "ab", "2"]);
+    }
+
+    #[test]
+    fn test_read_csv_record_stray_quote() {
+        let mut reader = "1,5'10\",2\n3,\"a\",4\n5,\"b\",6\n".as_bytes();
+        assert!(read_csv_record(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_read_csv_record_unterminated() {
+        let mut reader = "1,\"sin cerrar\n2,otro\n".as_bytes();
+        assert!(read_csv_record(&mut reader).is_err());
+    }
+
     #[test]
     fn test_get_bad_table_path() {
         let dir = Path::new("/dir/sin_unit_tables");
@@ -238,6 +611,15 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_dotted_table_name_is_invalid() {
+        let dir = Path::new("tests/unit_tables");
+        assert!(get_table_path(dir, "ordenes").is_ok());
+        assert!(get_table_path(dir, "ordenes.bak").is_err());
+        assert!(get_table_path(dir, "../unit_tables/ordenes").is_err());
+        assert!(build_table_path(dir, "").is_err());
+    }
+
     #[test]
     fn test_get_temp_file() {
         let table_name = "ordenes";
@@ -248,6 +630,16 @@ mod tests {
         fs::remove_file(temp_path).unwrap();
     }
 
+    #[test]
+    fn test_get_temp_file_twice_from_same_thread() {
+        let table_path = Path::new("tests/tables");
+        let (_, first) = get_temp_file("ordenes", table_path).unwrap();
+        let second = get_temp_file("ordenes", table_path);
+        fs::remove_file(first).unwrap();
+        let (_, second) = second.unwrap();
+        fs::remove_file(second).unwrap();
+    }
+
     #[test]
     fn test_delete_temp_file() {
         let table_path = Path::new("tests/unit_tables/ordenes.csv");
@@ -257,6 +649,20 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_rewrite_table_failure_removes_temp_file() {
+        let table_path = Path::new("tests/unit_tables/ordenes.csv");
+        let before = fs::read_to_string(table_path).unwrap();
+        let result = rewrite_table("ordenes", table_path, |writer| {
+            writeln!(writer, "id")?;
+            errored!(Default, "failed while writing")
+        });
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(table_path).unwrap(), before);
+        let (_, temp_path) = get_temp_file("ordenes", table_path).unwrap();
+        fs::remove_file(temp_path).unwrap();
+    }
+
     #[test]
     fn test_delete_non_temporary_file_error() {
         let table_path = Path::new("tests/unit_tables/ordenes.csv");
@@ -38,3 +38,13 @@ fn test_delete_none() {
     let delete_query = "DELETE FROM pokemon WHERE type = 'Sound'";
     test.verify_no_changes("pokemon.csv".to_string(), delete_query);
 }
+
+#[test]
+fn test_delete_with_subquery() {
+    let test = RusticSQLTest::default();
+    let delete_query = "DELETE FROM users WHERE user_id NOT IN (SELECT user_id FROM orders)";
+    let result = test.run_for(delete_query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT COUNT(*) FROM users";
+    test.assert_row(select_query, &["6"]);
+}
@@ -20,8 +20,17 @@ fn test_insert_nothing() {
     let test = RusticSQLTest::default();
     let query = "INSERT INTO users VALUES (14, 'Solidus Snake', 'solidus.snake@mgs.com', 40)";
     let result = test.run_for(query.to_string());
-    print!("{:?}", &result);
-    assert!(result.is_err());
+    assert!(result.is_ok());
+    let select_query = "SELECT * FROM users WHERE user_id = 14";
+    test.assert_row(
+        select_query,
+        &["14", "Solidus Snake", "solidus.snake@mgs.com", "40"],
+    );
+
+    let query = "INSERT INTO users VALUES (15, 'Raiden', 'raiden@mgs.com')";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("expected 4 columns")));
+    test.assert_row("SELECT COUNT(*) FROM users", &["11"]);
 }
 
 #[test]
@@ -62,3 +71,179 @@ fn test_insert_invalid_table() {
     let result = test.run_for(insert_query.to_string());
     assert!(result.is_err_and(|e| e.to_string().contains("table")));
 }
+
+#[test]
+fn test_insert_values_with_commas_and_quotes() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO users (user_id, name, email, age) VALUES (17, 'Snake, \"Solid\"', '  padded  ', 35)";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT name, email, age FROM users WHERE user_id = 17";
+    test.assert_row(
+        select_query,
+        &["\"Snake, \"\"Solid\"\"\"", "\"  padded  \"", "35"],
+    );
+    let select_query = "SELECT user_id FROM users WHERE name = 'Snake, \"Solid\"'";
+    test.assert_row(select_query, &["17"]);
+}
+
+#[test]
+fn test_insert_respects_schema_types() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO stores (store_id, city, zip_code) VALUES (5, 'Portland', '04101')";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT * FROM stores WHERE zip_code = '04101'";
+    test.assert_row(select_query, &["5", "Portland", "04101"]);
+}
+
+#[test]
+fn test_insert_invalid_type_for_schema() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO stores (store_id, city) VALUES ('five', 'Portland')";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err());
+    let select_query = "SELECT * FROM stores WHERE city = 'Portland'";
+    test.assert_row(select_query, &[]);
+}
+
+#[test]
+fn test_insert_null_values() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO stores (store_id, city, zip_code) VALUES (6, NULL, NULL)";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    test.assert_row(
+        "SELECT * FROM stores WHERE store_id = 6",
+        &["6", "\\N", "\\N"],
+    );
+}
+
+#[test]
+fn test_insert_omitted_columns_are_null() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO stores (store_id, city) VALUES (6, '')";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT store_id FROM stores WHERE city IS NOT NULL AND zip_code IS NULL";
+    let result = test.run_and_get_rows(select_query.to_string());
+    assert_eq!(vec!["store_id", "5", "6"], result);
+
+    let query = "INSERT INTO users (user_id, name) VALUES (11, 'Ivy Young')";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    test.assert_row("SELECT name FROM users WHERE age IS NULL", &["Ivy Young"]);
+}
+
+#[test]
+fn test_insert_select_from_another_table() {
+    let test = RusticSQLTest::default();
+    let query = "CREATE TABLE archive (id INT, name TEXT, years INT)";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let query = "INSERT INTO archive (id, name, years) SELECT user_id, name, age FROM users WHERE age > 35 ORDER BY age";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let query = "INSERT INTO archive (id, name) SELECT user_id, UPPER(name) FROM users WHERE age < 26 UNION ALL SELECT order_id, product FROM orders WHERE order_id = 1";
+    assert!(test.run_for(query.to_string()).is_ok());
+
+    let result = test.run_and_get_rows("SELECT * FROM archive".to_string());
+    assert_eq!(
+        vec![
+            "id,name,years",
+            "10,Henry Clark,38",
+            "8,Frank Miller,40",
+            "4,Bob Brown,45",
+            "9,GRACE LEE,",
+            "1,Laptop,"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_insert_select_from_same_table() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO users (user_id, name, age) SELECT user_id + 100, name, age FROM users WHERE age > 40";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let result = test
+        .run_and_get_rows("SELECT user_id, name, age FROM users WHERE user_id > 10".to_string());
+    assert_eq!(vec!["user_id,name,age", "104,Bob Brown,45"], result);
+    test.assert_row("SELECT COUNT(*) FROM users", &["11"]);
+}
+
+#[test]
+fn test_insert_select_invalid() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO stores (store_id, city) SELECT * FROM users";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("expected 2 columns")));
+
+    let query = "INSERT INTO stores (store_id, city) SELECT user_id FROM users";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("expected 2 columns")));
+
+    let query = "INSERT INTO stores (store_id) SELECT user_id FROM kojima";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("table")));
+    test.assert_row("SELECT COUNT(*) FROM stores", &["5"]);
+}
+
+#[test]
+fn test_insert_values_with_expressions() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO users (user_id, name, email, age) VALUES (11, CONCAT('Ivy', ' Young'), DEFAULT, 3 * 7), ((SELECT MAX(user_id) FROM users) + 1, UPPER('raiden'), NULL, (2 + 3) * 6)";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let result = test.run_and_get_rows("SELECT * FROM users WHERE user_id > 10".to_string());
+    assert_eq!(
+        vec![
+            "user_id,name,email,age",
+            "11,Ivy Young,,21",
+            "11,RAIDEN,,30"
+        ],
+        result
+    );
+
+    let query = "INSERT INTO users (user_id, name) VALUES (user_id + 1, 'Raiden')";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("constant expressions")));
+}
+
+#[test]
+fn test_insert_select_without_columns() {
+    let test = RusticSQLTest::default();
+    let query = "INSERT INTO stores SELECT store_id + 10, UPPER(city), zip_code FROM stores WHERE store_id < 3";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let result = test.run_and_get_rows("SELECT * FROM stores WHERE store_id > 10".to_string());
+    assert_eq!(
+        vec![
+            "store_id,city,zip_code",
+            "11,BOSTON,02110",
+            "12,NEW YORK,10001"
+        ],
+        result
+    );
+
+    let query = "INSERT INTO stores SELECT store_id, city FROM stores";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("expected 3 columns")));
+}
+
+#[test]
+fn test_failed_insert_writes_no_rows() {
+    let test = RusticSQLTest::default();
+    let query = "CREATE TABLE archive (id INT, who TEXT)";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let query = "INSERT INTO archive (id, who) SELECT CASE WHEN user_id = 3 THEN 'x' ELSE user_id END, name FROM users";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "INSERT INTO archive (id, who) SELECT 10 / (3 - user_id), name FROM users";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "INSERT INTO archive (id, who) VALUES (50, 'a'), ('bad', 'b')";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "INSERT INTO archive (id, who) VALUES (50, 'a'), (1 / 0, 'b')";
+    assert!(test.run_for(query.to_string()).is_err());
+    test.assert_row("SELECT COUNT(*) FROM archive", &["0"]);
+
+    let query = "INSERT INTO archive (id, who) VALUES (50, 'a'), (51, 'b')";
+    assert!(test.run_for(query.to_string()).is_ok());
+    test.assert_row("SELECT COUNT(*) FROM archive", &["2"]);
+}
@@ -110,3 +110,1070 @@ fn test_select_with_invalid_table() {
     let result = test.run_for(query.to_string());
     assert!(result.is_err_and(|x| x.to_string().contains("exist")))
 }
+
+#[test]
+fn test_select_inner_join() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT users.name, orders.product FROM users INNER JOIN orders ON users.user_id = orders.user_id WHERE orders.quantity > 1 ORDER BY orders.order_id";
+
+    let expected_rows: Vec<String> = [
+        vec!["John Doe", "Mouse"],
+        vec!["Alice Johnson", "Headphones"],
+        vec!["Eve Adams", "Monitor"],
+        vec!["Frank Miller", "Keyboard"],
+    ]
+    .iter()
+    .map(|r| r.join(","))
+    .collect();
+    let expected_header: String = ["users.name", "orders.product"].join(",");
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(expected_header, result[0]);
+    assert_eq!(expected_rows, result[1..]);
+}
+
+#[test]
+fn test_select_left_join() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, product FROM users LEFT JOIN orders ON users.user_id = orders.user_id WHERE age < 30 ORDER BY name, product";
+
+    let expected_rows: Vec<String> = [
+        vec!["Alice Johnson", "Headphones"],
+        vec!["Alice Johnson", "Keyboard"],
+        vec!["Alice Johnson", "Mouse"],
+        vec!["David Wilson", ""],
+        vec!["Grace Lee", ""],
+        vec!["John Doe", "Laptop"],
+        vec!["John Doe", "Mouse"],
+    ]
+    .iter()
+    .map(|r| r.join(","))
+    .collect();
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!("name,product", result[0]);
+    assert_eq!(expected_rows, result[1..]);
+}
+
+#[test]
+fn test_select_join_on_with_subquery() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT COUNT(*) FROM users JOIN orders ON users.user_id = orders.user_id AND orders.price > (SELECT AVG(price) FROM orders)";
+    test.assert_row(query, &["3"]);
+}
+
+#[test]
+fn test_select_join_with_ambiguous_column() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id FROM users JOIN orders ON users.user_id = orders.user_id";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("ambiguous")))
+}
+
+#[test]
+fn test_select_qualified_columns_must_match_table() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT users.name FROM users WHERE users.user_id = 1";
+    test.assert_row(query, &["John Doe"]);
+    let query = "SELECT u.name FROM users u WHERE users.user_id = 1";
+    test.assert_row(query, &["John Doe"]);
+
+    let query = "SELECT name FROM users WHERE nope.name = 'x'";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("INVALID_COLUMN")));
+    let query = "SELECT name FROM users u ORDER BY orders.name";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "UPDATE users SET age = 1 WHERE orders.user_id = 1";
+    assert!(test.run_for(query.to_string()).is_err());
+}
+
+#[test]
+fn test_select_group_by_with_aggregates() {
+    let test = RusticSQLTest::default();
+    let query =
+        "SELECT user_id, COUNT(*), SUM(price) FROM orders GROUP BY user_id ORDER BY user_id";
+
+    let expected_rows: Vec<String> = [
+        vec!["1", "2", "1225"],
+        vec!["2", "1", "300"],
+        vec!["3", "3", "150"],
+        vec!["5", "1", "1200"],
+        vec!["7", "1", "300"],
+        vec!["8", "2", "1245"],
+    ]
+    .iter()
+    .map(|r| r.join(","))
+    .collect();
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!("user_id,COUNT(*),SUM(price)", result[0]);
+    assert_eq!(expected_rows, result[1..]);
+}
+
+#[test]
+fn test_select_aggregates_without_group_by() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT COUNT(*), MIN(age), MAX(age), AVG(age) FROM users WHERE age > 0";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!("COUNT(*),MIN(age),MAX(age),AVG(age)", result[0]);
+    assert_eq!(vec!["10,25,45,33"], result[1..]);
+}
+
+#[test]
+fn test_select_group_by_ordered_by_aggregate() {
+    let test = RusticSQLTest::default();
+    let query =
+        "SELECT product, COUNT(*) FROM orders GROUP BY product ORDER BY COUNT(*) DESC, product";
+
+    let expected_rows: Vec<String> = [
+        vec!["Laptop", "3"],
+        vec!["Keyboard", "2"],
+        vec!["Monitor", "2"],
+        vec!["Mouse", "2"],
+        vec!["Headphones", "1"],
+    ]
+    .iter()
+    .map(|r| r.join(","))
+    .collect();
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(expected_rows, result[1..]);
+}
+
+#[test]
+fn test_select_group_by_invalid_column() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT COUNT(*) FROM orders GROUP BY store";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("exist")))
+}
+
+#[test]
+fn test_select_ungrouped_columns_are_rejected() {
+    let test = RusticSQLTest::default();
+    let queries = [
+        "SELECT name, COUNT(*) FROM users GROUP BY age",
+        "SELECT name FROM users HAVING COUNT(*) > 1",
+        "SELECT COUNT(*) FROM users HAVING age > 30",
+        "SELECT age, COUNT(*) FROM users GROUP BY age ORDER BY name",
+        "SELECT age * user_id, COUNT(*) FROM users GROUP BY age",
+    ];
+    for query in queries {
+        let result = test.run_for(query.to_string());
+        assert!(
+            result.is_err_and(|x| x.to_string().contains("GROUP BY")),
+            "{}",
+            query
+        );
+    }
+
+    let query = "SELECT u.age, COUNT(*) AS total FROM users u GROUP BY age ORDER BY total DESC, u.age LIMIT 1";
+    test.assert_row(query, &["25", "1"]);
+}
+
+#[test]
+fn test_select_group_by_with_having() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id, COUNT(*) FROM orders GROUP BY user_id HAVING COUNT(*) > 1 AND SUM(price) > 200 ORDER BY user_id";
+
+    let expected_rows: Vec<String> = [vec!["1", "2"], vec!["8", "2"]]
+        .iter()
+        .map(|r| r.join(","))
+        .collect();
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!("user_id,COUNT(*)", result[0]);
+    assert_eq!(expected_rows, result[1..]);
+}
+
+#[test]
+fn test_select_with_limit_and_offset() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, age FROM users ORDER BY age DESC LIMIT 3 OFFSET 1";
+
+    let expected_rows: Vec<String> = [
+        vec!["Frank Miller", "40"],
+        vec!["Henry Clark", "38"],
+        vec!["Jane Smith", "34"],
+    ]
+    .iter()
+    .map(|r| r.join(","))
+    .collect();
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!("name,age", result[0]);
+    assert_eq!(expected_rows, result[1..]);
+}
+
+#[test]
+fn test_select_with_limit_without_ordering() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id FROM users WHERE age > 30 LIMIT 2";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id", "2", "4"], result);
+}
+
+#[test]
+fn test_select_with_offset_past_end() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id FROM users ORDER BY user_id LIMIT 5 OFFSET 20";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id"], result);
+}
+
+#[test]
+fn test_select_limit_is_prefix_of_sort_with_ties() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT order_id FROM orders ORDER BY price DESC";
+    let sorted = test.run_and_get_rows(query.to_string());
+    for limit in 1..sorted.len() {
+        let query = format!(
+            "SELECT order_id FROM orders ORDER BY price DESC LIMIT {}",
+            limit
+        );
+        let result = test.run_and_get_rows(query);
+        assert_eq!(sorted[..=limit], result, "LIMIT {}", limit);
+    }
+}
+
+#[test]
+fn test_select_with_huge_or_negative_limit() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id FROM users ORDER BY user_id LIMIT 18446744073709551615 OFFSET 8";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id", "9", "10"], result);
+    let query = "SELECT user_id FROM users LIMIT 18446744073709551615 OFFSET 9";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id", "10"], result);
+
+    let query = "SELECT user_id FROM users LIMIT -1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|e| e.to_string().contains("INVALID_SYNTAX")));
+}
+
+#[test]
+fn test_select_group_by_with_limit() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id, COUNT(*) FROM orders GROUP BY user_id ORDER BY COUNT(*) DESC, user_id LIMIT 2";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id,COUNT(*)", "3,3", "1,2"], result);
+}
+
+#[test]
+fn test_select_distinct() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT DISTINCT product FROM orders ORDER BY product";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "product",
+            "Headphones",
+            "Keyboard",
+            "Laptop",
+            "Monitor",
+            "Mouse"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_distinct_with_limit() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT DISTINCT user_id FROM orders LIMIT 3";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id", "1", "2", "3"], result);
+}
+
+#[test]
+fn test_select_count_distinct() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT COUNT(DISTINCT product), COUNT(product) FROM orders";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["COUNT(DISTINCT product),COUNT(product)", "5,10"],
+        result
+    );
+}
+
+#[test]
+fn test_select_text_column_from_schema() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT city, zip_code FROM stores WHERE zip_code >= '01000' ORDER BY zip_code";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "city,zip_code",
+            "Springfield,01101",
+            "Boston,02110",
+            "New York,10001"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_schema_type_mismatch() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT city FROM stores WHERE zip_code = 501";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_select_arithmetic_projection() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT product, price * quantity FROM orders WHERE price * quantity >= 600";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "product,price * quantity",
+            "Laptop,1200",
+            "Laptop,1200",
+            "Monitor,600",
+            "Laptop,1200"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_arithmetic_precedence() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT order_id, (price + 5) * quantity - 10 % 4 FROM orders WHERE order_id = 2";
+
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["order_id,((price + 5) * quantity) - (10 % 4)", "2,58"],
+        result
+    );
+}
+
+#[test]
+fn test_select_division_by_zero() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT price / 0 FROM orders";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_select_is_null() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT city FROM stores WHERE zip_code IS NULL";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["city", "Augusta"], result);
+
+    let query = "SELECT COUNT(*), COUNT(zip_code) FROM stores WHERE zip_code IS NOT NULL";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*),COUNT(zip_code)", "4,4"], result);
+}
+
+#[test]
+fn test_select_null_comparisons_are_unknown() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT store_id FROM stores WHERE NOT zip_code = '02110' OR zip_code = NULL";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["store_id", "2", "3", "4"], result);
+
+    let query = "SELECT city, zip_code FROM stores WHERE zip_code IS NULL OR store_id = 1";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["city,zip_code", "Boston,02110", "Augusta,\\N"], result);
+}
+
+#[test]
+fn test_select_left_join_fills_null() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT users.name FROM users LEFT JOIN orders ON users.user_id = orders.user_id WHERE orders.order_id IS NULL ORDER BY users.name";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "users.name",
+            "Bob Brown",
+            "David Wilson",
+            "Grace Lee",
+            "Henry Clark"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_like() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE name LIKE '%a_is' OR name LIKE '_ve %' ORDER BY name";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Charlie Davis", "Eve Adams"], result);
+
+    let query = "SELECT user_id FROM users WHERE email NOT LIKE '%.%@%' AND user_id <= 3";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id"], result);
+}
+
+#[test]
+fn test_select_ilike() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE name ILIKE 'j%' ORDER BY name";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Jane Smith", "John Doe"], result);
+
+    let query = "SELECT COUNT(*) FROM users WHERE name LIKE 'j%'";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "0"], result);
+}
+
+#[test]
+fn test_select_correlated_subquery_is_rejected() {
+    let test = RusticSQLTest::default();
+    let queries = [
+        "SELECT name FROM users WHERE EXISTS (SELECT 1 FROM orders WHERE orders.user_id = users.user_id)",
+        "SELECT name FROM users u WHERE 1 < (SELECT COUNT(*) FROM orders WHERE user_id = u.user_id)",
+        "SELECT name FROM users WHERE user_id IN (SELECT user_id FROM orders WHERE price > users.age)",
+        "SELECT name FROM users WHERE EXISTS (SELECT order_id FROM orders WHERE age > 30)",
+    ];
+    for query in queries {
+        let result = test.run_for(query.to_string());
+        assert!(
+            result.is_err_and(|e| e.to_string().contains("correlated")),
+            "{}",
+            query
+        );
+    }
+
+    let query = "SELECT COUNT(*) FROM users WHERE user_id IN (SELECT orders.user_id FROM orders)";
+    test.assert_row(query, &["6"]);
+}
+
+#[test]
+fn test_select_like_escape() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT COUNT(*) FROM users WHERE email LIKE '%!_%' ESCAPE '!'";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "0"], result);
+
+    let query = "SELECT COUNT(*) FROM users WHERE email LIKE '%_%' ESCAPE '!'";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "10"], result);
+}
+
+#[test]
+fn test_select_in_list() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE user_id IN (2, 4, 20) ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Jane Smith", "Bob Brown"], result);
+
+    let query = "SELECT COUNT(*) FROM users WHERE user_id NOT IN (1, 2, 3, 4, 5, 6, 7, 8, 9)";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "1"], result);
+}
+
+#[test]
+fn test_select_between() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE age BETWEEN 25 AND 28 AND name NOT BETWEEN 'A' AND 'E' ORDER BY age";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Grace Lee", "John Doe"], result);
+}
+
+#[test]
+fn test_select_in_subquery() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE user_id IN (SELECT user_id FROM orders WHERE product = 'Laptop') ORDER BY name";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["name", "Charlie Davis", "Frank Miller", "John Doe"],
+        result
+    );
+
+    let query = "SELECT COUNT(*) FROM users WHERE user_id NOT IN (SELECT user_id FROM orders)";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "4"], result);
+}
+
+#[test]
+fn test_select_exists_subquery() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT COUNT(*) FROM users WHERE EXISTS (SELECT * FROM orders WHERE quantity > 2)";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "10"], result);
+
+    let query =
+        "SELECT COUNT(*) FROM users WHERE NOT EXISTS (SELECT * FROM orders WHERE quantity > 2)";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "0"], result);
+}
+
+#[test]
+fn test_select_scalar_subquery() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE age > (SELECT age FROM users WHERE name = 'Jane Smith') ORDER BY age DESC";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["name", "Bob Brown", "Frank Miller", "Henry Clark"],
+        result
+    );
+
+    let query = "SELECT name FROM users WHERE age = (SELECT MAX(age) FROM users)";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Bob Brown"], result);
+}
+
+#[test]
+fn test_select_invalid_subqueries() {
+    let test = RusticSQLTest::default();
+    let queries = [
+        "SELECT name FROM users WHERE age > (SELECT age FROM users)",
+        "SELECT name FROM users WHERE user_id IN (SELECT user_id, product FROM orders)",
+        "SELECT name FROM users WHERE user_id IN (SELECT user_id FROM missing)",
+        "SELECT name FROM users WHERE user_id IN (SELECT user_id FROM orders",
+    ];
+    for query in queries {
+        assert!(test.run_for(query.to_string()).is_err(), "{}", query);
+    }
+}
+
+#[test]
+fn test_select_compared_with_average() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users WHERE age > (SELECT AVG(age) FROM users) ORDER BY age DESC";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "name",
+            "Bob Brown",
+            "Frank Miller",
+            "Henry Clark",
+            "Jane Smith"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_decimal_and_negative_numbers() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT order_id, price * 1.5, quantity * -1 FROM orders WHERE price < 45.5 AND quantity > -1 ORDER BY order_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "order_id,price * 1.5,quantity * -1",
+            "2,37.5,-2",
+            "4,67.5,-1",
+            "5,37.5,-1",
+            "9,67.5,-3"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_ordered_by_mixed_numbers() {
+    let test = RusticSQLTest::default();
+    let insert = "INSERT INTO orders (order_id, user_id, product, quantity, price) VALUES (11, 2, 'Cable', 1, 9.99)";
+    assert!(test.run_for(insert.to_string()).is_ok());
+
+    let query = "SELECT product, price FROM orders WHERE price < 30 ORDER BY price, order_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["product,price", "Cable,9.99", "Mouse,25", "Mouse,25"],
+        result
+    );
+}
+
+#[test]
+fn test_select_string_functions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT UPPER(name), LENGTH(name), SUBSTR(email, 1, 4) FROM users WHERE LOWER(name) LIKE 'j%' ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "UPPER(name),LENGTH(name),\"SUBSTR(email, 1, 4)\"",
+            "JOHN DOE,8,john",
+            "JANE SMITH,10,jane"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_nested_string_functions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT REPLACE(CONCAT(product, ' x', quantity), 'Laptop', 'Notebook') FROM orders WHERE order_id <= 3";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "\"REPLACE(CONCAT(product, ' x', quantity), 'Laptop', 'Notebook')\"",
+            "Notebook x1",
+            "Mouse x2",
+            "Monitor x1"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_invalid_string_functions() {
+    let test = RusticSQLTest::default();
+    let queries = [
+        "SELECT UPPER(name, email) FROM users",
+        "SELECT SUBSTR(name) FROM users",
+        "SELECT SUBSTR(name, 'a') FROM users",
+        "SELECT LOWER(missing) FROM users",
+    ];
+    for query in queries {
+        assert!(test.run_for(query.to_string()).is_err(), "{}", query);
+    }
+}
+
+#[test]
+fn test_select_compares_dates() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM events WHERE day > DATE '2024-01-31' ORDER BY day";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Review", "Retro"], result);
+
+    let query = "SELECT name FROM events WHERE starts_at < '2024-01-31' OR day = TIMESTAMP '2024-03-01 00:00:00' ORDER BY starts_at";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Launch", "Kickoff", "Retro"], result);
+
+    let query = "SELECT MIN(day), MAX(starts_at) FROM events WHERE day LIKE '2024-%'";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["MIN(day),MAX(starts_at)", "2024-01-15,2024-03-01 10:15:00"],
+        result
+    );
+}
+
+#[test]
+fn test_select_date_functions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, DATE_ADD(day, INTERVAL 1 MONTH), EXTRACT(HOUR FROM starts_at), DATE_TRUNC('month', starts_at) FROM events WHERE EXTRACT(YEAR FROM day) = 2024 AND day < DATE_SUB(DATE '2024-03-01', INTERVAL 1 DAY) ORDER BY day";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "name,\"DATE_ADD(day, INTERVAL 1 MONTH)\",EXTRACT(HOUR FROM starts_at),\"DATE_TRUNC('month', starts_at)\"",
+            "Kickoff,2024-02-15,9,2024-01-01 00:00:00",
+            "Planning,2024-02-29,14,2024-01-01 00:00:00"
+        ],
+        result
+    );
+
+    let query = "SELECT COUNT(*) FROM events WHERE starts_at < NOW()";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "5"], result);
+}
+
+#[test]
+fn test_select_invalid_dates() {
+    let test = RusticSQLTest::default();
+    let queries = [
+        "SELECT name FROM events WHERE day > DATE '2024-02-30'",
+        "SELECT name FROM events WHERE day > 'yesterday'",
+        "SELECT name FROM events WHERE day > 5",
+        "SELECT EXTRACT(WEEK FROM day) FROM events",
+        "SELECT DATE_ADD(day, 1) FROM events",
+        "SELECT DATE_TRUNC('decade', day) FROM events",
+    ];
+    for query in queries {
+        assert!(test.run_for(query.to_string()).is_err(), "{}", query);
+    }
+}
+
+#[test]
+fn test_select_case_expressions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, CASE WHEN age >= 40 THEN 'senior' WHEN age >= 30 THEN 'adult' ELSE 'young' END FROM users WHERE user_id <= 4 ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "name,CASE WHEN age >= 40 THEN 'senior' WHEN age >= 30 THEN 'adult' ELSE 'young' END",
+            "John Doe,young",
+            "Jane Smith,adult",
+            "Alice Johnson,young",
+            "Bob Brown,senior"
+        ],
+        result
+    );
+
+    let query = "SELECT order_id FROM orders WHERE CASE product WHEN 'Laptop' THEN quantity * 2 ELSE quantity END > 1 ORDER BY order_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["order_id", "1", "2", "6", "7", "8", "9", "10"], result);
+}
+
+#[test]
+fn test_select_with_column_aliases() {
+    let test = RusticSQLTest::default();
+    let query =
+        "SELECT name AS full_name, age AS years FROM users u WHERE u.age > 35 ORDER BY years DESC";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "full_name,years",
+            "Bob Brown,45",
+            "Frank Miller,40",
+            "Henry Clark,38"
+        ],
+        result
+    );
+
+    let query = "SELECT product, COUNT(*) AS total, SUM(price) AS revenue FROM orders GROUP BY product HAVING COUNT(*) > 2";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["product,total,revenue", "Laptop,3,3600"], result);
+}
+
+#[test]
+fn test_select_join_with_table_aliases() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT u.name AS customer, o.product FROM users AS u INNER JOIN orders o ON u.user_id = o.user_id WHERE o.quantity > 1 ORDER BY o.order_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "customer,o.product",
+            "John Doe,Mouse",
+            "Alice Johnson,Headphones",
+            "Eve Adams,Monitor",
+            "Frank Miller,Keyboard"
+        ],
+        result
+    );
+
+    let query =
+        "SELECT a.name, b.name FROM users a JOIN users b ON b.age = a.age + 1 ORDER BY a.user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "a.name,b.name",
+            "John Doe,Alice Johnson",
+            "David Wilson,John Doe",
+            "Eve Adams,Jane Smith"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_invalid_aliases() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name FROM users JOIN users ON users.user_id = users.user_id";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("more than once")));
+
+    let query = "SELECT name AS FROM users";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err());
+
+    let query = "SELECT u.name FROM users u JOIN orders o ON users.user_id = o.user_id";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_select_literal_and_computed_projection() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, 'customer' AS kind, 1, NULL, -2.5, age - 20 FROM users WHERE user_id <= 2 ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "name,kind,1,NULL,-2.5,age - 20",
+            "John Doe,customer,1,,-2.5,8",
+            "Jane Smith,customer,1,,-2.5,14"
+        ],
+        result
+    );
+
+    let query = "SELECT order_id, quantity * price AS subtotal FROM orders ORDER BY subtotal DESC, order_id LIMIT 3";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["order_id,subtotal", "1,1200", "7,1200", "10,1200"],
+        result
+    );
+
+    let query = "SELECT product, SUM(price) * 2 AS doubled FROM orders GROUP BY product ORDER BY doubled LIMIT 2";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["product,doubled", "Mouse,100", "Headphones,160"],
+        result
+    );
+}
+
+#[test]
+fn test_select_union_and_union_all() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT product FROM orders WHERE quantity > 1 UNION SELECT product FROM orders WHERE price > 1000 ORDER BY product";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "product",
+            "Headphones",
+            "Keyboard",
+            "Laptop",
+            "Monitor",
+            "Mouse"
+        ],
+        result
+    );
+
+    let query = "SELECT name AS label FROM users WHERE age > 40 UNION ALL SELECT product FROM orders WHERE price > 1000 ORDER BY label DESC LIMIT 3";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["label", "Laptop", "Laptop", "Laptop"], result);
+}
+
+#[test]
+fn test_select_intersect_and_except() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id FROM users WHERE age < 35 INTERSECT SELECT user_id FROM orders ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id", "1", "2", "3", "5", "7"], result);
+
+    let query = "SELECT user_id FROM users EXCEPT SELECT user_id FROM orders ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["user_id", "4", "6", "9", "10"], result);
+
+    let query = "SELECT name FROM users WHERE user_id IN (SELECT user_id FROM orders WHERE quantity > 2 UNION SELECT user_id FROM orders WHERE price = 300) ORDER BY name";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["name", "Eve Adams", "Frank Miller", "Jane Smith"],
+        result
+    );
+}
+
+#[test]
+fn test_select_union_with_mixed_numerics() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT 2.0 + 0 FROM users UNION SELECT 2 FROM users";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["2.0 + 0", "2"], result);
+
+    let query = "SELECT price / 1.0 FROM orders WHERE order_id = 1 EXCEPT SELECT price FROM orders";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(1, result.len());
+}
+
+#[test]
+fn test_select_compound_uses_table_null_marker() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT city, zip_code FROM stores WHERE store_id = 5 UNION SELECT city, NULL FROM stores WHERE store_id = 1 ORDER BY city";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["city,zip_code", "Augusta,\\N", "Boston,\\N"], result);
+
+    let query = "SELECT city, zip_code FROM stores EXCEPT SELECT city, zip_code FROM stores WHERE zip_code IS NOT NULL";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["city,zip_code", "Augusta,\\N"], result);
+}
+
+#[test]
+fn test_select_compound_with_different_columns() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, age FROM users UNION SELECT product FROM orders";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("same number of columns")));
+
+    let query = "SELECT * FROM users EXCEPT SELECT * FROM orders";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("same number of columns")));
+}
+
+#[test]
+fn test_select_with_common_tables() {
+    let test = RusticSQLTest::default();
+    let query = "WITH seniors AS (SELECT user_id, name AS senior FROM users WHERE age > 35) SELECT senior FROM seniors ORDER BY senior";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["senior", "Bob Brown", "Frank Miller", "Henry Clark"],
+        result
+    );
+
+    let query =
+        "WITH buyers AS (SELECT user_id, SUM(price) AS spent FROM orders GROUP BY user_id), \
+        top AS (SELECT user_id FROM buyers WHERE spent > 500) \
+        SELECT u.name, b.spent FROM users u JOIN buyers b ON u.user_id = b.user_id \
+        WHERE u.user_id IN (SELECT user_id FROM top) ORDER BY b.spent DESC";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "u.name,b.spent",
+            "Frank Miller,1245",
+            "John Doe,1225",
+            "Charlie Davis,1200"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_common_table_shadows_table() {
+    let test = RusticSQLTest::default();
+    let query = "WITH users AS (SELECT name FROM users WHERE age < 26) SELECT * FROM users";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["name", "Grace Lee"], result);
+
+    let query = "SELECT COUNT(*) FROM users";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(vec!["COUNT(*)", "10"], result);
+}
+
+#[test]
+fn test_select_common_table_keeps_types_and_nulls() {
+    let test = RusticSQLTest::default();
+    let files = test.table_files();
+    let query = "WITH s AS (SELECT zip_code, city FROM stores) SELECT zip_code, city FROM s";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "zip_code,city",
+            "02110,Boston",
+            "10001,New York",
+            "00501,Holtsville",
+            "01101,Springfield",
+            "\\N,Augusta"
+        ],
+        result
+    );
+
+    let query =
+        "WITH s AS (SELECT zip_code FROM stores) SELECT COUNT(*) FROM s WHERE zip_code IS NULL";
+    test.assert_row(query, &["1"]);
+    assert_eq!(files, test.table_files());
+}
+
+#[test]
+fn test_select_nested_common_tables_with_same_name() {
+    let test = RusticSQLTest::default();
+    let query = "WITH s AS (WITH s AS (SELECT user_id, age FROM users WHERE age > 30) \
+        SELECT user_id FROM s WHERE age < 50) SELECT COUNT(*) FROM s";
+    test.assert_row(query, &["6"]);
+}
+
+#[test]
+fn test_select_invalid_common_tables() {
+    let test = RusticSQLTest::default();
+    let query = "WITH missing AS (SELECT nope FROM users) SELECT * FROM missing";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("nope")));
+
+    let query =
+        "WITH later AS (SELECT * FROM first), first AS (SELECT * FROM users) SELECT * FROM later";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("does not exist")));
+
+    let query = "WITH seniors AS (SELECT name FROM users WHERE age > 35) SELECT * FROM seniors";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let query = "SELECT * FROM seniors";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("does not exist")));
+}
+
+#[test]
+fn test_select_ranking_window_functions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT order_id, price, RANK() OVER (ORDER BY price DESC) AS position, ROW_NUMBER() OVER (ORDER BY price DESC) AS n FROM orders WHERE price >= 80 ORDER BY n";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "order_id,price,position,n",
+            "1,1200,1,1",
+            "7,1200,1,2",
+            "10,1200,1,3",
+            "3,300,4,4",
+            "8,300,4,5",
+            "6,80,6,6"
+        ],
+        result
+    );
+
+    let query = "SELECT u.name, o.product, ROW_NUMBER() OVER (PARTITION BY u.user_id ORDER BY o.price DESC) AS n FROM users u JOIN orders o ON o.user_id = u.user_id WHERE u.user_id IN (1, 3) ORDER BY u.user_id, n";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "u.name,o.product,n",
+            "John Doe,Laptop,1",
+            "John Doe,Mouse,2",
+            "Alice Johnson,Headphones,1",
+            "Alice Johnson,Keyboard,2",
+            "Alice Johnson,Mouse,3"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_offset_and_running_window_functions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT order_id, SUM(price) OVER (PARTITION BY user_id ORDER BY order_id) AS running, LAG(price) OVER (PARTITION BY user_id ORDER BY order_id) AS previous, LEAD(product, 1, 'none') OVER (PARTITION BY user_id ORDER BY order_id) AS next FROM orders WHERE user_id <= 3 ORDER BY order_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "order_id,running,previous,next",
+            "1,1200,,Mouse",
+            "2,1225,1200,none",
+            "3,300,,none",
+            "4,45,,Mouse",
+            "5,70,45,Headphones",
+            "6,150,25,none"
+        ],
+        result
+    );
+
+    let query = "SELECT name, age - LAG(age, 2, 0) OVER (ORDER BY user_id) AS diff, SUM(age) OVER () AS total FROM users ORDER BY user_id LIMIT 3";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "name,diff,total",
+            "John Doe,28,330",
+            "Jane Smith,34,330",
+            "Alice Johnson,1,330"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_select_window_ordered_by_aggregate() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT user_id, SUM(price), RANK() OVER (ORDER BY SUM(price) DESC) AS position FROM orders GROUP BY user_id ORDER BY position";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec![
+            "user_id,SUM(price),position",
+            "8,1245,1",
+            "1,1225,2",
+            "5,1200,3",
+            "2,300,4",
+            "7,300,4",
+            "3,150,6"
+        ],
+        result
+    );
+
+    let query = "SELECT user_id, RANK() OVER (ORDER BY COUNT(*) DESC) AS position FROM orders GROUP BY user_id ORDER BY user_id";
+    let result = test.run_and_get_rows(query.to_string());
+    assert_eq!(
+        vec!["user_id,position", "1,2", "2,4", "3,1", "5,4", "7,4", "8,2"],
+        result
+    );
+}
+
+#[test]
+fn test_select_invalid_window_functions() {
+    let test = RusticSQLTest::default();
+    let query = "SELECT name, RANK() OVER (ORDER BY nope) FROM users";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("nope")));
+
+    let query = "SELECT SUM(name) OVER (ORDER BY user_id) FROM users";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("numeric")));
+
+    let query = "SELECT name FROM users WHERE ROW_NUMBER() OVER (ORDER BY age) = 1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err_and(|x| x.to_string().contains("WHERE")));
+}
@@ -0,0 +1,137 @@
+use crate::utils::RusticSQLTest;
+
+pub mod utils;
+
+#[test]
+fn test_create_table_and_insert() {
+    let test = RusticSQLTest::default();
+    let query = "CREATE TABLE warehouses (warehouse_id INT, city TEXT, zip_code TEXT)";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+
+    let result = test.run_and_get_rows("SELECT * FROM warehouses".to_string());
+    assert_eq!(vec!["warehouse_id,city,zip_code"], result);
+
+    let insert =
+        "INSERT INTO warehouses (warehouse_id, city, zip_code) VALUES (1, 'Boston', '02110')";
+    assert!(test.run_for(insert.to_string()).is_ok());
+    test.assert_row(
+        "SELECT * FROM warehouses WHERE zip_code = '02110'",
+        &["1", "Boston", "02110"],
+    );
+
+    let invalid = "INSERT INTO warehouses (warehouse_id) VALUES ('one')";
+    assert!(test.run_for(invalid.to_string()).is_err());
+}
+
+#[test]
+fn test_create_existing_table() {
+    let test = RusticSQLTest::default();
+    let query = "CREATE TABLE users (user_id INT)";
+    assert!(test.run_for(query.to_string()).is_err());
+
+    let query = "CREATE TABLE IF NOT EXISTS users (user_id INT)";
+    test.verify_no_changes("users.csv".to_string(), query);
+}
+
+#[test]
+fn test_drop_table() {
+    let test = RusticSQLTest::default();
+    let query = "DROP TABLE stores";
+    assert!(test.run_for(query.to_string()).is_ok());
+    assert!(test.run_for("SELECT * FROM stores".to_string()).is_err());
+
+    let query = "CREATE TABLE stores (store_id TEXT)";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let insert = "INSERT INTO stores (store_id) VALUES ('007')";
+    assert!(test.run_for(insert.to_string()).is_ok());
+    test.assert_row("SELECT * FROM stores", &["007"]);
+}
+
+#[test]
+fn test_drop_missing_table() {
+    let test = RusticSQLTest::default();
+    let query = "DROP TABLE warehouses";
+    assert!(test.run_for(query.to_string()).is_err());
+
+    let query = "DROP TABLE IF EXISTS warehouses";
+    assert!(test.run_for(query.to_string()).is_ok());
+}
+
+#[test]
+fn test_dotted_table_name_is_rejected() {
+    let test = RusticSQLTest::default();
+    let query = "DROP TABLE users.backup";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "DROP TABLE IF EXISTS users.backup";
+    assert!(test.run_for(query.to_string()).is_err());
+    assert!(test
+        .run_for("SELECT * FROM orders.bak".to_string())
+        .is_err());
+    test.assert_row("SELECT COUNT(*) FROM users", &["10"]);
+}
+
+#[test]
+fn test_reserved_column_names_can_be_quoted() {
+    let test = RusticSQLTest::default();
+    let query = "CREATE TABLE shifts (shift_id INT, \"on\" TEXT, \"end\" INT)";
+    assert!(test.run_for(query.to_string()).is_ok());
+
+    let insert =
+        "INSERT INTO shifts (shift_id, \"on\", \"end\") VALUES (1, 'mon', 17), (2, 'tue', 9)";
+    assert!(test.run_for(insert.to_string()).is_ok());
+    let update = "UPDATE shifts SET \"end\" = \"end\" + 1 WHERE \"on\" IN ('mon')";
+    assert!(test.run_for(update.to_string()).is_ok());
+
+    test.assert_row(
+        "SELECT \"on\", \"shifts.end\" FROM shifts WHERE \"end\" > 10",
+        &["mon", "18"],
+    );
+    let result = test.run_and_get_rows("SELECT shift_id FROM shifts ORDER BY \"end\"".to_string());
+    assert_eq!(vec!["shift_id", "2", "1"], result);
+    assert!(test.run_for("SELECT end FROM shifts".to_string()).is_err());
+}
+
+#[test]
+fn test_alter_table_add_column() {
+    let test = RusticSQLTest::default();
+    let query = "ALTER TABLE stores ADD COLUMN manager TEXT DEFAULT 'Doe, Jane'";
+    assert!(test.run_for(query.to_string()).is_ok());
+    test.assert_row(
+        "SELECT * FROM stores WHERE store_id = 2",
+        &["2", "New York", "10001", "\"Doe, Jane\""],
+    );
+
+    let query = "ALTER TABLE stores ADD COLUMN employees INT DEFAULT 'many'";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "ALTER TABLE stores ADD COLUMN employees INT DEFAULT 10";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let update = "UPDATE stores SET employees = 'a few' WHERE store_id = 1";
+    assert!(test.run_for(update.to_string()).is_err());
+}
+
+#[test]
+fn test_alter_table_drop_column() {
+    let test = RusticSQLTest::default();
+    let query = "ALTER TABLE users DROP COLUMN email";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let result = test.run_and_get_rows("SELECT * FROM users WHERE user_id = 1".to_string());
+    assert_eq!(vec!["user_id,name,age", "1,John Doe,28"], result);
+
+    let query = "ALTER TABLE users DROP COLUMN email";
+    assert!(test.run_for(query.to_string()).is_err());
+}
+
+#[test]
+fn test_alter_table_rename_column() {
+    let test = RusticSQLTest::default();
+    let query = "ALTER TABLE stores RENAME COLUMN zip_code TO postal_code";
+    assert!(test.run_for(query.to_string()).is_ok());
+    test.assert_row(
+        "SELECT city FROM stores WHERE postal_code = '00501'",
+        &["Holtsville"],
+    );
+
+    let query = "ALTER TABLE stores RENAME COLUMN city TO store_id";
+    assert!(test.run_for(query.to_string()).is_err());
+}
@@ -9,8 +9,9 @@ fn test_update_pokemon_with_empty_name() {
     let result = test.run_for(update_query.to_string());
     assert!(result.is_ok());
     let select_query = "SELECT * FROM pokemon WHERE id = 1";
-    let expected_row = ["1", "", "Electric", "25"];
+    let expected_row = ["1", "\"\"", "Electric", "25"];
     test.assert_row(select_query, &expected_row);
+    test.assert_row("SELECT COUNT(*) FROM pokemon WHERE name IS NULL", &["0"]);
 }
 
 #[test]
@@ -21,7 +22,7 @@ fn test_update_all_pokemon_to_same_level() {
     assert!(result.is_ok());
     for id in 1..=10 {
         let select_query = format!("SELECT * FROM pokemon WHERE id = {}", id);
-        let expected_row = [&id.to_string(), "", "", "50"];
+        let expected_row = [&id.to_string(), "\"\"", "\"\"", "50"];
         test.assert_row(&select_query, &expected_row);
     }
 }
@@ -48,3 +49,137 @@ fn test_update_invalid_column() {
     let result = test.run_for(update_query.to_string());
     assert!(result.is_err_and(|e| e.to_string().contains("hp")));
 }
+
+#[test]
+fn test_update_value_with_comma_round_trips() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE pokemon SET name = 'Mr. Mime, Jr.' WHERE id = 1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT id, name, level FROM pokemon WHERE name = 'Mr. Mime, Jr.'";
+    test.assert_row(select_query, &["1", "\"Mr. Mime, Jr.\"", "25"]);
+}
+
+#[test]
+fn test_update_invalid_type_for_schema() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE stores SET store_id = 'first' WHERE city = 'Boston'";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_err());
+    let select_query = "SELECT * FROM stores WHERE city = 'Boston'";
+    test.assert_row(select_query, &["1", "Boston", "02110"]);
+}
+
+#[test]
+fn test_failed_update_leaves_table_usable() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE stores SET store_id = 'x' WHERE store_id = 2";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "UPDATE orders SET price = price / 0 WHERE order_id = 1";
+    assert!(test.run_for(query.to_string()).is_err());
+    let query = "DELETE FROM orders WHERE price / (quantity - 1) > 1";
+    assert!(test.run_for(query.to_string()).is_err());
+
+    let query = "DELETE FROM stores WHERE store_id = 2";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let query = "UPDATE orders SET price = 1300 WHERE order_id = 1";
+    assert!(test.run_for(query.to_string()).is_ok());
+    let query = "ALTER TABLE orders ADD COLUMN notes TEXT";
+    assert!(test.run_for(query.to_string()).is_ok());
+    test.assert_row("SELECT COUNT(*) FROM stores", &["4"]);
+    test.assert_row("SELECT price FROM orders WHERE order_id = 1", &["1300"]);
+}
+
+#[test]
+fn test_update_with_arithmetic_expression() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE pokemon SET level = level + 1 WHERE id = 1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT id, level FROM pokemon WHERE id = 1";
+    test.assert_row(select_query, &["1", "26"]);
+}
+
+#[test]
+fn test_update_arithmetic_uses_previous_values() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE pokemon SET level = level * 2, id = level WHERE id = 1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT id, level FROM pokemon WHERE name = 'Pikachu'";
+    test.assert_row(select_query, &["25", "50"]);
+}
+
+#[test]
+fn test_update_set_null() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE stores SET zip_code = NULL WHERE city = 'Boston'";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT store_id, zip_code FROM stores WHERE zip_code IS NULL";
+    let result = test.run_and_get_rows(select_query.to_string());
+    assert_eq!(vec!["store_id,zip_code", "1,\\N", "5,\\N"], result);
+}
+
+#[test]
+fn test_update_set_null_marker_text_is_not_null() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE stores SET zip_code = '\\N', city = 2110 WHERE store_id = 1";
+    assert!(test.run_for(query.to_string()).is_ok());
+    test.assert_row("SELECT COUNT(*) FROM stores WHERE zip_code IS NULL", &["1"]);
+    test.assert_row(
+        "SELECT city, zip_code FROM stores WHERE store_id = 1",
+        &["2110", "\"\\N\""],
+    );
+    test.assert_row("SELECT store_id FROM stores WHERE city = '2110'", &["1"]);
+}
+
+#[test]
+fn test_update_with_string_functions() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE pokemon SET name = CONCAT(UPPER(name), '-', LOWER(type)) WHERE id = 1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT name FROM pokemon WHERE id = 1";
+    test.assert_row(select_query, &["PIKACHU-electric"]);
+}
+
+#[test]
+fn test_update_with_date_functions() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE events SET day = DATE_ADD(day, INTERVAL 1 YEAR), starts_at = DATE_TRUNC('day', starts_at) WHERE EXTRACT(MONTH FROM day) = 2";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT day, starts_at FROM events WHERE event_id = 3";
+    test.assert_row(select_query, &["2025-02-28", "2024-02-29 00:00:00"]);
+}
+
+#[test]
+fn test_update_with_case_expression() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE pokemon SET type = CASE WHEN level > 20 THEN 'Veteran' ELSE type END, level = CASE type WHEN 'Fire' THEN level + 10 ELSE level END";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    let select_query = "SELECT name, type, level FROM pokemon WHERE id <= 2 ORDER BY id";
+    let result = test.run_and_get_rows(select_query.to_string());
+    assert_eq!(
+        vec![
+            "name,type,level",
+            "Pikachu,Veteran,25",
+            "Charmander,Fire,28"
+        ],
+        result
+    );
+}
+
+#[test]
+fn test_update_set_with_subquery() {
+    let test = RusticSQLTest::default();
+    let query = "UPDATE pokemon SET level = (SELECT MAX(level) FROM pokemon) + 1 WHERE id = 1";
+    let result = test.run_for(query.to_string());
+    assert!(result.is_ok());
+    test.assert_row(
+        "SELECT name, level FROM pokemon WHERE id = 1",
+        &["Pikachu", "31"],
+    );
+}
@@ -43,6 +43,15 @@ impl RusticSQLTest {
         assert_eq!(before_query, after_query)
     }
 
+    pub fn table_files(&self) -> Vec<String> {
+        let mut files: Vec<String> = fs::read_dir(&self.temp_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        files
+    }
+
     pub fn tear_down(&self) {
         fs::remove_dir_all(&self.temp_dir).expect("failed to clean up test directory");
     }
@@ -87,14 +96,26 @@ impl Default for RusticSQLTest {
         let pokemons = og_tables_path.join("pokemon.csv");
         let users = og_tables_path.join("users.csv");
         let people = og_tables_path.join("people.csv");
+        let orders = og_tables_path.join("orders.csv");
+        let stores = og_tables_path.join("stores.csv");
+        let stores_schema = og_tables_path.join("stores.schema");
+        let events = og_tables_path.join("events.csv");
 
         let temp_orders = temp_dir.join("pokemon.csv");
         let temp_users = temp_dir.join("users.csv");
         let temp_people = temp_dir.join("people.csv");
+        let temp_purchases = temp_dir.join("orders.csv");
+        let temp_stores = temp_dir.join("stores.csv");
+        let temp_stores_schema = temp_dir.join("stores.schema");
+        let temp_events = temp_dir.join("events.csv");
 
         fs::copy(pokemons, &temp_orders).expect("failed to copy order table.");
         fs::copy(users, &temp_users).expect("failed to copy user table.");
         fs::copy(people, &temp_people).expect("failed to copy people table.");
+        fs::copy(orders, &temp_purchases).expect("failed to copy orders table.");
+        fs::copy(stores, &temp_stores).expect("failed to copy stores table.");
+        fs::copy(stores_schema, &temp_stores_schema).expect("failed to copy stores schema.");
+        fs::copy(events, &temp_events).expect("failed to copy events table.");
 
         RusticSQLTest {
             temp_dir: temp_dir.to_path_buf(),